@@ -10,6 +10,8 @@
 [`Year`]: https://docs.rs/gregorian/latest/gregorian/struct.Year.html
 [`YearMonth`]: https://docs.rs/gregorian/latest/gregorian/struct.YearMonth.html
 [`Month`]: https://docs.rs/gregorian/latest/gregorian/struct.Month.html
+[`Weekday`]: https://docs.rs/gregorian/latest/gregorian/enum.Weekday.html
+[`WeekRules`]: https://docs.rs/gregorian/latest/gregorian/struct.WeekRules.html
 [`InvalidDayOfMonth`]: https://docs.rs/gregorian/latest/gregorian/struct.InvalidDayOfMonth.html
 
 [`Date::add_years()`]: https://docs.rs/gregorian/latest/gregorian/struct.Date.html#method.add_years
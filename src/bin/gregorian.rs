@@ -0,0 +1,514 @@
+//! Command line tool for date arithmetic and conversion.
+
+use gregorian::{Date, DateResultExt, Month, MonthGrid, Period, TextCalendar, WeekRules, Year, YearMonth};
+use serde_json::{json, Value};
+
+const USAGE: &str = "\
+Usage: gregorian [--json] <command> [arguments]
+
+Commands:
+  add DATE PERIOD [--clamp | --next-valid]  Add a period like 1M, -3D, 2W or 1Y to a date.
+  sub DATE PERIOD [--clamp | --next-valid]  Subtract a period from a date.
+  diff DATE DATE                            Compute the difference between two dates.
+  weekday DATE                              Print the weekday of a date.
+  iso-week DATE                             Print the ISO 8601 week date of a date.
+  cal [YYYY-MM | YYYY] [--sunday] [--week-numbers]
+                                            Print a month or year calendar.
+  convert [--from FORMAT] [--to FORMAT] VALUE
+                                            Convert between date formats.
+
+Adding months or years can give a day that does not exist, such as 2020-02-31.
+By default that is an error. Use --clamp to get the last day of the month,
+or --next-valid to get the first day of the next month.
+
+Formats for convert: date, jdn, mjd, unix, ordinal, iso-week.
+Without --to, all formats are printed.
+
+Options:
+  --json     Print the output as JSON.
+  -h, --help Print this help.
+";
+
+/// The formats supported by the `convert` command.
+const FORMATS: [&str; 6] = ["date", "jdn", "mjd", "unix", "ordinal", "iso-week"];
+
+/// The Julian day number of the modified Julian day 0.
+const MJD_EPOCH: i32 = 2_400_001;
+
+/// The first and last year accepted by the tool.
+///
+/// This is the range supported by the date arithmetic of the library, with a year of margin on both sides,
+/// so that calendar grids and ISO week dates never leave that range.
+const FIRST_YEAR: i16 = -32399;
+const LAST_YEAR: i16 = 32766;
+
+#[derive(Debug)]
+enum Error {
+	/// The command line arguments are invalid.
+	Usage(String),
+
+	/// The command failed.
+	Failed(String),
+}
+
+/// The parsed command line arguments.
+#[derive(Default)]
+struct Args {
+	json: bool,
+	help: bool,
+	clamp: bool,
+	next_valid: bool,
+	sunday: bool,
+	week_numbers: bool,
+	from: Option<String>,
+	to: Option<String>,
+	positional: Vec<String>,
+}
+
+impl Args {
+	fn parse(args: &[String]) -> Result<Self, Error> {
+		let mut parsed = Self::default();
+		let mut args = args.iter();
+		while let Some(arg) = args.next() {
+			match arg.as_str() {
+				"--json" => parsed.json = true,
+				"-h" | "--help" => parsed.help = true,
+				"--clamp" => parsed.clamp = true,
+				"--next-valid" => parsed.next_valid = true,
+				"--sunday" => parsed.sunday = true,
+				"--week-numbers" => parsed.week_numbers = true,
+				"--from" => parsed.from = Some(args.next().ok_or_else(|| usage("missing value for --from"))?.clone()),
+				"--to" => parsed.to = Some(args.next().ok_or_else(|| usage("missing value for --to"))?.clone()),
+				flag if flag.starts_with("--") => return Err(usage(format!("unknown option: {}", flag))),
+				_ => parsed.positional.push(arg.clone()),
+			}
+		}
+		if parsed.clamp && parsed.next_valid {
+			return Err(usage("--clamp and --next-valid can not be used together"));
+		}
+		Ok(parsed)
+	}
+
+	/// Get the positional arguments of the command, checking the number of arguments.
+	fn operands(&self, min: usize, max: usize) -> Result<&[String], Error> {
+		let operands = &self.positional[1..];
+		if operands.len() < min {
+			Err(usage(format!("missing arguments for {}", self.positional[0])))
+		} else if operands.len() > max {
+			Err(usage(format!("too many arguments for {}", self.positional[0])))
+		} else {
+			Ok(operands)
+		}
+	}
+}
+
+fn usage(message: impl Into<String>) -> Error {
+	Error::Usage(message.into())
+}
+
+fn failed(message: impl Into<String>) -> Error {
+	Error::Failed(message.into())
+}
+
+fn main() {
+	let args: Vec<String> = std::env::args().skip(1).collect();
+	match run(&args) {
+		Ok(output) => print!("{}", output),
+		Err(Error::Usage(message)) => {
+			eprintln!("error: {}\n\n{}", message, USAGE);
+			std::process::exit(2);
+		},
+		Err(Error::Failed(message)) => {
+			eprintln!("error: {}", message);
+			std::process::exit(1);
+		},
+	}
+}
+
+/// Run the command line tool and return the output.
+fn run(args: &[String]) -> Result<String, Error> {
+	let args = Args::parse(args)?;
+	if args.help {
+		return Ok(USAGE.to_string());
+	}
+	let Some(command) = args.positional.first() else {
+		return Err(usage("missing command"));
+	};
+
+	let (text, json) = match command.as_str() {
+		"add" => add(&args, 1)?,
+		"sub" => add(&args, -1)?,
+		"diff" => diff(&args)?,
+		"weekday" => weekday(&args)?,
+		"iso-week" => iso_week(&args)?,
+		"cal" => cal(&args)?,
+		"convert" => convert(&args)?,
+		command => return Err(usage(format!("unknown command: {}", command))),
+	};
+
+	if args.json {
+		Ok(format!("{}\n", json))
+	} else {
+		Ok(text)
+	}
+}
+
+fn parse_date(data: &str) -> Result<Date, Error> {
+	check_date(data.parse().map_err(|e| failed(format!("{}: {}", data, e)))?)
+}
+
+fn out_of_range() -> Error {
+	failed(format!("date out of range, expected a year from {} to {}", FIRST_YEAR, LAST_YEAR))
+}
+
+fn check_year(year: i16) -> Result<Year, Error> {
+	if (FIRST_YEAR..=LAST_YEAR).contains(&year) {
+		Ok(Year::new(year))
+	} else {
+		Err(out_of_range())
+	}
+}
+
+fn check_date(date: Date) -> Result<Date, Error> {
+	check_year(date.year().to_number())?;
+	Ok(date)
+}
+
+/// Check that a day number is in the supported range, given a function to compute the day number of a date.
+fn check_day_number(value: i64, day_number: impl Fn(Date) -> i64) -> Result<i64, Error> {
+	let first = day_number(Year::new(FIRST_YEAR).first_day());
+	let last = day_number(Year::new(LAST_YEAR).last_day());
+	if (first..=last).contains(&value) {
+		Ok(value)
+	} else {
+		Err(out_of_range())
+	}
+}
+
+fn parse_number<T: std::str::FromStr>(data: &str, what: &str) -> Result<T, Error> {
+	data.parse().map_err(|_| failed(format!("invalid {}: {}", what, data)))
+}
+
+fn add(args: &Args, sign: i32) -> Result<(String, Value), Error> {
+	let operands = args.operands(2, 2)?;
+	let date = parse_date(&operands[0])?;
+	let period: Period = operands[1].parse().map_err(|e| failed(format!("{}: {}", operands[1], e)))?;
+	let result = period
+		.checked_times(sign)
+		.and_then(|period| date.checked_add_period(period))
+		.ok_or_else(out_of_range)?;
+	let result = if args.clamp {
+		result.or_prev_valid()
+	} else if args.next_valid {
+		result.or_next_valid()
+	} else {
+		result.map_err(|e| failed(format!("{}; use --clamp or --next-valid to get a valid date", e)))?
+	};
+	let result = check_date(result)?;
+	Ok((format!("{}\n", result), json!({ "date": result.to_string() })))
+}
+
+/// Split the difference between two dates in whole years, months and remaining days.
+fn calendar_difference(from: Date, to: Date) -> (i32, i32, i32) {
+	if to < from {
+		let (years, months, days) = calendar_difference(to, from);
+		return (-years, -months, -days);
+	}
+	let month_index = |date: Date| i32::from(date.year().to_number()) * 12 + i32::from(date.month().to_number());
+	let mut months = month_index(to) - month_index(from);
+	if from.add_months(months).or_prev_valid() > to {
+		months -= 1;
+	}
+	let days = from.add_months(months).or_prev_valid().days_since(to);
+	(months / 12, months % 12, days)
+}
+
+fn plural(count: i32, unit: &str) -> String {
+	if count.abs() == 1 {
+		format!("{} {}", count, unit)
+	} else {
+		format!("{} {}s", count, unit)
+	}
+}
+
+fn diff(args: &Args) -> Result<(String, Value), Error> {
+	let operands = args.operands(2, 2)?;
+	let from = parse_date(&operands[0])?;
+	let to = parse_date(&operands[1])?;
+	let days = from.days_since(to);
+	let (years, months, remaining) = calendar_difference(from, to);
+	let text = format!(
+		"{} ({}, {}, {})\n",
+		plural(days, "day"),
+		plural(years, "year"),
+		plural(months, "month"),
+		plural(remaining, "day"),
+	);
+	let json = json!({
+		"from": from.to_string(),
+		"to": to.to_string(),
+		"days": days,
+		"calendar": { "years": years, "months": months, "days": remaining },
+	});
+	Ok((text, json))
+}
+
+fn weekday(args: &Args) -> Result<(String, Value), Error> {
+	let date = parse_date(&args.operands(1, 1)?[0])?;
+	let weekday = date.weekday();
+	let json = json!({
+		"date": date.to_string(),
+		"weekday": weekday.name(),
+		"number": weekday.to_number(),
+	});
+	Ok((format!("{}\n", weekday), json))
+}
+
+fn format_iso_week(date: Date) -> String {
+	let rules = WeekRules::ISO;
+	format!("{}-W{:02}-{}", rules.week_year(date), rules.week_of_year(date), date.weekday().to_number())
+}
+
+fn iso_week(args: &Args) -> Result<(String, Value), Error> {
+	let date = parse_date(&args.operands(1, 1)?[0])?;
+	let rules = WeekRules::ISO;
+	let json = json!({
+		"date": date.to_string(),
+		"year": rules.week_year(date).to_number(),
+		"week": rules.week_of_year(date),
+		"weekday": date.weekday().to_number(),
+	});
+	Ok((format!("{}\n", format_iso_week(date)), json))
+}
+
+fn grid_json(month: YearMonth, rules: WeekRules) -> Value {
+	let weeks: Vec<Value> = MonthGrid::new(month)
+		.with_week_rules(rules)
+		.rows()
+		.map(|week| {
+			let dates: Vec<String> = week.days.iter().map(|day| day.date.to_string()).collect();
+			json!({ "number": week.week_number, "dates": dates })
+		})
+		.collect();
+	json!({ "month": month.to_string(), "weeks": weeks })
+}
+
+fn cal(args: &Args) -> Result<(String, Value), Error> {
+	let operands = args.operands(0, 1)?;
+	let rules = if args.sunday { WeekRules::US } else { WeekRules::ISO };
+	let calendar = TextCalendar::new().with_week_rules(rules).with_week_numbers(args.week_numbers);
+	let today = Date::today();
+
+	match operands.first() {
+		Some(operand) if !operand.chars().skip(1).any(|c| c == '-') => {
+			let year = check_year(parse_number(operand, "year")?)?;
+			let calendar = calendar.with_highlight(move |date| date == today);
+			let months: Vec<Value> = gregorian::MONTHS.iter().map(|&month| grid_json(year.with_month(month), rules)).collect();
+			Ok((calendar.render_year(year), json!({ "year": year.to_number(), "months": months })))
+		},
+		operand => {
+			let month = match operand {
+				Some(operand) => parse_year_month(operand)?,
+				None => today.year_month(),
+			};
+			let calendar = calendar.with_highlight(move |date| date == today);
+			Ok((calendar.render_month(month), grid_json(month, rules)))
+		},
+	}
+}
+
+fn parse_year_month(data: &str) -> Result<YearMonth, Error> {
+	let invalid = || failed(format!("invalid month, expected YYYY-MM: {}", data));
+	let (year, month) = data.rsplit_once('-').ok_or_else(invalid)?;
+	let year: i16 = year.parse().map_err(|_| invalid())?;
+	let month: u8 = month.parse().map_err(|_| invalid())?;
+	let month = Month::new(month).map_err(|_| invalid())?;
+	Ok(check_year(year)?.with_month(month))
+}
+
+fn parse_iso_week(data: &str) -> Result<Date, Error> {
+	let invalid = || failed(format!("invalid ISO week date, expected YYYY-Www-D: {}", data));
+	let (rest, weekday) = data.rsplit_once('-').ok_or_else(invalid)?;
+	let (year, week) = rest.rsplit_once("-W").ok_or_else(invalid)?;
+	let year = check_year(year.parse().map_err(|_| invalid())?)?;
+	let week: u8 = week.parse().map_err(|_| invalid())?;
+	let weekday: u8 = weekday.parse().map_err(|_| invalid())?;
+	if week < 1 || week > WeekRules::ISO.weeks_in_year(year) || !(1..=7).contains(&weekday) {
+		return Err(invalid());
+	}
+	Ok(WeekRules::ISO.first_day_of_week_year(year).add_days(i32::from(week - 1) * 7 + i32::from(weekday - 1)))
+}
+
+fn parse_ordinal(data: &str) -> Result<Date, Error> {
+	let invalid = || failed(format!("invalid ordinal date, expected YYYY-DDD: {}", data));
+	let (year, day) = data.rsplit_once('-').ok_or_else(invalid)?;
+	let year = check_year(year.parse().map_err(|_| invalid())?)?;
+	let day: u16 = day.parse().map_err(|_| invalid())?;
+	year.with_day_of_year(day).map_err(|_| invalid())
+}
+
+fn check_format(format: &str) -> Result<&str, Error> {
+	if FORMATS.contains(&format) {
+		Ok(format)
+	} else {
+		Err(usage(format!("unknown format: {}, expected one of {}", format, FORMATS.join(", "))))
+	}
+}
+
+fn parse_format(format: &str, data: &str) -> Result<Date, Error> {
+	match format {
+		"jdn" => {
+			let jdn = check_day_number(parse_number(data, "Julian day number")?, |date| date.julian_day_number().into())?;
+			Ok(Date::from_julian_day_number(jdn as i32))
+		},
+		"mjd" => {
+			let mjd: i64 = parse_number(data, "modified Julian day")?;
+			let jdn = check_day_number(mjd.saturating_add(MJD_EPOCH.into()), |date| date.julian_day_number().into())?;
+			Ok(Date::from_julian_day_number(jdn as i32))
+		},
+		"unix" => {
+			let seconds: i64 = parse_number(data, "unix timestamp")?;
+			check_day_number(seconds.div_euclid(86400), |date| date.to_unix_timestamp() / 86400)?;
+			Ok(Date::from_unix_timestamp(seconds))
+		},
+		"ordinal" => parse_ordinal(data),
+		"iso-week" => parse_iso_week(data),
+		_ => parse_date(data),
+	}
+}
+
+fn format_value(format: &str, date: Date) -> Value {
+	match format {
+		"jdn" => json!(date.julian_day_number()),
+		"mjd" => json!(date.julian_day_number() - MJD_EPOCH),
+		"unix" => json!(date.to_unix_timestamp()),
+		"ordinal" => json!(format!("{}-{:03}", date.year(), date.day_of_year())),
+		"iso-week" => json!(format_iso_week(date)),
+		_ => json!(date.to_string()),
+	}
+}
+
+fn text_value(value: &Value) -> String {
+	match value {
+		Value::String(value) => value.clone(),
+		value => value.to_string(),
+	}
+}
+
+fn convert(args: &Args) -> Result<(String, Value), Error> {
+	let value = &args.operands(1, 1)?[0];
+	let from = check_format(args.from.as_deref().unwrap_or("date"))?;
+	let date = parse_format(from, value)?;
+
+	if let Some(to) = &args.to {
+		let output = format_value(check_format(to)?, date);
+		return Ok((format!("{}\n", text_value(&output)), output));
+	}
+
+	let mut text = String::new();
+	let mut json = serde_json::Map::new();
+	for format in FORMATS {
+		let output = format_value(format, date);
+		text.push_str(&format!("{}: {}\n", format, text_value(&output)));
+		json.insert(format.to_string(), output);
+	}
+	Ok((text, Value::Object(json)))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use assert2::{assert, let_assert};
+
+	fn run_args(args: &str) -> Result<String, Error> {
+		let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+		run(&args)
+	}
+
+	#[test]
+	fn add() {
+		assert!(let Err(Error::Failed(_)) = run_args("add 2020-01-31 1M"));
+		assert!(run_args("add 2020-01-31 1M --clamp").unwrap() == "2020-02-29\n");
+		assert!(run_args("add 2020-01-31 1M --next-valid").unwrap() == "2020-03-01\n");
+		assert!(run_args("add 2020-01-31 -3D").unwrap() == "2020-01-28\n");
+		assert!(run_args("sub 2020-03-31 1M --clamp --json").unwrap() == "{\"date\":\"2020-02-29\"}\n");
+		assert!(let Err(Error::Usage(_)) = run_args("add 2020-01-31 1M --clamp --next-valid"));
+		assert!(let Err(Error::Usage(_)) = run_args("add 2020-01-31"));
+		assert!(let Err(Error::Failed(_)) = run_args("add 2020-01-31 1Q"));
+		assert!(let Err(Error::Failed(_)) = run_args("add 2020-01-01 99999Y --clamp"));
+		assert!(let Err(Error::Failed(_)) = run_args("add 2020-01-01 -2147483648M"));
+		assert!(let Err(Error::Failed(_)) = run_args("sub 2020-01-01 -2147483648D"));
+		assert!(let Err(Error::Failed(_)) = run_args("add -32768-01-01 1D"));
+	}
+
+	#[test]
+	fn diff() {
+		assert!(run_args("diff 2020-01-01 2021-03-05").unwrap() == "429 days (1 year, 2 months, 4 days)\n");
+		assert!(run_args("diff 2021-03-05 2020-01-01").unwrap() == "-429 days (-1 year, -2 months, -4 days)\n");
+		assert!(run_args("diff 2020-01-31 2020-02-29").unwrap() == "29 days (0 years, 1 month, 0 days)\n");
+		let_assert!(Ok(json) = run_args("--json diff 2020-01-01 2020-01-02"));
+		assert!(json == "{\"calendar\":{\"days\":1,\"months\":0,\"years\":0},\"days\":1,\"from\":\"2020-01-01\",\"to\":\"2020-01-02\"}\n");
+	}
+
+	#[test]
+	fn weekday_and_iso_week() {
+		assert!(run_args("weekday 2024-02-29").unwrap() == "Thursday\n");
+		assert!(run_args("weekday 2024-02-29 --json").unwrap() == "{\"date\":\"2024-02-29\",\"number\":4,\"weekday\":\"Thursday\"}\n");
+		assert!(run_args("iso-week 2020-12-31").unwrap() == "2020-W53-4\n");
+		assert!(run_args("iso-week 2021-01-03").unwrap() == "2020-W53-7\n");
+		assert!(run_args("iso-week 2021-01-04 --json").unwrap() == "{\"date\":\"2021-01-04\",\"week\":1,\"weekday\":1,\"year\":2021}\n");
+	}
+
+	#[test]
+	fn cal() {
+		let_assert!(Ok(text) = run_args("cal 2024-02 --sunday"));
+		assert!(text.starts_with("   February 2024\nSu Mo Tu We Th Fr Sa\n"));
+		let_assert!(Ok(text) = run_args("cal 2024"));
+		assert!(text.starts_with("                              2024\n"));
+		let_assert!(Ok(json) = run_args("cal 2021-02 --json"));
+		let_assert!(Ok(json) = serde_json::from_str::<Value>(&json));
+		assert!(json["month"] == "2021-02");
+		assert!(json["weeks"].as_array().unwrap().len() == 4);
+		assert!(json["weeks"][0]["dates"][0] == "2021-02-01");
+		assert!(let Err(Error::Failed(_)) = run_args("cal 2024-13"));
+		assert!(let Err(Error::Failed(_)) = run_args("cal 32767"));
+		assert!(let Err(Error::Failed(_)) = run_args("cal é"));
+		assert!(let Err(Error::Failed(_)) = run(&["cal".to_string(), String::new()]));
+	}
+
+	#[test]
+	fn convert() {
+		assert!(run_args("convert --to jdn 2020-01-01").unwrap() == "2458850\n");
+		assert!(run_args("convert --from jdn --to date 2458850").unwrap() == "2020-01-01\n");
+		assert!(run_args("convert --from mjd 58849 --to date").unwrap() == "2020-01-01\n");
+		assert!(run_args("convert --from iso-week --to date 2020-W53-4").unwrap() == "2020-12-31\n");
+		assert!(run_args("convert --from ordinal --to date 2020-366").unwrap() == "2020-12-31\n");
+		assert!(run_args("convert --from unix --to date 1592611200").unwrap() == "2020-06-20\n");
+		assert!(run_args("convert 2020-12-31").unwrap() == concat!(
+			"date: 2020-12-31\n",
+			"jdn: 2459215\n",
+			"mjd: 59214\n",
+			"unix: 1609372800\n",
+			"ordinal: 2020-366\n",
+			"iso-week: 2020-W53-4\n",
+		));
+		assert!(run_args("convert --to unix --json 1970-01-02").unwrap() == "86400\n");
+		assert!(let Err(Error::Usage(_)) = run_args("convert --to julian 2020-01-01"));
+		assert!(let Err(Error::Failed(_)) = run_args("convert --from iso-week 2021-W53-1"));
+		assert!(let Err(Error::Failed(_)) = run_args("convert --from jdn 2147483647"));
+		assert!(let Err(Error::Failed(_)) = run_args("convert --from jdn -2147483648"));
+		assert!(let Err(Error::Failed(_)) = run_args("convert --from mjd 9223372036854775807"));
+		assert!(let Err(Error::Failed(_)) = run_args("convert --from unix -9223372036854775808"));
+		assert!(let Err(Error::Failed(_)) = run_args("convert --from ordinal 32767-001"));
+		assert!(let Err(Error::Failed(_)) = run_args("convert --from iso-week -32768-W01-1"));
+		assert!(run_args("convert --from jdn --to date 0").unwrap() == "-4713-11-24\n");
+	}
+
+	#[test]
+	fn usage_errors() {
+		assert!(let Err(Error::Usage(_)) = run_args(""));
+		assert!(let Err(Error::Usage(_)) = run_args("frobnicate"));
+		assert!(let Err(Error::Usage(_)) = run_args("weekday --bogus 2020-01-01"));
+		assert!(run_args("--help").unwrap() == USAGE);
+	}
+}
@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+
+use crate::{Date, DateRange, Year, YearMonth};
+
+/// A way to divide dates into consecutive buckets, such as weeks or months.
+///
+/// Each bucket is identified by a key, and every date belongs to exactly one bucket.
+pub trait Bucketing {
+	/// The key that identifies a bucket.
+	type Key: Copy + Ord;
+
+	/// Get the key of the bucket that contains a date.
+	fn bucket(&self, date: Date) -> Self::Key;
+
+	/// Get the key of the bucket after the given bucket.
+	fn next_bucket(&self, key: Self::Key) -> Self::Key;
+}
+
+/// Group dates by week, using the first day of the week as key.
+///
+/// The first day of the week is determined by the [`WeekRules`](crate::WeekRules).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ByWeek(pub crate::WeekRules);
+
+/// Group dates by month, using the [`YearMonth`] as key.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ByMonth;
+
+/// Group dates by quarter, using the first month of the quarter as key.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ByQuarter;
+
+/// Group dates by year, using the [`Year`] as key.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ByYear;
+
+impl Bucketing for ByWeek {
+	type Key = Date;
+
+	fn bucket(&self, date: Date) -> Date {
+		self.0.week_start(date)
+	}
+
+	fn next_bucket(&self, key: Date) -> Date {
+		key.add_days(7)
+	}
+}
+
+impl Bucketing for ByMonth {
+	type Key = YearMonth;
+
+	fn bucket(&self, date: Date) -> YearMonth {
+		date.year_month()
+	}
+
+	fn next_bucket(&self, key: YearMonth) -> YearMonth {
+		key.next()
+	}
+}
+
+impl Bucketing for ByQuarter {
+	type Key = YearMonth;
+
+	fn bucket(&self, date: Date) -> YearMonth {
+		let month = date.month().to_number();
+		date.year_month().sub_months(i32::from((month - 1) % 3))
+	}
+
+	fn next_bucket(&self, key: YearMonth) -> YearMonth {
+		key.add_months(3)
+	}
+}
+
+impl Bucketing for ByYear {
+	type Key = Year;
+
+	fn bucket(&self, date: Date) -> Year {
+		date.year()
+	}
+
+	fn next_bucket(&self, key: Year) -> Year {
+		key.next()
+	}
+}
+
+/// Group or count dates into buckets, such as weeks, months, quarters or years.
+///
+/// By default, only buckets that contain at least one date are returned.
+/// Use [`Self::with_empty_buckets()`] to also get the empty buckets between the first and the last date,
+/// or [`Self::with_range()`] to get all buckets of a fixed range of dates.
+///
+/// ```
+/// # use gregorian::{ByMonth, Date, DateGrouper, Month::*, YearMonth};
+/// let dates = [
+///     Date::new(2020, January, 10).unwrap(),
+///     Date::new(2020, January, 20).unwrap(),
+///     Date::new(2020, March, 5).unwrap(),
+/// ];
+/// let counts = DateGrouper::new(ByMonth).with_empty_buckets().count(dates);
+/// assert!(counts == [
+///     (YearMonth::new(2020, January), 2),
+///     (YearMonth::new(2020, February), 0),
+///     (YearMonth::new(2020, March), 1),
+/// ]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct DateGrouper<B> {
+	bucketing: B,
+	empty_buckets: bool,
+	range: Option<DateRange>,
+}
+
+impl<B: Bucketing> DateGrouper<B> {
+	/// Create a new grouper with the given bucketing.
+	pub const fn new(bucketing: B) -> Self {
+		Self {
+			bucketing,
+			empty_buckets: false,
+			range: None,
+		}
+	}
+
+	/// Also return the empty buckets between the first and the last bucket.
+	pub const fn with_empty_buckets(mut self) -> Self {
+		self.empty_buckets = true;
+		self
+	}
+
+	/// Only group the dates in a range, and return all buckets that overlap the range, including empty buckets.
+	///
+	/// Dates outside of the range are ignored.
+	pub fn with_range(mut self, range: impl Into<DateRange>) -> Self {
+		self.range = Some(range.into());
+		self.empty_buckets = true;
+		self
+	}
+
+	/// Get the bucketing of the grouper.
+	pub const fn bucketing(&self) -> &B {
+		&self.bucketing
+	}
+
+	/// Group values into buckets by their date.
+	///
+	/// The buckets are sorted by key, and the values in a bucket are in the order of the input.
+	pub fn group<V>(&self, items: impl IntoIterator<Item = (Date, V)>) -> Vec<(B::Key, Vec<V>)> {
+		let mut groups = BTreeMap::new();
+		for (date, value) in items {
+			if self.range.is_some_and(|range| !range.contains(date)) {
+				continue;
+			}
+			groups.entry(self.bucketing.bucket(date)).or_insert_with(Vec::new).push(value);
+		}
+
+		let bounds = match self.range {
+			Some(range) if !range.is_empty() => Some((self.bucketing.bucket(range.start()), self.bucketing.bucket(range.end().prev()))),
+			Some(_) => None,
+			None => groups.keys().next().copied().zip(groups.keys().next_back().copied()),
+		};
+		let Some((first, last)) = bounds.filter(|_| self.empty_buckets) else {
+			return groups.into_iter().collect();
+		};
+
+		let mut result = Vec::new();
+		let mut key = first;
+		while key <= last {
+			result.push((key, groups.remove(&key).unwrap_or_default()));
+			key = self.bucketing.next_bucket(key);
+		}
+		result
+	}
+
+	/// Count the number of dates in each bucket.
+	///
+	/// The buckets are sorted by key.
+	pub fn count(&self, dates: impl IntoIterator<Item = Date>) -> Vec<(B::Key, usize)> {
+		self.group(dates.into_iter().map(|date| (date, ())))
+			.into_iter()
+			.map(|(key, values)| (key, values.len()))
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, WeekRules};
+	use crate::util::date;
+	use assert2::assert;
+
+	#[test]
+	fn group_by_month() {
+		let items = [
+			(date(2020, March, 5), "c"),
+			(date(2020, January, 10), "a"),
+			(date(2020, January, 20), "b"),
+		];
+		assert!(DateGrouper::new(ByMonth).group(items) == [
+			(YearMonth::new(2020, January), vec!["a", "b"]),
+			(YearMonth::new(2020, March), vec!["c"]),
+		]);
+		assert!(DateGrouper::new(ByMonth).with_empty_buckets().group(items) == [
+			(YearMonth::new(2020, January), vec!["a", "b"]),
+			(YearMonth::new(2020, February), vec![]),
+			(YearMonth::new(2020, March), vec!["c"]),
+		]);
+		assert!(DateGrouper::new(ByMonth).count(items.map(|(date, _)| date)) == [
+			(YearMonth::new(2020, January), 2),
+			(YearMonth::new(2020, March), 1),
+		]);
+	}
+
+	#[test]
+	fn group_by_week() {
+		let dates = [date(2020, January, 1), date(2020, January, 5), date(2020, January, 6), date(2020, January, 20)];
+		assert!(DateGrouper::new(ByWeek(WeekRules::ISO)).with_empty_buckets().count(dates) == [
+			(date(2019, December, 30), 2),
+			(date(2020, January, 6), 1),
+			(date(2020, January, 13), 0),
+			(date(2020, January, 20), 1),
+		]);
+		assert!(DateGrouper::new(ByWeek(WeekRules::US)).count(dates) == [
+			(date(2019, December, 29), 1),
+			(date(2020, January, 5), 2),
+			(date(2020, January, 19), 1),
+		]);
+	}
+
+	#[test]
+	fn group_by_quarter_and_year() {
+		let dates = [date(2020, February, 29), date(2020, March, 31), date(2020, April, 1), date(2021, December, 31)];
+		assert!(DateGrouper::new(ByQuarter).count(dates) == [
+			(YearMonth::new(2020, January), 2),
+			(YearMonth::new(2020, April), 1),
+			(YearMonth::new(2021, October), 1),
+		]);
+		assert!(DateGrouper::new(ByQuarter).with_empty_buckets().count(dates).len() == 8);
+		assert!(DateGrouper::new(ByYear).count(dates) == [(Year::new(2020), 3), (Year::new(2021), 1)]);
+	}
+
+	#[test]
+	fn with_range() {
+		let dates = [date(2019, December, 31), date(2020, February, 1), date(2021, January, 1)];
+		let grouper = DateGrouper::new(ByQuarter).with_range(Year::new(2020));
+		assert!(grouper.count(dates) == [
+			(YearMonth::new(2020, January), 1),
+			(YearMonth::new(2020, April), 0),
+			(YearMonth::new(2020, July), 0),
+			(YearMonth::new(2020, October), 0),
+		]);
+		assert!(DateGrouper::new(ByYear).with_range(Year::new(2020)).count([]) == [(Year::new(2020), 0)]);
+		let empty = DateRange::new(date(2020, January, 1), date(2020, January, 1));
+		assert!(DateGrouper::new(ByYear).with_range(empty).count(dates).is_empty());
+	}
+}
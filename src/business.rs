@@ -0,0 +1,465 @@
+use crate::{Date, DateRange, DateResultExt, WeekdaySet};
+
+/// A calendar of business days.
+///
+/// A business day is a day that is not on a weekend day and not a holiday.
+/// The weekend is described by a [`WeekdaySet`], and the holidays are a set of individual dates.
+///
+/// Holidays that fall on a weekend day are ignored, since those days are not business days anyway.
+///
+/// All calculations jump over whole weeks at once and look up holidays with a binary search,
+/// so they remain efficient for long spans of time.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BusinessCalendar {
+	weekend: WeekdaySet,
+	holidays: Vec<Date>,
+}
+
+impl BusinessCalendar {
+	/// Create a new business calendar with the given weekend days and no holidays.
+	///
+	/// # Panics
+	/// This function panics if all weekdays are weekend days,
+	/// since there could never be any business days.
+	pub fn new(weekend: WeekdaySet) -> Self {
+		assert!(weekend != WeekdaySet::ALL, "a business calendar needs at least one working weekday");
+		Self {
+			weekend,
+			holidays: Vec::new(),
+		}
+	}
+
+	/// Create a new business calendar with Saturday and Sunday as weekend and no holidays.
+	pub fn weekdays() -> Self {
+		Self::new(WeekdaySet::WEEKEND)
+	}
+
+	/// Add holidays to the calendar, returning the modified calendar.
+	pub fn with_holidays(mut self, holidays: impl IntoIterator<Item = Date>) -> Self {
+		self.add_holidays(holidays);
+		self
+	}
+
+	/// Add a single holiday to the calendar.
+	pub fn add_holiday(&mut self, date: Date) {
+		if self.weekend.contains_date(date) {
+			return;
+		}
+		if let Err(index) = self.holidays.binary_search(&date) {
+			self.holidays.insert(index, date);
+		}
+	}
+
+	/// Add holidays to the calendar.
+	pub fn add_holidays(&mut self, holidays: impl IntoIterator<Item = Date>) {
+		let weekend = self.weekend;
+		self.holidays.extend(holidays.into_iter().filter(|date| !weekend.contains_date(*date)));
+		self.holidays.sort_unstable();
+		self.holidays.dedup();
+	}
+
+	/// Get the weekend days of the calendar.
+	pub fn weekend(&self) -> WeekdaySet {
+		self.weekend
+	}
+
+	/// Get the working weekdays of the calendar.
+	pub fn workdays(&self) -> WeekdaySet {
+		!self.weekend
+	}
+
+	/// Get the holidays of the calendar that fall on working weekdays, in sorted order.
+	pub fn holidays(&self) -> &[Date] {
+		&self.holidays
+	}
+
+	/// Check if a date is a holiday that falls on a working weekday.
+	pub fn is_holiday(&self, date: Date) -> bool {
+		self.holidays.binary_search(&date).is_ok()
+	}
+
+	/// Check if a date is a business day.
+	pub fn is_business_day(&self, date: Date) -> bool {
+		!self.weekend.contains_date(date) && !self.is_holiday(date)
+	}
+
+	/// Get the first business day after `date`.
+	pub fn next_business_day(&self, date: Date) -> Date {
+		self.add_business_days(date, 1)
+	}
+
+	/// Get the last business day before `date`.
+	pub fn prev_business_day(&self, date: Date) -> Date {
+		self.add_business_days(date, -1)
+	}
+
+	/// Get `date` itself if it is a business day, or else the first business day after it.
+	pub fn business_day_on_or_after(&self, date: Date) -> Date {
+		if self.is_business_day(date) {
+			date
+		} else {
+			self.next_business_day(date)
+		}
+	}
+
+	/// Get `date` itself if it is a business day, or else the last business day before it.
+	pub fn business_day_on_or_before(&self, date: Date) -> Date {
+		if self.is_business_day(date) {
+			date
+		} else {
+			self.prev_business_day(date)
+		}
+	}
+
+	/// Compute a date by adding a number of business days.
+	///
+	/// The start date itself is never counted.
+	/// For a positive `days`, this returns the business day that is `days` business days after `date`.
+	/// For a negative `days`, this returns the business day that is `-days` business days before `date`.
+	/// If `days` is zero, the date is returned unchanged, even if it is not a business day.
+	pub fn add_business_days(&self, date: Date, days: i32) -> Date {
+		let mut current = date;
+		let mut remaining = days;
+		loop {
+			let target = add_workdays(current, remaining, self.workdays());
+
+			// Any holidays we skipped over must be made up for with extra days.
+			let skipped = if remaining >= 0 {
+				self.count_holidays(current.next(), target.next())
+			} else {
+				-self.count_holidays(target, current)
+			};
+
+			if skipped == 0 {
+				return target;
+			}
+			current = target;
+			remaining = skipped;
+		}
+	}
+
+	/// Compute a date by subtracting a number of business days.
+	///
+	/// This is equivalent to [`Self::add_business_days()`] with the negated number of days.
+	pub fn sub_business_days(&self, date: Date, days: i32) -> Date {
+		self.add_business_days(date, -days)
+	}
+
+	/// Count the number of business days from `start` until `end`.
+	///
+	/// The start date is included if it is a business day, but the end date is not.
+	/// If `end` is before `start`, the result is negative.
+	///
+	/// If both dates are business days, then adding the result to `start` with [`Self::add_business_days()`] gives `end`.
+	pub fn business_days_between(&self, start: Date, end: Date) -> i32 {
+		if end < start {
+			-self.business_days_between(end, start)
+		} else {
+			self.business_days_in(DateRange::new(start, end)) as i32
+		}
+	}
+
+	/// Count the number of business days in a date range.
+	pub fn business_days_in(&self, range: DateRange) -> u32 {
+		if range.is_empty() {
+			return 0;
+		}
+		range.count_matching(self.workdays()) - self.count_holidays(range.start(), range.end()) as u32
+	}
+
+	/// Count the holidays in the half-open range from `start` to `end`.
+	fn count_holidays(&self, start: Date, end: Date) -> i32 {
+		let start = self.holidays.partition_point(|x| *x < start);
+		let end = self.holidays.partition_point(|x| *x < end);
+		end.saturating_sub(start) as i32
+	}
+}
+
+/// A convention for adjusting dates that do not fall on a business day.
+///
+/// These are the standard business day conventions used for payment dates in financial contracts.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BusinessDayConvention {
+	/// Do not adjust the date.
+	Unadjusted,
+
+	/// Use the first business day after the date.
+	Following,
+
+	/// Use the first business day after the date,
+	/// unless that is in the next month, then use the last business day before the date.
+	ModifiedFollowing,
+
+	/// Use the last business day before the date.
+	Preceding,
+
+	/// Use the last business day before the date,
+	/// unless that is in the previous month, then use the first business day after the date.
+	ModifiedPreceding,
+
+	/// Use the nearest business day.
+	///
+	/// If the previous and next business day are equally far away, the next business day is used.
+	Nearest,
+}
+
+impl BusinessDayConvention {
+	/// Adjust a date according to the convention.
+	///
+	/// If `date` is a business day, it is always returned unchanged.
+	pub fn adjust(self, date: Date, calendar: &BusinessCalendar) -> Date {
+		if calendar.is_business_day(date) {
+			return date;
+		}
+		match self {
+			Self::Unadjusted => date,
+			Self::Following => calendar.next_business_day(date),
+			Self::Preceding => calendar.prev_business_day(date),
+			Self::ModifiedFollowing => {
+				let adjusted = calendar.next_business_day(date);
+				if adjusted.year_month() == date.year_month() {
+					adjusted
+				} else {
+					calendar.prev_business_day(date)
+				}
+			},
+			Self::ModifiedPreceding => {
+				let adjusted = calendar.prev_business_day(date);
+				if adjusted.year_month() == date.year_month() {
+					adjusted
+				} else {
+					calendar.next_business_day(date)
+				}
+			},
+			Self::Nearest => {
+				let next = calendar.next_business_day(date);
+				let prev = calendar.prev_business_day(date);
+				if date.days_since(next) <= prev.days_since(date) {
+					next
+				} else {
+					prev
+				}
+			},
+		}
+	}
+
+	/// Add a number of months to a date and adjust the result according to the convention.
+	///
+	/// If the day of the month does not exist in the resulting month,
+	/// the last day of that month is used before the adjustment is applied.
+	pub fn add_months(self, date: Date, months: i32, calendar: &BusinessCalendar) -> Date {
+		self.adjust(date.add_months(months).or_prev_valid(), calendar)
+	}
+
+	/// Add a number of years to a date and adjust the result according to the convention.
+	///
+	/// If the date is a leap day and the resulting year has no leap day,
+	/// 28 February is used before the adjustment is applied.
+	pub fn add_years(self, date: Date, years: i16, calendar: &BusinessCalendar) -> Date {
+		self.adjust(date.add_years(years).or_prev_valid(), calendar)
+	}
+}
+
+/// Add a number of days that fall on a weekday in `workdays`, without considering holidays.
+///
+/// The start date itself is never counted.
+fn add_workdays(date: Date, days: i32, workdays: WeekdaySet) -> Date {
+	if days == 0 {
+		return date;
+	}
+
+	let per_week = workdays.len() as i32;
+	let forward = days > 0;
+	let days = days.abs();
+
+	// Jump over whole weeks, but leave at least one workday to step to.
+	let weeks = (days - 1) / per_week;
+	let mut remaining = days - weeks * per_week;
+	let mut date = if forward {
+		date.add_days(weeks * 7)
+	} else {
+		date.sub_days(weeks * 7)
+	};
+
+	while remaining > 0 {
+		date = if forward {
+			let date = date.next();
+			date.add_days(workdays.days_until_next(date.weekday()).unwrap_or(0) as i32)
+		} else {
+			let date = date.prev();
+			date.sub_days(workdays.days_since_prev(date.weekday()).unwrap_or(0) as i32)
+		};
+		remaining -= 1;
+	}
+	date
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, Weekday::*, Year};
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	fn calendar() -> BusinessCalendar {
+		BusinessCalendar::weekdays().with_holidays([
+			date(2020, December, 25),
+			date(2020, December, 26), // Saturday, ignored.
+			date(2021, January, 1),
+			date(2021, April, 5),
+			date(2021, December, 24),
+		])
+	}
+
+	/// Reference implementation stepping one day at a time.
+	fn add_business_days_slow(calendar: &BusinessCalendar, mut date: Date, days: i32) -> Date {
+		for _ in 0..days.abs() {
+			loop {
+				date = if days > 0 { date.next() } else { date.prev() };
+				if calendar.is_business_day(date) {
+					break;
+				}
+			}
+		}
+		date
+	}
+
+	#[test]
+	fn is_business_day() {
+		let calendar = calendar();
+		assert!(calendar.holidays().len() == 4);
+		assert!(calendar.is_business_day(date(2020, December, 24)));
+		assert!(!calendar.is_business_day(date(2020, December, 25)));
+		assert!(!calendar.is_business_day(date(2020, December, 26)));
+		assert!(!calendar.is_business_day(date(2020, December, 27)));
+		assert!(calendar.is_business_day(date(2020, December, 28)));
+		assert!(!calendar.is_holiday(date(2020, December, 26)));
+	}
+
+	#[test]
+	fn next_prev_business_day() {
+		let calendar = calendar();
+		assert!(calendar.next_business_day(date(2020, December, 24)) == date(2020, December, 28));
+		assert!(calendar.prev_business_day(date(2020, December, 28)) == date(2020, December, 24));
+		assert!(calendar.next_business_day(date(2020, December, 31)) == date(2021, January, 4));
+		assert!(calendar.business_day_on_or_after(date(2020, December, 25)) == date(2020, December, 28));
+		assert!(calendar.business_day_on_or_after(date(2020, December, 24)) == date(2020, December, 24));
+		assert!(calendar.business_day_on_or_before(date(2021, January, 3)) == date(2020, December, 31));
+	}
+
+	#[test]
+	fn add_business_days() {
+		let calendar = calendar();
+		assert!(calendar.add_business_days(date(2020, December, 24), 0) == date(2020, December, 24));
+		assert!(calendar.add_business_days(date(2020, December, 26), 0) == date(2020, December, 26));
+		assert!(calendar.add_business_days(date(2020, December, 24), 1) == date(2020, December, 28));
+		assert!(calendar.add_business_days(date(2020, December, 24), 5) == date(2021, January, 4));
+		assert!(calendar.add_business_days(date(2021, January, 4), -5) == date(2020, December, 24));
+		assert!(calendar.sub_business_days(date(2021, January, 4), 5) == date(2020, December, 24));
+
+		let start = date(2020, December, 1);
+		for offset in 0..20 {
+			let start = start.add_days(offset);
+			for days in -300..300 {
+				assert!(calendar.add_business_days(start, days) == add_business_days_slow(&calendar, start, days));
+			}
+		}
+	}
+
+	#[test]
+	fn add_business_days_custom_weekend() {
+		let calendar = BusinessCalendar::new(WeekdaySet::from_array([Friday, Saturday]))
+			.with_holidays([date(2024, April, 10), date(2024, April, 11)]);
+		let start = date(2024, March, 1);
+		for days in -100..100 {
+			assert!(calendar.add_business_days(start, days) == add_business_days_slow(&calendar, start, days));
+		}
+
+		let calendar = BusinessCalendar::new(WeekdaySet::range(Tuesday, Sunday));
+		assert!(calendar.add_business_days(date(2024, March, 4), 1) == date(2024, March, 11));
+		assert!(calendar.add_business_days(date(2024, March, 5), -1) == date(2024, March, 4));
+		assert!(calendar.add_business_days(date(2024, March, 5), 52) == date(2025, March, 3));
+	}
+
+	#[test]
+	fn business_days_between() {
+		let calendar = calendar();
+		assert!(calendar.business_days_between(date(2020, December, 24), date(2020, December, 24)) == 0);
+		assert!(calendar.business_days_between(date(2020, December, 24), date(2020, December, 28)) == 1);
+		assert!(calendar.business_days_between(date(2020, December, 28), date(2020, December, 24)) == -1);
+		assert!(calendar.business_days_between(date(2021, January, 1), date(2022, January, 1)) == 258);
+		assert!(calendar.business_days_in(Year::new(2021).days()) == 258);
+
+		let start = date(2020, December, 1);
+		for offset in -50..400 {
+			let end = start.add_days(offset);
+			let expected = if offset >= 0 {
+				DateRange::new(start, end).iter().filter(|x| calendar.is_business_day(*x)).count() as i32
+			} else {
+				-(DateRange::new(end, start).iter().filter(|x| calendar.is_business_day(*x)).count() as i32)
+			};
+			assert!(calendar.business_days_between(start, end) == expected);
+			if calendar.is_business_day(end) {
+				assert!(calendar.add_business_days(start, expected) == end);
+			}
+		}
+	}
+
+	#[test]
+	fn adjust() {
+		use BusinessDayConvention::*;
+		let calendar = calendar();
+
+		// A business day is never adjusted.
+		for convention in [Unadjusted, Following, ModifiedFollowing, Preceding, ModifiedPreceding, Nearest] {
+			assert!(convention.adjust(date(2020, December, 24), &calendar) == date(2020, December, 24));
+		}
+
+		// Saturday 26 December 2020, after a holiday on Friday.
+		let saturday = date(2020, December, 26);
+		assert!(Unadjusted.adjust(saturday, &calendar) == saturday);
+		assert!(Following.adjust(saturday, &calendar) == date(2020, December, 28));
+		assert!(ModifiedFollowing.adjust(saturday, &calendar) == date(2020, December, 28));
+		assert!(Preceding.adjust(saturday, &calendar) == date(2020, December, 24));
+		assert!(ModifiedPreceding.adjust(saturday, &calendar) == date(2020, December, 24));
+		assert!(Nearest.adjust(saturday, &calendar) == date(2020, December, 28));
+
+		// Sunday 1 August 2021, at the start of a month.
+		let sunday = date(2021, August, 1);
+		assert!(Preceding.adjust(sunday, &calendar) == date(2021, July, 30));
+		assert!(ModifiedPreceding.adjust(sunday, &calendar) == date(2021, August, 2));
+		assert!(Nearest.adjust(sunday, &calendar) == date(2021, August, 2));
+
+		// Saturday 31 July 2021, at the end of a month.
+		let saturday = date(2021, July, 31);
+		assert!(Following.adjust(saturday, &calendar) == date(2021, August, 2));
+		assert!(ModifiedFollowing.adjust(saturday, &calendar) == date(2021, July, 30));
+		assert!(Nearest.adjust(saturday, &calendar) == date(2021, July, 30));
+
+		// Saturday 25 December 2021 is mid-month and Friday 24 December is a holiday, so both roll forward to Monday.
+		assert!(ModifiedFollowing.adjust(date(2021, December, 25), &calendar) == date(2021, December, 27));
+		assert!(Nearest.adjust(date(2021, December, 25), &calendar) == date(2021, December, 27));
+	}
+
+	#[test]
+	fn add_months() {
+		use BusinessDayConvention::*;
+		let calendar = calendar();
+		assert!(ModifiedFollowing.add_months(date(2021, January, 31), 1, &calendar) == date(2021, February, 26));
+		assert!(Following.add_months(date(2021, March, 31), 1, &calendar) == date(2021, April, 30));
+		assert!(ModifiedFollowing.add_months(date(2021, May, 31), 2, &calendar) == date(2021, July, 30));
+		assert!(Following.add_months(date(2021, May, 31), 2, &calendar) == date(2021, August, 2));
+		assert!(Preceding.add_months(date(2021, March, 5), -3, &calendar) == date(2020, December, 4));
+		assert!(ModifiedFollowing.add_years(date(2020, February, 29), 1, &calendar) == date(2021, February, 26));
+		assert!(Unadjusted.add_years(date(2020, February, 29), 1, &calendar) == date(2021, February, 28));
+	}
+
+	#[test]
+	fn serde() {
+		let_assert!(Ok(serialized) = serde_yaml::to_string(&BusinessDayConvention::ModifiedFollowing));
+		assert!(serialized == "ModifiedFollowing\n");
+		let_assert!(Ok(parsed) = serde_yaml::from_str::<BusinessDayConvention>("Preceding"));
+		assert!(parsed == BusinessDayConvention::Preceding);
+	}
+}
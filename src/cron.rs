@@ -0,0 +1,651 @@
+use crate::date::MIN_YEAR;
+use crate::{Date, InvalidCronSyntax, Month, Weekday, WeekdaySet, YearMonth};
+
+const EXPECTED_FIELDS: &str = "three fields for the day of month, month and day of week, like \"L * ?\"";
+const EXPECTED_DAY_OF_MONTH: &str = "a day of month like \"15\", \"1-10/2\", \"L\", \"L-3\", \"15W\" or \"LW\"";
+const EXPECTED_MONTH: &str = "a month like \"3\", \"MAR\", \"1-6\" or \"*/3\"";
+const EXPECTED_DAY_OF_WEEK: &str = "a day of week like \"2\", \"MON-FRI\", \"6L\" or \"6#3\"";
+
+const MONTH_NAMES: [&str; 12] = ["JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC"];
+const WEEKDAY_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// The number of months after which the Gregorian calendar repeats, including the weekdays.
+///
+/// If an expression does not match any day in this many months, it never matches.
+const SEARCH_MONTHS: u32 = 400 * 12;
+
+/// The date part of a cron expression, selecting days by day of month, month and day of week.
+///
+/// The expression consists of three whitespace separated fields, in the same order as in a cron expression:
+/// the day of month, the month and the day of week.
+/// For example, `"L * ?"` selects the last day of every month, and `"? * MON-FRI"` selects all weekdays.
+///
+/// Each field is a comma separated list of values, ranges like `1-5`, and steps like `1-31/2`, `10/5` or `*/3`.
+/// A field of `*` or `?` matches any value.
+/// Months can also be written as `JAN` to `DEC`.
+/// Days of the week are numbered from 1 (Sunday) to 7 (Saturday) as in Quartz, or written as `SUN` to `SAT`.
+///
+/// The Quartz extensions are supported:
+/// * `L` in the day of month is the last day of the month, and `L-3` is three days before the last day.
+/// * `15W` in the day of month is the weekday nearest to the 15th, without crossing into another month.
+/// * `LW` in the day of month is the last weekday of the month.
+/// * `6L` in the day of week is the last Friday of the month, and `L` on its own is Saturday.
+/// * `6#3` in the day of week is the third Friday of the month.
+///
+/// If both the day of month and the day of week are restricted (not `*` or `?`),
+/// a day matches if it matches *either* field, as in traditional cron.
+/// So `"13 * FRI"` selects every 13th of the month and every Friday.
+/// If only one of them is restricted, that field alone decides.
+/// The month must always match.
+///
+/// ```
+/// # use gregorian::{CronDays, Date};
+/// let third_friday: CronDays = "? * 6#3".parse().unwrap();
+/// let date = Date::new(2024, 1, 1).unwrap();
+/// assert!(third_friday.next_after(date) == Some(Date::new(2024, 1, 19).unwrap()));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CronDays {
+	days_of_month: DayOfMonthField,
+	months: u16,
+	days_of_week: DayOfWeekField,
+}
+
+/// The parsed day of month field.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+struct DayOfMonthField {
+	/// True if the field is `*` or `?`.
+	any: bool,
+
+	/// Bit `n` is set for each day of month `n`.
+	days: u32,
+
+	/// Bit `n` is set for each `L-n`, with bit 0 for `L`.
+	last: u32,
+
+	/// Bit `n` is set for each `nW`.
+	nearest_weekday: u32,
+
+	/// True if the field contains `LW`.
+	last_weekday: bool,
+}
+
+/// The parsed day of week field.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+struct DayOfWeekField {
+	/// True if the field is `*` or `?`.
+	any: bool,
+
+	/// The weekdays that match every week.
+	weekdays: WeekdaySet,
+
+	/// The weekdays that match in the last week of the month (`nL`).
+	last: WeekdaySet,
+
+	/// The weekdays that match in the n-th week of the month (`n#k`).
+	nth: [WeekdaySet; 5],
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for CronDays {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for CronDays {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = std::borrow::Cow::<'_, str>::deserialize(deserializer)?;
+		raw.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+impl CronDays {
+	/// Check if a date matches the expression.
+	pub fn matches(&self, date: Date) -> bool {
+		self.months & (1 << date.month().to_number()) != 0 && self.matches_day(date.year_month(), date.day())
+	}
+
+	/// Get the first matching date strictly after `date`.
+	///
+	/// Returns `None` if the expression never matches.
+	pub fn next_after(&self, date: Date) -> Option<Date> {
+		let mut year_month = date.year_month();
+		let mut first_day = date.day() + 1;
+		for _ in 0..=SEARCH_MONTHS {
+			if self.months & (1 << year_month.month().to_number()) != 0 {
+				if let Some(day) = (first_day..=year_month.total_days()).find(|&day| self.matches_day(year_month, day)) {
+					return year_month.with_day(day).ok();
+				}
+			}
+			if year_month == YearMonth::new(i16::MAX, Month::December) {
+				return None;
+			}
+			year_month = year_month.next();
+			first_day = 1;
+		}
+		None
+	}
+
+	/// Get the last matching date strictly before `date`.
+	///
+	/// Returns `None` if the expression never matches,
+	/// or if there is no match before the start of the supported range of years.
+	pub fn prev_before(&self, date: Date) -> Option<Date> {
+		if date.year().to_number() < MIN_YEAR {
+			return None;
+		}
+		let mut year_month = date.year_month();
+		let mut last_day = date.day() - 1;
+		for _ in 0..=SEARCH_MONTHS {
+			if self.months & (1 << year_month.month().to_number()) != 0 {
+				if let Some(day) = (1..=last_day).rev().find(|&day| self.matches_day(year_month, day)) {
+					return year_month.with_day(day).ok();
+				}
+			}
+			if year_month <= YearMonth::new(MIN_YEAR, Month::January) {
+				return None;
+			}
+			year_month = year_month.prev();
+			last_day = year_month.total_days();
+		}
+		None
+	}
+
+	/// Iterate over the matching dates strictly after `date`, in ascending order.
+	pub fn iter_after(&self, date: Date) -> CronDaysIter {
+		CronDaysIter {
+			days: *self,
+			current: Some(date),
+			forward: true,
+		}
+	}
+
+	/// Iterate over the matching dates strictly before `date`, in descending order.
+	pub fn iter_before(&self, date: Date) -> CronDaysIter {
+		CronDaysIter {
+			days: *self,
+			current: Some(date),
+			forward: false,
+		}
+	}
+
+	/// Check if a day in a month matches the day of month and day of week fields.
+	fn matches_day(&self, year_month: YearMonth, day: u8) -> bool {
+		let day_of_month = (!self.days_of_month.any).then(|| self.days_of_month.matches(year_month, day));
+		let day_of_week = (!self.days_of_week.any).then(|| self.days_of_week.matches(year_month, day));
+		match (day_of_month, day_of_week) {
+			(None, None) => true,
+			(Some(matches), None) | (None, Some(matches)) => matches,
+			(Some(a), Some(b)) => a || b,
+		}
+	}
+}
+
+impl DayOfMonthField {
+	fn matches(&self, year_month: YearMonth, day: u8) -> bool {
+		let total_days = year_month.total_days();
+		if self.days & (1 << day) != 0 || self.last & (1 << (total_days - day)) != 0 {
+			return true;
+		}
+		let weekday = match year_month.with_day(day) {
+			Ok(date) => date.weekday(),
+			Err(_) => return false,
+		};
+		if weekday == Weekday::Saturday || weekday == Weekday::Sunday {
+			return false;
+		}
+		if self.last_weekday && nearest_weekday(year_month, total_days) == Some(day) {
+			return true;
+		}
+
+		// Only the days up to two days away can have this day as nearest weekday.
+		let first = day.saturating_sub(2).max(1);
+		let last = (day + 2).min(total_days);
+		(first..=last).any(|target| self.nearest_weekday & (1 << target) != 0 && nearest_weekday(year_month, target) == Some(day))
+	}
+}
+
+impl DayOfWeekField {
+	fn matches(&self, year_month: YearMonth, day: u8) -> bool {
+		let weekday = match year_month.with_day(day) {
+			Ok(date) => date.weekday(),
+			Err(_) => return false,
+		};
+		if self.weekdays.contains(weekday) {
+			return true;
+		}
+		if self.last.contains(weekday) && day + 7 > year_month.total_days() {
+			return true;
+		}
+		self.nth[usize::from((day - 1) / 7)].contains(weekday)
+	}
+}
+
+/// Get the weekday nearest to a day of the month, without leaving the month.
+fn nearest_weekday(year_month: YearMonth, day: u8) -> Option<u8> {
+	let total_days = year_month.total_days();
+	if day > total_days {
+		return None;
+	}
+	let weekday = year_month.with_day(day).ok()?.weekday();
+	let nearest = match weekday {
+		Weekday::Saturday if day == 1 => day + 2,
+		Weekday::Saturday => day - 1,
+		Weekday::Sunday if day == total_days => day - 2,
+		Weekday::Sunday => day + 1,
+		_ => day,
+	};
+	Some(nearest)
+}
+
+/// Iterator over the dates matching a [`CronDays`] expression.
+///
+/// Created with [`CronDays::iter_after()`] or [`CronDays::iter_before()`].
+#[derive(Copy, Clone, Debug)]
+pub struct CronDaysIter {
+	days: CronDays,
+	current: Option<Date>,
+	forward: bool,
+}
+
+impl Iterator for CronDaysIter {
+	type Item = Date;
+
+	fn next(&mut self) -> Option<Date> {
+		let current = self.current?;
+		let next = if self.forward {
+			self.days.next_after(current)
+		} else {
+			self.days.prev_before(current)
+		};
+		self.current = next;
+		next
+	}
+}
+
+impl core::iter::FusedIterator for CronDaysIter {}
+
+impl core::str::FromStr for CronDays {
+	type Err = InvalidCronSyntax;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		let mut fields = data.split_ascii_whitespace();
+		let (days_of_month, months, days_of_week) = match (fields.next(), fields.next(), fields.next(), fields.next()) {
+			(Some(days_of_month), Some(months), Some(days_of_week), None) => (days_of_month, months, days_of_week),
+			_ => return Err(InvalidCronSyntax::new(EXPECTED_FIELDS)),
+		};
+		Ok(Self {
+			days_of_month: parse_days_of_month(days_of_month).ok_or_else(|| InvalidCronSyntax::new(EXPECTED_DAY_OF_MONTH))?,
+			months: parse_months(months).ok_or_else(|| InvalidCronSyntax::new(EXPECTED_MONTH))?,
+			days_of_week: parse_days_of_week(days_of_week).ok_or_else(|| InvalidCronSyntax::new(EXPECTED_DAY_OF_WEEK))?,
+		})
+	}
+}
+
+fn parse_days_of_month(data: &str) -> Option<DayOfMonthField> {
+	let mut field = DayOfMonthField::default();
+	if data == "*" || data == "?" {
+		field.any = true;
+		return Some(field);
+	}
+	for item in data.split(',') {
+		if item.eq_ignore_ascii_case("L") {
+			field.last |= 1;
+		} else if item.eq_ignore_ascii_case("LW") {
+			field.last_weekday = true;
+		} else if let Some(offset) = item.strip_prefix(['L', 'l']).and_then(|rest| rest.strip_prefix('-')) {
+			field.last |= 1 << parse_number(offset.as_bytes(), 1, 30)?;
+		} else if let Some(day) = item.strip_suffix(['W', 'w']) {
+			field.nearest_weekday |= 1 << parse_number(day.as_bytes(), 1, 31)?;
+		} else {
+			for day in parse_range(item, 1, 31, &[])? {
+				field.days |= 1 << day;
+			}
+		}
+	}
+	Some(field)
+}
+
+fn parse_months(data: &str) -> Option<u16> {
+	if data == "*" || data == "?" {
+		return Some(0b1_1111_1111_1110);
+	}
+	let mut months = 0;
+	for item in data.split(',') {
+		for month in parse_range(item, 1, 12, &MONTH_NAMES)? {
+			months |= 1 << month;
+		}
+	}
+	Some(months)
+}
+
+fn parse_days_of_week(data: &str) -> Option<DayOfWeekField> {
+	let mut field = DayOfWeekField::default();
+	if data == "*" || data == "?" {
+		field.any = true;
+		return Some(field);
+	}
+	for item in data.split(',') {
+		if item.eq_ignore_ascii_case("L") {
+			field.weekdays.insert(Weekday::Saturday);
+		} else if let Some((weekday, n)) = item.split_once('#') {
+			let weekday = parse_weekday(weekday)?;
+			let n = parse_number(n.as_bytes(), 1, 5)?;
+			field.nth[usize::from(n - 1)].insert(weekday);
+		} else if let Some(weekday) = item.strip_suffix(['L', 'l']) {
+			field.last.insert(parse_weekday(weekday)?);
+		} else {
+			for weekday in parse_range(item, 1, 7, &WEEKDAY_NAMES)? {
+				field.weekdays.insert(weekday_from_number(weekday));
+			}
+		}
+	}
+	Some(field)
+}
+
+/// Parse a number or name in the range `min..=max`.
+///
+/// Names are matched case-insensitively, with the first name corresponding to `min`.
+fn parse_value(data: &str, min: u8, max: u8, names: &[&str]) -> Option<u8> {
+	if let Some(index) = names.iter().position(|name| data.eq_ignore_ascii_case(name)) {
+		return Some(min + index as u8);
+	}
+	parse_number(data.as_bytes(), min, max)
+}
+
+/// Parse a number in the range `min..=max`.
+fn parse_number(data: &[u8], min: u8, max: u8) -> Option<u8> {
+	if data.is_empty() || data.len() > 2 || !data.iter().all(|c| c.is_ascii_digit()) {
+		return None;
+	}
+	let value = data.iter().fold(0, |value, c| value * 10 + (c - b'0'));
+	(min..=max).contains(&value).then_some(value)
+}
+
+fn parse_weekday(data: &str) -> Option<Weekday> {
+	Some(weekday_from_number(parse_value(data, 1, 7, &WEEKDAY_NAMES)?))
+}
+
+/// Parse a single value, a range or a step expression and get the selected values.
+fn parse_range(data: &str, min: u8, max: u8, names: &[&str]) -> Option<impl Iterator<Item = u8>> {
+	let (range, step) = match data.split_once('/') {
+		Some((range, step)) => (range, Some(parse_number(step.as_bytes(), 1, max)?)),
+		None => (data, None),
+	};
+	let (start, end) = if range == "*" {
+		(min, max)
+	} else if let Some((start, end)) = range.split_once('-') {
+		(parse_value(start, min, max, names)?, parse_value(end, min, max, names)?)
+	} else {
+		let value = parse_value(range, min, max, names)?;
+		(value, if step.is_some() { max } else { value })
+	};
+	if start > end {
+		return None;
+	}
+	Some((start..=end).step_by(usize::from(step.unwrap_or(1))))
+}
+
+/// Convert a Quartz day of week number to a weekday: 1 is Sunday and 7 is Saturday.
+const fn weekday_from_number(number: u8) -> Weekday {
+	Weekday::Sunday.wrapping_add(number as i8 - 1)
+}
+
+impl core::fmt::Display for CronDays {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let mut list = ListWriter::default();
+		if self.days_of_month.any {
+			write!(f, "{}", if self.days_of_week.any { "*" } else { "?" })?;
+		} else {
+			list.write_ranges(f, (1..=31).filter(|day| self.days_of_month.days & (1 << day) != 0))?;
+			for offset in (0..=30).filter(|offset| self.days_of_month.last & (1 << offset) != 0) {
+				match offset {
+					0 => list.write(f, format_args!("L"))?,
+					offset => list.write(f, format_args!("L-{}", offset))?,
+				}
+			}
+			for day in (1..=31).filter(|day| self.days_of_month.nearest_weekday & (1 << day) != 0) {
+				list.write(f, format_args!("{}W", day))?;
+			}
+			if self.days_of_month.last_weekday {
+				list.write(f, format_args!("LW"))?;
+			}
+		}
+
+		write!(f, " ")?;
+		let mut list = ListWriter::default();
+		if self.months == 0b1_1111_1111_1110 {
+			write!(f, "*")?;
+		} else {
+			list.write_ranges(f, (1..=12).filter(|month| self.months & (1 << month) != 0))?;
+		}
+
+		write!(f, " ")?;
+		let mut list = ListWriter::default();
+		let days_of_week = &self.days_of_week;
+		if days_of_week.any {
+			write!(f, "{}", if self.days_of_month.any { "*" } else { "?" })?;
+		} else {
+			let numbers = |set: WeekdaySet| (1..=7).filter(move |&number| set.contains(weekday_from_number(number)));
+			list.write_ranges(f, numbers(days_of_week.weekdays))?;
+			for number in numbers(days_of_week.last) {
+				list.write(f, format_args!("{}L", number))?;
+			}
+			for (n, set) in days_of_week.nth.iter().enumerate() {
+				for number in numbers(*set) {
+					list.write(f, format_args!("{}#{}", number, n + 1))?;
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Helper to write comma separated lists.
+#[derive(Default)]
+struct ListWriter {
+	first_written: bool,
+}
+
+impl ListWriter {
+	fn write(&mut self, f: &mut core::fmt::Formatter, item: core::fmt::Arguments) -> core::fmt::Result {
+		if self.first_written {
+			write!(f, ",")?;
+		}
+		self.first_written = true;
+		write!(f, "{}", item)
+	}
+
+	/// Write ascending values, merging runs of three or more consecutive values into a range.
+	fn write_ranges(&mut self, f: &mut core::fmt::Formatter, values: impl Iterator<Item = u8>) -> core::fmt::Result {
+		let mut run: Option<(u8, u8)> = None;
+		for value in values {
+			run = match run {
+				Some((start, end)) if end + 1 == value => Some((start, value)),
+				Some((start, end)) => {
+					self.write_run(f, start, end)?;
+					Some((value, value))
+				},
+				None => Some((value, value)),
+			};
+		}
+		if let Some((start, end)) = run {
+			self.write_run(f, start, end)?;
+		}
+		Ok(())
+	}
+
+	fn write_run(&mut self, f: &mut core::fmt::Formatter, start: u8, end: u8) -> core::fmt::Result {
+		match end - start {
+			0 => self.write(f, format_args!("{}", start)),
+			1 => {
+				self.write(f, format_args!("{}", start))?;
+				self.write(f, format_args!("{}", end))
+			},
+			_ => self.write(f, format_args!("{}-{}", start, end)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Month::*;
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	#[track_caller]
+	fn cron(data: &str) -> CronDays {
+		let_assert!(Ok(cron) = data.parse());
+		cron
+	}
+
+	#[track_caller]
+	fn dates_after(data: &str, start: Date, count: usize) -> Vec<Date> {
+		cron(data).iter_after(start).take(count).collect()
+	}
+
+	#[test]
+	fn plain_fields() {
+		let start = date(2024, January, 1);
+		assert!(dates_after("* * *", start, 2) == [date(2024, January, 2), date(2024, January, 3)]);
+		assert!(dates_after("1,15 */3 ?", start, 4) == [
+			date(2024, January, 15),
+			date(2024, April, 1),
+			date(2024, April, 15),
+			date(2024, July, 1),
+		]);
+		assert!(dates_after("10/10 FEB-MAR ?", start, 4) == [
+			date(2024, February, 10),
+			date(2024, February, 20),
+			date(2024, March, 10),
+			date(2024, March, 20),
+		]);
+		assert!(dates_after("? * MON-FRI", date(2024, January, 5), 2) == [date(2024, January, 8), date(2024, January, 9)]);
+		assert!(dates_after("? * 1,7", date(2024, January, 5), 2) == [date(2024, January, 6), date(2024, January, 7)]);
+	}
+
+	#[test]
+	fn day_of_month_or_day_of_week() {
+		// If both fields are restricted, either one can match.
+		let start = date(2024, September, 10);
+		assert!(dates_after("13 * FRI", start, 4) == [
+			date(2024, September, 13),
+			date(2024, September, 20),
+			date(2024, September, 27),
+			date(2024, October, 4),
+		]);
+		assert!(cron("13 * FRI").matches(date(2024, October, 13)));
+		assert!(cron("13 * ?").matches(date(2024, October, 13)));
+		assert!(!cron("? * FRI").matches(date(2024, October, 13)));
+		assert!(!cron("13 JAN FRI").matches(date(2024, October, 13)));
+	}
+
+	#[test]
+	fn last_day() {
+		let start = date(2024, January, 1);
+		assert!(dates_after("L * ?", start, 3) == [date(2024, January, 31), date(2024, February, 29), date(2024, March, 31)]);
+		assert!(dates_after("L-3 * ?", start, 3) == [date(2024, January, 28), date(2024, February, 26), date(2024, March, 28)]);
+		assert!(dates_after("LW * ?", start, 3) == [date(2024, January, 31), date(2024, February, 29), date(2024, March, 29)]);
+	}
+
+	#[test]
+	fn nearest_weekday() {
+		// June 1st 2024 is a Saturday, so the nearest weekday is Monday June 3rd.
+		assert!(cron("1W * ?").next_after(date(2024, May, 31)) == Some(date(2024, June, 3)));
+		// June 15th 2024 is a Saturday, so the nearest weekday is Friday June 14th.
+		assert!(cron("15W * ?").next_after(date(2024, June, 1)) == Some(date(2024, June, 14)));
+		// June 30th 2024 is a Sunday, so the nearest weekday is Friday June 28th.
+		assert!(cron("30W * ?").next_after(date(2024, June, 1)) == Some(date(2024, June, 28)));
+		// September 15th 2024 is a Sunday, so the nearest weekday is Monday September 16th.
+		assert!(cron("15W * ?").next_after(date(2024, September, 1)) == Some(date(2024, September, 16)));
+		// There is no 31st day in April, so "31W" does not match in April.
+		assert!(cron("31W * ?").next_after(date(2024, April, 1)) == Some(date(2024, May, 31)));
+	}
+
+	#[test]
+	fn nth_weekday() {
+		let start = date(2024, January, 1);
+		assert!(dates_after("? * 6#3", start, 3) == [date(2024, January, 19), date(2024, February, 16), date(2024, March, 15)]);
+		assert!(dates_after("? * FRI#5", start, 2) == [date(2024, March, 29), date(2024, May, 31)]);
+		assert!(dates_after("? * 6L", start, 3) == [date(2024, January, 26), date(2024, February, 23), date(2024, March, 29)]);
+		assert!(dates_after("? * monL", start, 1) == [date(2024, January, 29)]);
+		assert!(dates_after("? * L", start, 1) == [date(2024, January, 6)]);
+	}
+
+	#[test]
+	fn prev_before() {
+		let start = date(2024, March, 1);
+		let last = cron("L * ?");
+		assert!(last.prev_before(start) == Some(date(2024, February, 29)));
+		assert!(last.prev_before(date(2024, February, 29)) == Some(date(2024, January, 31)));
+		assert!(last.iter_before(start).take(3).collect::<Vec<_>>() == [
+			date(2024, February, 29),
+			date(2024, January, 31),
+			date(2023, December, 31),
+		]);
+		assert!(cron("? * 2#1").prev_before(start) == Some(date(2024, February, 5)));
+	}
+
+	#[test]
+	fn never_matches() {
+		let feb_30 = cron("30 2 ?");
+		assert!(feb_30.next_after(date(2024, January, 1)) == None);
+		assert!(feb_30.prev_before(date(2024, January, 1)) == None);
+		assert!(feb_30.iter_after(date(2024, January, 1)).next() == None);
+	}
+
+	#[test]
+	fn stops_at_date_limits() {
+		let first = date(MIN_YEAR, January, 1);
+		assert!(cron("L * ?").prev_before(first) == None);
+		assert!(cron("? * MON").prev_before(first) == None);
+		assert!(cron("? * MON").prev_before(first.add_days(7)) == Some(first.add_days(7).prev().weekday_on_or_before(Weekday::Monday)));
+		assert!(cron("? * MON").iter_before(first.add_days(7)).count() == 1);
+		assert!(cron("1 * ?").next_after(date(i16::MAX, December, 1)) == None);
+	}
+
+	#[test]
+	fn parse_errors() {
+		let error = |data: &str| data.parse::<CronDays>().map_err(|e| e.expected);
+		assert!(error("") == Err(EXPECTED_FIELDS));
+		assert!(error("* *") == Err(EXPECTED_FIELDS));
+		assert!(error("* * * *") == Err(EXPECTED_FIELDS));
+		assert!(error("32 * ?") == Err(EXPECTED_DAY_OF_MONTH));
+		assert!(error("0 * ?") == Err(EXPECTED_DAY_OF_MONTH));
+		assert!(error("L-31 * ?") == Err(EXPECTED_DAY_OF_MONTH));
+		assert!(error("10-5 * ?") == Err(EXPECTED_DAY_OF_MONTH));
+		assert!(error("1/0 * ?") == Err(EXPECTED_DAY_OF_MONTH));
+		assert!(error("1 13 ?") == Err(EXPECTED_MONTH));
+		assert!(error("1 JANUARY ?") == Err(EXPECTED_MONTH));
+		assert!(error("? * 0") == Err(EXPECTED_DAY_OF_WEEK));
+		assert!(error("? * 6#6") == Err(EXPECTED_DAY_OF_WEEK));
+		assert!(error("? * MONDAY") == Err(EXPECTED_DAY_OF_WEEK));
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn format() {
+		assert!(cron("* * *").to_string() == "* * *");
+		assert!(cron("? * ?").to_string() == "* * *");
+		assert!(cron("1,2,3,5,L-2,L,15W,LW jan-mar,DEC ?").to_string() == "1-3,5,L,L-2,15W,LW 1-3,12 ?");
+		assert!(cron("? * MON-FRI,6L,2#1").to_string() == "? * 2-6,6L,2#1");
+		assert!(cron("13 * FRI").to_string() == "13 * 6");
+		assert!(cron("*/10 * ?").to_string() == "1,11,21,31 * ?");
+	}
+
+	#[test]
+	fn serde() {
+		#[derive(Debug, serde::Deserialize, serde::Serialize)]
+		struct Container {
+			days: CronDays,
+		}
+
+		let_assert!(Ok(serialized) = serde_yaml::to_string(&Container { days: cron("L * ?") }));
+		assert!(serialized == "days: L * ?\n");
+		let_assert!(Ok(parsed) = serde_yaml::from_str::<Container>("days: '? * 6#3'"));
+		assert!(parsed.days == cron("? * 6#3"));
+	}
+}
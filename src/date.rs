@@ -1,4 +1,4 @@
-use crate::{DateParseError, InvalidDate, InvalidDayOfMonth, InvalidDateSyntax, Month, Year, YearMonth};
+use crate::{DateParseError, InvalidDate, InvalidDayOfMonth, InvalidDateSyntax, Month, Weekday, Year, YearMonth};
 use crate::util::{modulo_i16, modulo_i32};
 
 /// The total number of days in 400 years.
@@ -7,6 +7,12 @@ const DAYS_IN_400_YEAR: i32 = 400 * 365 + 97;
 /// The number of days since year 0 for 1970-01-01.
 const UNIX_EPOCH: i32 = DAYS_IN_400_YEAR * 4 + 370 * 365 + 90;
 
+/// The Julian day number of 1 January 0000 in the proleptic Gregorian calendar.
+pub(crate) const JULIAN_DAY_OF_YEAR_ZERO: i32 = 1_721_060;
+
+/// The lowest year for which [`Date::days_since_year_zero()`] does not overflow.
+pub(crate) const MIN_YEAR: i16 = -32400;
+
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 /// A calendar date consting of a year, month and day.
 ///
@@ -124,6 +130,19 @@ impl Date {
 		60 * 60 * 24 * days as i64
 	}
 
+	/// Get the date for a Julian day number.
+	///
+	/// The Julian day number counts the days since 1 January 4713 BC of the proleptic Julian calendar.
+	/// Julian days start at noon, so this gives the date on which the Julian day starts.
+	pub const fn from_julian_day_number(julian_day: i32) -> Self {
+		Self::from_days_since_year_zero(julian_day - JULIAN_DAY_OF_YEAR_ZERO)
+	}
+
+	/// Get the Julian day number that starts at noon of the date.
+	pub const fn julian_day_number(self) -> i32 {
+		self.days_since_year_zero() + JULIAN_DAY_OF_YEAR_ZERO
+	}
+
 	/// Get the year.
 	pub const fn year(self) -> Year {
 		self.year
@@ -152,6 +171,26 @@ impl Date {
 		crate::raw::day_of_year(self.month, self.day, self.year.has_leap_day())
 	}
 
+	/// Get the day of the week.
+	pub const fn weekday(self) -> Weekday {
+		// 1 January 0000 was a Saturday, and every 400 year cycle has a whole number of weeks.
+		Weekday::from_number(modulo_i32(self.days_since_year_zero() + 5, 7) as u8 + 1)
+	}
+
+	/// Get the first date on or after this date that falls on the given weekday.
+	///
+	/// If this date already falls on the weekday, it is returned unchanged.
+	pub const fn weekday_on_or_after(self, weekday: Weekday) -> Self {
+		self.add_days(self.weekday().days_until(weekday) as i32)
+	}
+
+	/// Get the last date on or before this date that falls on the given weekday.
+	///
+	/// If this date already falls on the weekday, it is returned unchanged.
+	pub const fn weekday_on_or_before(self, weekday: Weekday) -> Self {
+		self.sub_days(weekday.days_until(self.weekday()) as i32)
+	}
+
 	/// The number of days remaining in the year, including the current date.
 	///
 	/// For Janury 1 this will return 365 in a non-leap year or 366 in a leap year.
@@ -267,6 +306,28 @@ impl Date {
 		Self::from_days_since_year_zero(self.days_since_year_zero() - days)
 	}
 
+	/// Compute a date by adding days, or `None` if the result is before `MIN_YEAR` or after the year [`i16::MAX`].
+	pub(crate) const fn checked_add_days(self, days: i32) -> Option<Self> {
+		let min = Year::new(MIN_YEAR).first_day().days_since_year_zero() as i64;
+		let max = Year::new(i16::MAX).last_day().days_since_year_zero() as i64;
+		let days = self.days_since_year_zero() as i64 + days as i64;
+		if days < min || days > max {
+			None
+		} else {
+			Some(Self::from_days_since_year_zero(days as i32))
+		}
+	}
+
+	/// Compute a date by adding months, or `None` if the year of the result is before `MIN_YEAR`.
+	///
+	/// Like [`Self::add_months()`], the resulting date may not be valid.
+	pub(crate) const fn checked_add_months(self, months: i32) -> Option<Result<Self, InvalidDayOfMonth>> {
+		match self.year_month().checked_add_months(months) {
+			Some(month) => Some(month.with_day(self.day())),
+			None => None,
+		}
+	}
+
 	/// Compute a date by adding a number of months.
 	///
 	/// The resulting date may not be valid.
@@ -543,6 +604,18 @@ mod test {
 		assert!(Date::new(2000, 1, 1).unwrap().add_days(400 * 365 + 97 + 60) == Date::new(2400, 3, 1).unwrap());
 	}
 
+	#[test]
+	fn weekday_on_or_after_before() {
+		use crate::Weekday::*;
+		let date = Date::new(2024, 2, 29).unwrap();
+		assert!(date.weekday_on_or_after(Thursday) == date);
+		assert!(date.weekday_on_or_after(Friday) == Date::new(2024, 3, 1).unwrap());
+		assert!(date.weekday_on_or_after(Wednesday) == Date::new(2024, 3, 6).unwrap());
+		assert!(date.weekday_on_or_before(Thursday) == date);
+		assert!(date.weekday_on_or_before(Wednesday) == Date::new(2024, 2, 28).unwrap());
+		assert!(date.weekday_on_or_before(Friday) == Date::new(2024, 2, 23).unwrap());
+	}
+
 	#[test]
 	fn add_years() {
 		assert!(Date::new(2020, 1, 1).unwrap().add_years(1).unwrap() == Date::new(2021, 1, 1).unwrap());
@@ -581,6 +654,15 @@ mod test {
 		assert!(Date::from_unix_timestamp(1592697600) == Date::new(2020, 6, 21).unwrap());
 	}
 
+	#[test]
+	fn julian_day_number() {
+		assert!(Date::new(2000, 1, 1).unwrap().julian_day_number() == 2_451_545);
+		assert!(Date::new(1858, 11, 17).unwrap().julian_day_number() == 2_400_001);
+		assert!(Date::new(-4713, 11, 24).unwrap().julian_day_number() == 0);
+		assert!(Date::from_julian_day_number(2_451_545) == Date::new(2000, 1, 1).unwrap());
+		assert!(Date::from_julian_day_number(0) == Date::new(-4713, 11, 24).unwrap());
+	}
+
 	#[test]
 	fn to_unix_timestamp() {
 		const SECONDS_IN_DAY: i64 = 60 * 60 * 24;
@@ -0,0 +1,425 @@
+use crate::{Date, DateRange, Year, YearMonth};
+
+/// A map from dates to values, stored contiguously by date.
+///
+/// This is intended for daily time series.
+/// The values are stored in a single vector from the first to the last date in the map,
+/// so looking up a value takes constant time, and iteration is always in order of date.
+/// Dates without a value are stored as `None`, so the map can also hold series with gaps.
+///
+/// Inserting a date before the first date or after the last date grows the map at that end.
+/// Growing at the end takes amortized constant time, like [`Vec::push()`].
+/// Growing at the start has to move all existing values.
+///
+/// ```
+/// # use gregorian::{Date, DateMap, Month, YearMonth};
+/// let start = Date::new(2020, 1, 30).unwrap();
+/// let series = DateMap::from_values(start, [1.0, 2.0, 3.0, 4.0]);
+/// assert!(series[start.add_days(1)] == 2.0);
+///
+/// let monthly = series.resample_monthly(|_, values| values.iter().flatten().sum::<f64>());
+/// assert!(monthly == [(YearMonth::new(2020, Month::January), 3.0), (YearMonth::new(2020, Month::February), 7.0)]);
+/// ```
+#[derive(Clone)]
+pub struct DateMap<T> {
+	/// The date of the first value.
+	start: Date,
+
+	/// The values, without leading or trailing `None`.
+	values: Vec<Option<T>>,
+}
+
+impl<T> DateMap<T> {
+	/// Create a new empty map.
+	pub fn new() -> Self {
+		Self {
+			start: Date::from_days_since_year_zero(0),
+			values: Vec::new(),
+		}
+	}
+
+	/// Create a map with consecutive values, starting at a date.
+	pub fn from_values(start: Date, values: impl IntoIterator<Item = T>) -> Self {
+		Self::from_options(start, values.into_iter().map(Some))
+	}
+
+	/// Create a map with consecutive optional values, starting at a date.
+	///
+	/// A `None` value means that there is no value for that date.
+	pub fn from_options(start: Date, values: impl IntoIterator<Item = Option<T>>) -> Self {
+		let mut map = Self {
+			start,
+			values: values.into_iter().collect(),
+		};
+		map.trim();
+		map
+	}
+
+	/// Get the number of dates with a value.
+	pub fn len(&self) -> usize {
+		self.values.iter().filter(|value| value.is_some()).count()
+	}
+
+	/// Check if the map is empty.
+	pub fn is_empty(&self) -> bool {
+		self.values.is_empty()
+	}
+
+	/// Get the first date with a value.
+	pub fn first_date(&self) -> Option<Date> {
+		self.span().map(|span| span.start())
+	}
+
+	/// Get the last date with a value.
+	pub fn last_date(&self) -> Option<Date> {
+		self.span().map(|span| span.end().prev())
+	}
+
+	/// Get the range from the first to the last date with a value, or `None` if the map is empty.
+	pub fn span(&self) -> Option<DateRange> {
+		if self.values.is_empty() {
+			None
+		} else {
+			Some(DateRange::new(self.start, self.start.add_days(self.values.len() as i32)))
+		}
+	}
+
+	/// Get the values from the first to the last date with a value, including gaps.
+	pub fn as_slice(&self) -> &[Option<T>] {
+		&self.values
+	}
+
+	/// Get the values for a range of dates, including gaps.
+	///
+	/// The slice only covers the part of the range between the first and last date with a value,
+	/// so it starts at the returned date, which can be later than the start of the range.
+	pub fn slice(&self, range: impl Into<DateRange>) -> (Date, &[Option<T>]) {
+		let range = range.into();
+		let start = self.clamped_index(range.start());
+		let end = self.clamped_index(range.end()).max(start);
+		(self.start.add_days(start as i32), &self.values[start..end])
+	}
+
+	/// Get the index of a date, if it is in the stored range.
+	fn index(&self, date: Date) -> Option<usize> {
+		let index = usize::try_from(self.start.days_since(date)).ok()?;
+		(index < self.values.len()).then_some(index)
+	}
+
+	/// Get the index of a date, clamped to the stored range.
+	fn clamped_index(&self, date: Date) -> usize {
+		self.start.days_since(date).clamp(0, self.values.len() as i32) as usize
+	}
+
+	/// Get the value for a date.
+	pub fn get(&self, date: Date) -> Option<&T> {
+		self.values[self.index(date)?].as_ref()
+	}
+
+	/// Get a mutable reference to the value for a date.
+	pub fn get_mut(&mut self, date: Date) -> Option<&mut T> {
+		let index = self.index(date)?;
+		self.values[index].as_mut()
+	}
+
+	/// Check if the map has a value for a date.
+	pub fn contains_key(&self, date: Date) -> bool {
+		self.get(date).is_some()
+	}
+
+	/// Set the value for a date, returning the previous value.
+	pub fn insert(&mut self, date: Date, value: T) -> Option<T> {
+		let index = self.grow_to(date);
+		self.values[index].replace(value)
+	}
+
+	/// Get a mutable reference to the value for a date, inserting a value if there is none yet.
+	pub fn get_or_insert_with(&mut self, date: Date, value: impl FnOnce() -> T) -> &mut T {
+		let index = self.grow_to(date);
+		self.values[index].get_or_insert_with(value)
+	}
+
+	/// Remove the value for a date, returning the removed value.
+	pub fn remove(&mut self, date: Date) -> Option<T> {
+		let index = self.index(date)?;
+		let value = self.values[index].take();
+		self.trim();
+		value
+	}
+
+	/// Remove all values from the map.
+	pub fn clear(&mut self) {
+		self.values.clear();
+	}
+
+	/// Grow the stored range to include a date, and return the index of the date.
+	fn grow_to(&mut self, date: Date) -> usize {
+		if self.values.is_empty() {
+			self.start = date;
+		}
+		let offset = self.start.days_since(date);
+		if offset < 0 {
+			self.values.splice(0..0, core::iter::repeat_with(|| None).take(offset.unsigned_abs() as usize));
+			self.start = date;
+			0
+		} else {
+			let index = offset as usize;
+			if index >= self.values.len() {
+				self.values.resize_with(index + 1, || None);
+			}
+			index
+		}
+	}
+
+	/// Remove leading and trailing `None` values.
+	fn trim(&mut self) {
+		let Some(first) = self.values.iter().position(Option::is_some) else {
+			self.values.clear();
+			return;
+		};
+		let end = self.values.iter().rposition(Option::is_some).unwrap_or(first) + 1;
+		self.values.truncate(end);
+		self.values.drain(..first);
+		self.start = self.start.add_days(first as i32);
+	}
+
+	/// Iterate over the dates and values in order of date.
+	pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Date, &T)> + '_ {
+		let start = self.start;
+		self.values
+			.iter()
+			.enumerate()
+			.filter_map(move |(i, value)| Some((start.add_days(i as i32), value.as_ref()?)))
+	}
+
+	/// Iterate over the dates and mutable values in order of date.
+	pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (Date, &mut T)> + '_ {
+		let start = self.start;
+		self.values
+			.iter_mut()
+			.enumerate()
+			.filter_map(move |(i, value)| Some((start.add_days(i as i32), value.as_mut()?)))
+	}
+
+	/// Iterate over the dates and values in a range of dates, in order of date.
+	pub fn range(&self, range: impl Into<DateRange>) -> impl DoubleEndedIterator<Item = (Date, &T)> + '_ {
+		let (start, values) = self.slice(range);
+		values
+			.iter()
+			.enumerate()
+			.filter_map(move |(i, value)| Some((start.add_days(i as i32), value.as_ref()?)))
+	}
+
+	/// Aggregate the values per month.
+	///
+	/// The aggregate function is called once for every month from the first to the last date with a value,
+	/// with the values of the month, including gaps.
+	/// For the first and last month, the slice only covers the dates from the first date and up to the last date.
+	pub fn resample_monthly<U>(&self, aggregate: impl FnMut(YearMonth, &[Option<T>]) -> U) -> Vec<(YearMonth, U)> {
+		let Some(first) = self.first_date() else {
+			return Vec::new();
+		};
+		self.resample(first.year_month(), YearMonth::next, YearMonth::days, aggregate)
+	}
+
+	/// Aggregate the values per year.
+	///
+	/// The aggregate function is called once for every year from the first to the last date with a value,
+	/// with the values of the year, including gaps.
+	/// For the first and last year, the slice only covers the dates from the first date and up to the last date.
+	pub fn resample_yearly<U>(&self, aggregate: impl FnMut(Year, &[Option<T>]) -> U) -> Vec<(Year, U)> {
+		let Some(first) = self.first_date() else {
+			return Vec::new();
+		};
+		self.resample(first.year(), Year::next, Year::days, aggregate)
+	}
+
+	fn resample<K: Copy, U>(
+		&self,
+		first: K,
+		next: impl Fn(K) -> K,
+		days: impl Fn(K) -> DateRange,
+		mut aggregate: impl FnMut(K, &[Option<T>]) -> U,
+	) -> Vec<(K, U)> {
+		let end = self.start.add_days(self.values.len() as i32);
+		let mut result = Vec::new();
+		let mut key = first;
+		while days(key).start() < end {
+			let (_, values) = self.slice(days(key));
+			result.push((key, aggregate(key, values)));
+			key = next(key);
+		}
+		result
+	}
+}
+
+impl<T> Default for DateMap<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: PartialEq> PartialEq for DateMap<T> {
+	fn eq(&self, other: &Self) -> bool {
+		self.iter().eq(other.iter())
+	}
+}
+
+impl<T: Eq> Eq for DateMap<T> {}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for DateMap<T> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<T> core::ops::Index<Date> for DateMap<T> {
+	type Output = T;
+
+	fn index(&self, date: Date) -> &T {
+		match self.get(date) {
+			Some(value) => value,
+			None => panic!("no value for date {}", date),
+		}
+	}
+}
+
+impl<T> core::ops::IndexMut<Date> for DateMap<T> {
+	fn index_mut(&mut self, date: Date) -> &mut T {
+		match self.get_mut(date) {
+			Some(value) => value,
+			None => panic!("no value for date {}", date),
+		}
+	}
+}
+
+impl<T> FromIterator<(Date, T)> for DateMap<T> {
+	fn from_iter<I: IntoIterator<Item = (Date, T)>>(iter: I) -> Self {
+		let mut map = Self::new();
+		map.extend(iter);
+		map
+	}
+}
+
+impl<T> Extend<(Date, T)> for DateMap<T> {
+	fn extend<I: IntoIterator<Item = (Date, T)>>(&mut self, iter: I) {
+		for (date, value) in iter {
+			self.insert(date, value);
+		}
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for DateMap<T> {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_map(self.iter())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T: serde::Deserialize<'a>> serde::Deserialize<'a> for DateMap<T> {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = std::collections::BTreeMap::<Date, T>::deserialize(deserializer)?;
+		Ok(raw.into_iter().collect())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Month::*;
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	#[test]
+	fn insert_and_grow() {
+		let mut map = DateMap::new();
+		assert!(map.is_empty());
+		assert!(map.insert(date(2020, January, 10), 10) == None);
+		assert!(map.insert(date(2020, January, 5), 5) == None);
+		assert!(map.insert(date(2020, January, 12), 12) == None);
+		assert!(map.insert(date(2020, January, 10), 11) == Some(10));
+		assert!(map.len() == 3);
+		assert!(map.span() == Some(DateRange::inclusive(date(2020, January, 5), date(2020, January, 12))));
+		assert!(map.as_slice() == [Some(5), None, None, None, None, Some(11), None, Some(12)]);
+
+		assert!(map.get(date(2020, January, 5)) == Some(&5));
+		assert!(map.get(date(2020, January, 6)) == None);
+		assert!(map.get(date(2019, January, 5)) == None);
+		map[date(2020, January, 12)] += 1;
+		assert!(map[date(2020, January, 12)] == 13);
+		*map.get_or_insert_with(date(2020, January, 6), || 0) += 6;
+		assert!(map[date(2020, January, 6)] == 6);
+
+		assert!(map.iter().collect::<Vec<_>>() == [
+			(date(2020, January, 5), &5),
+			(date(2020, January, 6), &6),
+			(date(2020, January, 10), &11),
+			(date(2020, January, 12), &13),
+		]);
+	}
+
+	#[test]
+	fn remove_trims() {
+		let mut map = DateMap::from_values(date(2020, January, 1), 1..=5);
+		assert!(map.remove(date(2020, January, 1)) == Some(1));
+		assert!(map.remove(date(2020, January, 1)) == None);
+		assert!(map.first_date() == Some(date(2020, January, 2)));
+		assert!(map.remove(date(2020, January, 5)) == Some(5));
+		assert!(map.last_date() == Some(date(2020, January, 4)));
+		assert!(map.remove(date(2020, January, 3)) == Some(3));
+		assert!(map.as_slice() == [Some(2), None, Some(4)]);
+		map.remove(date(2020, January, 2));
+		map.remove(date(2020, January, 4));
+		assert!(map.is_empty());
+		assert!(map == DateMap::new());
+	}
+
+	#[test]
+	fn slice_and_range() {
+		let map = DateMap::from_options(date(2020, January, 30), [Some(1), None, Some(3), Some(4)]);
+		let (start, values) = map.slice(YearMonth::new(2020, February));
+		assert!(start == date(2020, February, 1));
+		assert!(values == [Some(3), Some(4)]);
+
+		let (start, values) = map.slice(YearMonth::new(2020, January));
+		assert!(start == date(2020, January, 30));
+		assert!(values == [Some(1), None]);
+
+		let (_, values) = map.slice(YearMonth::new(2020, March));
+		assert!(values.is_empty());
+
+		assert!(map.range(date(2020, January, 31)..date(2020, February, 2)).collect::<Vec<_>>() == [(date(2020, February, 1), &3)]);
+		assert!(map.range(YearMonth::new(2019, December)).count() == 0);
+	}
+
+	#[test]
+	fn resample() {
+		let map = DateMap::from_values(date(2020, December, 30), 1..=35);
+		let monthly = map.resample_monthly(|_, values| values.iter().flatten().sum::<i32>());
+		assert!(monthly == [
+			(YearMonth::new(2020, December), 1 + 2),
+			(YearMonth::new(2021, January), (3..=33).sum()),
+			(YearMonth::new(2021, February), 34 + 35),
+		]);
+		let yearly = map.resample_yearly(|_, values| values.len());
+		assert!(yearly == [(Year::new(2020), 2), (Year::new(2021), 33)]);
+
+		let sparse: DateMap<i32> = [(date(2020, January, 15), 1), (date(2020, March, 15), 2)].into_iter().collect();
+		let counts = sparse.resample_monthly(|_, values| values.iter().flatten().count());
+		assert!(counts == [
+			(YearMonth::new(2020, January), 1),
+			(YearMonth::new(2020, February), 0),
+			(YearMonth::new(2020, March), 1),
+		]);
+		assert!(DateMap::<i32>::new().resample_yearly(|_, values| values.len()).is_empty());
+	}
+
+	#[test]
+	fn serde() {
+		let map = DateMap::from_options(date(2020, January, 1), [Some(1.5), None, Some(2.5)]);
+		let_assert!(Ok(yaml) = serde_yaml::to_string(&map));
+		assert!(yaml == "2020-01-01: 1.5\n2020-01-03: 2.5\n");
+		assert!(serde_yaml::from_str::<DateMap<f64>>(&yaml).ok() == Some(map));
+	}
+}
@@ -0,0 +1,250 @@
+use crate::{Date, WeekdaySet, Year, YearMonth};
+
+/// A range of consecutive dates.
+///
+/// The range includes the start date, but not the end date.
+/// If the end date is equal to or before the start date, the range is empty.
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateRange {
+	start: Date,
+	end: Date,
+}
+
+impl DateRange {
+	/// Create a new date range from a start date (inclusive) and an end date (exclusive).
+	pub const fn new(start: Date, end: Date) -> Self {
+		Self { start, end }
+	}
+
+	/// Create a new date range from a first and last date, both inclusive.
+	pub const fn inclusive(first: Date, last: Date) -> Self {
+		Self::new(first, last.next())
+	}
+
+	/// Get the first date of the range.
+	pub const fn start(self) -> Date {
+		self.start
+	}
+
+	/// Get the end date of the range.
+	///
+	/// The end date itself is not part of the range.
+	pub const fn end(self) -> Date {
+		self.end
+	}
+
+	/// Get the number of days in the range.
+	pub const fn len(self) -> u32 {
+		let days = self.start.days_since(self.end);
+		if days < 0 {
+			0
+		} else {
+			days as u32
+		}
+	}
+
+	/// Check if the range is empty.
+	pub const fn is_empty(self) -> bool {
+		self.len() == 0
+	}
+
+	/// Check if the range contains a date.
+	pub const fn contains(self, date: Date) -> bool {
+		let date = date.days_since_year_zero();
+		date >= self.start.days_since_year_zero() && date < self.end.days_since_year_zero()
+	}
+
+	/// Count the number of days in the range that fall on a weekday in `set`.
+	///
+	/// This does not iterate over the range, so it takes constant time regardless of the length of the range.
+	pub const fn count_matching(self, set: WeekdaySet) -> u32 {
+		set.count_in_days(self.start.weekday(), self.len())
+	}
+
+	/// Iterate over the dates in the range.
+	pub const fn iter(self) -> DateRangeIter {
+		DateRangeIter {
+			next: self.start,
+			end: self.end,
+		}
+	}
+}
+
+impl From<core::ops::Range<Date>> for DateRange {
+	fn from(other: core::ops::Range<Date>) -> Self {
+		Self::new(other.start, other.end)
+	}
+}
+
+impl From<core::ops::RangeInclusive<Date>> for DateRange {
+	fn from(other: core::ops::RangeInclusive<Date>) -> Self {
+		Self::inclusive(*other.start(), *other.end())
+	}
+}
+
+impl From<Year> for DateRange {
+	fn from(other: Year) -> Self {
+		other.days()
+	}
+}
+
+impl From<YearMonth> for DateRange {
+	fn from(other: YearMonth) -> Self {
+		other.days()
+	}
+}
+
+impl IntoIterator for DateRange {
+	type Item = Date;
+	type IntoIter = DateRangeIter;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl core::fmt::Debug for DateRange {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "DateRange({}..{})", self.start, self.end)
+	}
+}
+
+/// Iterator over the dates in a [`DateRange`].
+#[derive(Clone, Debug)]
+pub struct DateRangeIter {
+	next: Date,
+	end: Date,
+}
+
+impl Iterator for DateRangeIter {
+	type Item = Date;
+
+	fn next(&mut self) -> Option<Date> {
+		if self.next >= self.end {
+			None
+		} else {
+			let date = self.next;
+			self.next = date.next();
+			Some(date)
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = DateRange::new(self.next, self.end).len() as usize;
+		(len, Some(len))
+	}
+
+	fn nth(&mut self, n: usize) -> Option<Date> {
+		if n >= self.len() {
+			self.next = self.end;
+			None
+		} else {
+			self.next = self.next.add_days(n as i32);
+			self.next()
+		}
+	}
+
+	fn last(mut self) -> Option<Date> {
+		self.next_back()
+	}
+}
+
+impl DoubleEndedIterator for DateRangeIter {
+	fn next_back(&mut self) -> Option<Date> {
+		if self.next >= self.end {
+			None
+		} else {
+			self.end = self.end.prev();
+			Some(self.end)
+		}
+	}
+}
+
+impl ExactSizeIterator for DateRangeIter {}
+
+impl core::iter::FusedIterator for DateRangeIter {}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, Weekday::*};
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	#[test]
+	fn len() {
+		assert!(DateRange::new(date(2020, January, 1), date(2020, January, 1)).len() == 0);
+		assert!(DateRange::new(date(2020, January, 1), date(2020, January, 1)).is_empty());
+		assert!(DateRange::new(date(2020, January, 2), date(2020, January, 1)).len() == 0);
+		assert!(DateRange::new(date(2020, January, 1), date(2021, January, 1)).len() == 366);
+		assert!(DateRange::inclusive(date(2020, January, 1), date(2020, January, 31)).len() == 31);
+	}
+
+	#[test]
+	fn contains() {
+		let range = DateRange::from(date(2020, January, 1)..date(2020, February, 1));
+		assert!(range.contains(date(2020, January, 1)));
+		assert!(range.contains(date(2020, January, 31)));
+		assert!(!range.contains(date(2020, February, 1)));
+		assert!(!range.contains(date(2019, December, 31)));
+	}
+
+	#[test]
+	fn from_year_and_month() {
+		assert!(DateRange::from(Year::new(2020)) == DateRange::new(date(2020, January, 1), date(2021, January, 1)));
+		assert!(DateRange::from(YearMonth::new(2020, February)) == DateRange::new(date(2020, February, 1), date(2020, March, 1)));
+	}
+
+	#[test]
+	fn iter() {
+		let range = DateRange::from(date(2020, February, 27)..=date(2020, March, 2));
+		let mut iter = range.iter();
+		assert!(iter.len() == 5);
+		assert!(iter.next() == Some(date(2020, February, 27)));
+		assert!(iter.next_back() == Some(date(2020, March, 2)));
+		assert!(iter.next() == Some(date(2020, February, 28)));
+		assert!(iter.next() == Some(date(2020, February, 29)));
+		assert!(iter.len() == 1);
+		assert!(iter.next() == Some(date(2020, March, 1)));
+		assert!(iter.next() == None);
+		assert!(iter.next_back() == None);
+
+		assert!(range.iter().nth(2) == Some(date(2020, February, 29)));
+		assert!(range.iter().nth(5) == None);
+		assert!(range.iter().count() == 5);
+	}
+
+	#[test]
+	fn count_matching() {
+		let weekend = WeekdaySet::from_array([Saturday, Sunday]);
+		let start = date(2020, January, 1);
+		for len in 0..100 {
+			let range = DateRange::new(start, start.add_days(len));
+			let expected = range.iter().filter(|date| weekend.contains_date(*date)).count();
+			assert!(range.count_matching(weekend) as usize == expected);
+			assert!(range.count_matching(WeekdaySet::ALL) == len as u32);
+			assert!(range.count_matching(WeekdaySet::EMPTY) == 0);
+		}
+
+		let year = DateRange::new(date(2021, January, 1), date(2022, January, 1));
+		assert!(year.count_matching(WeekdaySet::WORKDAYS) == 261);
+		assert!(year.count_matching(WeekdaySet::single(Friday)) == 53);
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn format() {
+		let range = DateRange::new(date(2020, January, 1), date(2020, February, 1));
+		assert!(format!("{:?}", range) == "DateRange(2020-01-01..2020-02-01)");
+	}
+
+	#[test]
+	fn serde() {
+		let range = DateRange::new(date(2020, January, 1), date(2020, February, 1));
+		let_assert!(Ok(serialized) = serde_yaml::to_string(&range));
+		assert!(serialized == "start: 2020-01-01\nend: 2020-02-01\n");
+		let_assert!(Ok(parsed) = serde_yaml::from_str::<DateRange>(&serialized));
+		assert!(parsed == range);
+	}
+}
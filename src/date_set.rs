@@ -0,0 +1,487 @@
+use crate::{Date, DateInterval, DateRange, InvalidDateSetSyntax};
+
+/// A compact set of dates, stored as a bitmap.
+///
+/// Each date is stored as a single bit, indexed by [`Date::days_since_year_zero()`].
+/// The bitmap only covers the dates from the first to the last date in the set,
+/// so a set with a few thousand dates spread over a couple of years takes less than a kilobyte.
+///
+/// Inserting, removing and looking up a date take constant time.
+/// Set operations process 64 dates at a time.
+///
+/// The set can be parsed from and formatted as a comma separated list of dates and date ranges,
+/// such as `"2020-01-01/2020-01-05,2020-01-08"`.
+/// Ranges use the ISO 8601 interval notation, and both the first and last date are included.
+///
+/// ```
+/// # use gregorian::{Date, DateSet};
+/// let mut set = DateSet::new();
+/// set.insert(Date::new(2020, 1, 2).unwrap());
+/// set.insert(Date::new(2020, 1, 1).unwrap());
+/// set.insert(Date::new(2020, 1, 5).unwrap());
+/// assert!(set.len() == 3);
+/// assert!(set.to_string() == "2020-01-01/2020-01-02,2020-01-05");
+/// assert!(set.next_after(Date::new(2020, 1, 2).unwrap()) == Some(Date::new(2020, 1, 5).unwrap()));
+/// ```
+#[derive(Clone, Default, Eq, PartialEq, Hash)]
+pub struct DateSet {
+	/// The index of the first word in the bitmap.
+	first_word: i32,
+
+	/// The bitmap, without leading or trailing zero words.
+	words: Vec<u64>,
+}
+
+/// Get the word index and bit index of a date.
+fn position(date: Date) -> (i32, u32) {
+	let days = date.days_since_year_zero();
+	(days.div_euclid(64), days.rem_euclid(64) as u32)
+}
+
+/// Get the date for a word index and bit index.
+fn date_at(word: i32, bit: u32) -> Date {
+	Date::from_days_since_year_zero(word * 64 + bit as i32)
+}
+
+impl DateSet {
+	/// Create a new empty set.
+	pub const fn new() -> Self {
+		Self {
+			first_word: 0,
+			words: Vec::new(),
+		}
+	}
+
+	/// Check if the set is empty.
+	pub fn is_empty(&self) -> bool {
+		self.words.is_empty()
+	}
+
+	/// Get the number of dates in the set.
+	pub fn len(&self) -> usize {
+		self.words.iter().map(|word| word.count_ones() as usize).sum()
+	}
+
+	/// Remove all dates from the set.
+	pub fn clear(&mut self) {
+		self.words.clear();
+		self.first_word = 0;
+	}
+
+	/// Get the word with the given index, which is zero outside of the bitmap.
+	fn word(&self, word: i32) -> u64 {
+		usize::try_from(word - self.first_word)
+			.ok()
+			.and_then(|index| self.words.get(index))
+			.copied()
+			.unwrap_or(0)
+	}
+
+	/// Get the index of the word after the last word in the bitmap.
+	fn end_word(&self) -> i32 {
+		self.first_word + self.words.len() as i32
+	}
+
+	/// Check if the set contains a date.
+	pub fn contains(&self, date: Date) -> bool {
+		let (word, bit) = position(date);
+		self.word(word) & (1 << bit) != 0
+	}
+
+	/// Add a date to the set.
+	///
+	/// Returns `true` if the date was not in the set yet.
+	pub fn insert(&mut self, date: Date) -> bool {
+		let (word, bit) = position(date);
+		if self.words.is_empty() {
+			self.first_word = word;
+			self.words.push(0);
+		} else if word < self.first_word {
+			let extra = (self.first_word - word) as usize;
+			self.words.splice(0..0, core::iter::repeat_n(0, extra));
+			self.first_word = word;
+		} else if word >= self.end_word() {
+			self.words.resize((word - self.first_word) as usize + 1, 0);
+		}
+		let word = &mut self.words[(word - self.first_word) as usize];
+		let inserted = *word & (1 << bit) == 0;
+		*word |= 1 << bit;
+		inserted
+	}
+
+	/// Remove a date from the set.
+	///
+	/// Returns `true` if the date was in the set.
+	pub fn remove(&mut self, date: Date) -> bool {
+		if !self.contains(date) {
+			return false;
+		}
+		let (word, bit) = position(date);
+		self.words[(word - self.first_word) as usize] &= !(1 << bit);
+		self.trim();
+		true
+	}
+
+	/// Add all dates in a range to the set.
+	pub fn insert_range(&mut self, range: impl Into<DateRange>) {
+		let range = range.into();
+		if !range.is_empty() {
+			*self = self.union(&Self::from(range));
+		}
+	}
+
+	/// Get the first date in the set.
+	pub fn first(&self) -> Option<Date> {
+		let word = *self.words.first()?;
+		Some(date_at(self.first_word, word.trailing_zeros()))
+	}
+
+	/// Get the last date in the set.
+	pub fn last(&self) -> Option<Date> {
+		let word = *self.words.last()?;
+		Some(date_at(self.end_word() - 1, 63 - word.leading_zeros()))
+	}
+
+	/// Get the number of dates in the set that are before a date.
+	///
+	/// If the date is in the set, this is the index of the date when iterating over the set.
+	pub fn rank(&self, date: Date) -> usize {
+		let (word, bit) = position(date);
+		let index = (word - self.first_word).clamp(0, self.words.len() as i32) as usize;
+		let full: usize = self.words[..index].iter().map(|word| word.count_ones() as usize).sum();
+		full + (self.word(word) & ((1 << bit) - 1)).count_ones() as usize
+	}
+
+	/// Get the first date in the set after a date.
+	pub fn next_after(&self, date: Date) -> Option<Date> {
+		let (start_word, bit) = position(date.next());
+		let mut word = start_word.max(self.first_word);
+		let mut bits = self.word(word);
+		if word == start_word {
+			// Mask out the bits up to and including `date`.
+			bits &= u64::MAX << bit;
+		}
+		while word < self.end_word() {
+			if bits != 0 {
+				return Some(date_at(word, bits.trailing_zeros()));
+			}
+			word += 1;
+			bits = self.word(word);
+		}
+		None
+	}
+
+	/// Get the last date in the set before a date.
+	pub fn prev_before(&self, date: Date) -> Option<Date> {
+		let (start_word, bit) = position(date.prev());
+		let mut word = start_word.min(self.end_word() - 1);
+		let mut bits = self.word(word);
+		if word == start_word {
+			// Mask out the bits from `date` onwards.
+			bits &= u64::MAX >> (63 - bit);
+		}
+		while word >= self.first_word {
+			if bits != 0 {
+				return Some(date_at(word, 63 - bits.leading_zeros()));
+			}
+			word -= 1;
+			bits = self.word(word);
+		}
+		None
+	}
+
+	/// Iterate over the dates in the set in order.
+	pub fn iter(&self) -> DateSetIter<'_> {
+		DateSetIter {
+			set: self,
+			index: 0,
+			bits: self.words.first().copied().unwrap_or(0),
+		}
+	}
+
+	/// Iterate over the ranges of consecutive dates in the set in order.
+	pub fn ranges(&self) -> impl Iterator<Item = DateRange> + '_ {
+		let mut dates = self.iter().peekable();
+		core::iter::from_fn(move || {
+			let start = dates.next()?;
+			let mut end = start.next();
+			while dates.next_if_eq(&end).is_some() {
+				end = end.next();
+			}
+			Some(DateRange::new(start, end))
+		})
+	}
+
+	/// Get the dates that are in either set.
+	pub fn union(&self, other: &Self) -> Self {
+		self.combine(other, |a, b| a | b)
+	}
+
+	/// Get the dates that are in both sets.
+	pub fn intersection(&self, other: &Self) -> Self {
+		self.combine(other, |a, b| a & b)
+	}
+
+	/// Get the dates that are in this set but not in the other set.
+	pub fn difference(&self, other: &Self) -> Self {
+		self.combine(other, |a, b| a & !b)
+	}
+
+	/// Get the dates that are in exactly one of the two sets.
+	pub fn symmetric_difference(&self, other: &Self) -> Self {
+		self.combine(other, |a, b| a ^ b)
+	}
+
+	/// Combine two sets word by word.
+	fn combine(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+		let (first_word, end_word) = match (self.is_empty(), other.is_empty()) {
+			(true, true) => return Self::new(),
+			(false, true) => (self.first_word, self.end_word()),
+			(true, false) => (other.first_word, other.end_word()),
+			(false, false) => (self.first_word.min(other.first_word), self.end_word().max(other.end_word())),
+		};
+		let mut result = Self {
+			first_word,
+			words: (first_word..end_word).map(|word| op(self.word(word), other.word(word))).collect(),
+		};
+		result.trim();
+		result
+	}
+
+	/// Remove leading and trailing zero words.
+	fn trim(&mut self) {
+		let Some(start) = self.words.iter().position(|&word| word != 0) else {
+			self.clear();
+			return;
+		};
+		let end = self.words.iter().rposition(|&word| word != 0).unwrap_or(start) + 1;
+		self.words.truncate(end);
+		self.words.drain(..start);
+		self.first_word += start as i32;
+	}
+}
+
+/// Iterator over the dates in a [`DateSet`].
+#[derive(Clone)]
+pub struct DateSetIter<'a> {
+	set: &'a DateSet,
+	index: usize,
+	bits: u64,
+}
+
+impl Iterator for DateSetIter<'_> {
+	type Item = Date;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.bits == 0 {
+			self.index += 1;
+			self.bits = *self.set.words.get(self.index)?;
+		}
+		let bit = self.bits.trailing_zeros();
+		self.bits &= self.bits - 1;
+		Some(date_at(self.set.first_word + self.index as i32, bit))
+	}
+}
+
+impl<'a> IntoIterator for &'a DateSet {
+	type Item = Date;
+	type IntoIter = DateSetIter<'a>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl From<DateRange> for DateSet {
+	fn from(other: DateRange) -> Self {
+		if other.is_empty() {
+			return Self::new();
+		}
+		let (first_word, first_bit) = position(other.start());
+		let (last_word, last_bit) = position(other.end().prev());
+		let mut words = vec![u64::MAX; (last_word - first_word) as usize + 1];
+		words[0] &= u64::MAX << first_bit;
+		*words.last_mut().unwrap() &= u64::MAX >> (63 - last_bit);
+		Self { first_word, words }
+	}
+}
+
+impl FromIterator<Date> for DateSet {
+	fn from_iter<I: IntoIterator<Item = Date>>(iter: I) -> Self {
+		let mut set = Self::new();
+		set.extend(iter);
+		set
+	}
+}
+
+impl Extend<Date> for DateSet {
+	fn extend<I: IntoIterator<Item = Date>>(&mut self, iter: I) {
+		for date in iter {
+			self.insert(date);
+		}
+	}
+}
+
+impl core::fmt::Debug for DateSet {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "DateSet({})", self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DateSet {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for DateSet {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = std::borrow::Cow::<'_, str>::deserialize(deserializer)?;
+		raw.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+impl core::str::FromStr for DateSet {
+	type Err = InvalidDateSetSyntax;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		let mut set = Self::new();
+		if data.trim().is_empty() {
+			return Ok(set);
+		}
+		for item in data.split(',') {
+			let item = item.trim();
+			if item.contains('/') {
+				let interval: DateInterval = item.parse().map_err(|_| InvalidDateSetSyntax::new())?;
+				set.insert_range(interval.to_range().ok_or_else(InvalidDateSetSyntax::new)?);
+			} else {
+				set.insert(item.parse().map_err(|_| InvalidDateSetSyntax::new())?);
+			}
+		}
+		Ok(set)
+	}
+}
+
+impl core::fmt::Display for DateSet {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		for (i, range) in self.ranges().enumerate() {
+			if i > 0 {
+				write!(f, ",")?;
+			}
+			if range.len() == 1 {
+				write!(f, "{}", range.start())?;
+			} else {
+				write!(f, "{}/{}", range.start(), range.end().prev())?;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, YearMonth};
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+	use std::collections::BTreeSet;
+
+	#[test]
+	fn insert_and_remove() {
+		let mut set = DateSet::new();
+		assert!(set.is_empty());
+		assert!(set.insert(date(2020, March, 1)));
+		assert!(set.insert(date(2019, January, 1)));
+		assert!(set.insert(date(2021, December, 31)));
+		assert!(!set.insert(date(2020, March, 1)));
+		assert!(set.len() == 3);
+		assert!(set.contains(date(2020, March, 1)));
+		assert!(!set.contains(date(2020, March, 2)));
+		assert!(!set.contains(date(1900, March, 1)));
+		assert!(set.first() == Some(date(2019, January, 1)));
+		assert!(set.last() == Some(date(2021, December, 31)));
+
+		assert!(set.remove(date(2019, January, 1)));
+		assert!(!set.remove(date(2019, January, 1)));
+		assert!(set.first() == Some(date(2020, March, 1)));
+		assert!(set.remove(date(2020, March, 1)));
+		assert!(set.remove(date(2021, December, 31)));
+		assert!(set.is_empty());
+		assert!(set == DateSet::new());
+	}
+
+	#[test]
+	fn matches_btree_set() {
+		// Insert a pseudo-random pattern of dates, including dates before year zero.
+		let start = date(-1, June, 1);
+		let offsets = (0..2000).map(|i: i32| (i * 7919) % 3001);
+		let expected: BTreeSet<Date> = offsets.clone().map(|i| start.add_days(i)).collect();
+		let set: DateSet = offsets.map(|i| start.add_days(i)).collect();
+
+		assert!(set.len() == expected.len());
+		assert!(set.iter().eq(expected.iter().copied()));
+		for i in -10..3010 {
+			let day = start.add_days(i);
+			assert!(set.contains(day) == expected.contains(&day));
+			assert!(set.rank(day) == expected.range(..day).count());
+			assert!(set.next_after(day) == expected.range(day.next()..).next().copied());
+			assert!(set.prev_before(day) == expected.range(..day).next_back().copied());
+		}
+	}
+
+	#[test]
+	fn set_algebra() {
+		let a = DateSet::from(DateRange::new(date(2020, January, 1), date(2020, March, 1)));
+		let b: DateSet = YearMonth::new(2020, February).days().into_iter().chain(YearMonth::new(2020, April).days()).collect();
+		let a_dates: BTreeSet<Date> = a.iter().collect();
+		let b_dates: BTreeSet<Date> = b.iter().collect();
+
+		assert!(a.len() == 60);
+		assert!(a.union(&b).iter().eq(a_dates.union(&b_dates).copied()));
+		assert!(a.intersection(&b).iter().eq(a_dates.intersection(&b_dates).copied()));
+		assert!(a.difference(&b).iter().eq(a_dates.difference(&b_dates).copied()));
+		assert!(b.difference(&a).iter().eq(b_dates.difference(&a_dates).copied()));
+		assert!(a.symmetric_difference(&b).iter().eq(a_dates.symmetric_difference(&b_dates).copied()));
+		assert!(a.difference(&a) == DateSet::new());
+		assert!(a.intersection(&DateSet::new()) == DateSet::new());
+		assert!(a.union(&DateSet::new()) == a);
+	}
+
+	#[test]
+	fn ranges() {
+		let mut set = DateSet::from(DateRange::inclusive(date(2020, January, 30), date(2020, February, 2)));
+		set.insert(date(2020, February, 10));
+		assert!(set.ranges().collect::<Vec<_>>() == [
+			DateRange::new(date(2020, January, 30), date(2020, February, 3)),
+			DateRange::new(date(2020, February, 10), date(2020, February, 11)),
+		]);
+	}
+
+	#[test]
+	fn parse_and_format() {
+		let_assert!(Ok(set) = "2020-01-01/2020-01-05, 2020-01-08,2020-02-01/P1W".parse::<DateSet>());
+		assert!(set.len() == 13);
+		assert!(set.to_string() == "2020-01-01/2020-01-05,2020-01-08,2020-02-01/2020-02-07");
+		assert!(set.to_string().parse() == Ok(set));
+		assert!("".parse() == Ok(DateSet::new()));
+		assert!(DateSet::new().to_string() == "");
+
+		assert!(let Err(_) = "2020-01-01/..".parse::<DateSet>());
+		assert!(let Err(_) = "2020-01-01,".parse::<DateSet>());
+		assert!(let Err(_) = "2020-02-30".parse::<DateSet>());
+		assert!(let Err(_) = "2020-01-01/P999999Y".parse::<DateSet>());
+		assert!(let Err(_) = "P999999Y/2020-01-01".parse::<DateSet>());
+		assert!(let Err(_) = "2020-03-01/2020-01-01".parse::<DateSet>());
+	}
+
+	#[test]
+	fn serde() {
+		let set: DateSet = [date(2020, January, 1), date(2020, January, 2), date(2020, July, 4)].into_iter().collect();
+		let_assert!(Ok(yaml) = serde_yaml::to_string(&set));
+		assert!(yaml == "2020-01-01/2020-01-02,2020-07-04\n");
+		assert!(serde_yaml::from_str::<DateSet>(&yaml).ok() == Some(set));
+		assert!(let Err(_) = serde_yaml::from_str::<DateSet>("2020-01-01/P999999Y"));
+	}
+}
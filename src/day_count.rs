@@ -0,0 +1,316 @@
+use crate::{BusinessCalendar, Date, Month};
+
+/// A day count convention, used to compute accrued interest between two dates.
+///
+/// A day count convention determines the number of days between two dates,
+/// and the fraction of a year that the period represents.
+///
+/// The conventions follow the definitions of section 4.16 of the 2006 ISDA Definitions.
+/// Some conventions need additional information, such as the termination date or the coupon period.
+/// This information is stored in the enum variant.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DayCount<'a> {
+	/// 30/360, also known as Bond Basis.
+	///
+	/// Every month is treated as 30 days and every year as 360 days.
+	/// A start date on the 31st is treated as the 30th.
+	/// An end date on the 31st is treated as the 30th if the start date is on the 30th or 31st.
+	Thirty360,
+
+	/// 30/360 US, also known as 30/360 SIA.
+	///
+	/// Like [`Self::Thirty360`], but a start date on the last day of February is treated as the 30th.
+	/// If both dates are on the last day of February, the end date is also treated as the 30th.
+	Thirty360Us,
+
+	/// 30E/360, also known as Eurobond Basis.
+	///
+	/// Every month is treated as 30 days and every year as 360 days.
+	/// Both dates are treated as the 30th if they fall on the 31st.
+	ThirtyE360,
+
+	/// 30E/360 (ISDA).
+	///
+	/// Like [`Self::ThirtyE360`], but dates on the last day of February are also treated as the 30th,
+	/// unless the end date is the termination date.
+	ThirtyE360Isda {
+		/// The termination date of the contract.
+		termination: Date,
+	},
+
+	/// ACT/360: the actual number of days, divided by 360.
+	Actual360,
+
+	/// ACT/365F: the actual number of days, divided by 365.
+	Actual365Fixed,
+
+	/// ACT/ACT (ISDA): the actual number of days in leap years divided by 366,
+	/// plus the actual number of days in other years divided by 365.
+	ActualActualIsda,
+
+	/// ACT/ACT (ICMA): the actual number of days divided by the number of days in the coupon period,
+	/// times the number of coupon periods per year.
+	///
+	/// The accrual period should fall within the coupon period.
+	/// For irregular periods, split the accrual period over notional regular coupon periods and add the fractions.
+	ActualActualIcma {
+		/// The start date of the coupon period.
+		period_start: Date,
+
+		/// The end date of the coupon period.
+		period_end: Date,
+
+		/// The number of coupon periods per year.
+		frequency: u8,
+	},
+
+	/// BUS/252: the number of business days divided by 252.
+	///
+	/// The start date is included and the end date is excluded when counting business days.
+	Business252 {
+		/// The business calendar used to count business days.
+		calendar: &'a BusinessCalendar,
+	},
+}
+
+impl DayCount<'_> {
+	/// Get the common name of the convention, such as `"ACT/360"`.
+	pub const fn name(&self) -> &'static str {
+		match self {
+			Self::Thirty360 => "30/360",
+			Self::Thirty360Us => "30/360 US",
+			Self::ThirtyE360 => "30E/360",
+			Self::ThirtyE360Isda { .. } => "30E/360 ISDA",
+			Self::Actual360 => "ACT/360",
+			Self::Actual365Fixed => "ACT/365F",
+			Self::ActualActualIsda => "ACT/ACT ISDA",
+			Self::ActualActualIcma { .. } => "ACT/ACT ICMA",
+			Self::Business252 { .. } => "BUS/252",
+		}
+	}
+
+	/// Get the number of days from `start` until `end` according to the convention.
+	///
+	/// If `end` is before `start`, the result is negative.
+	pub fn day_count(&self, start: Date, end: Date) -> i32 {
+		match *self {
+			Self::Thirty360 => {
+				let d1 = start.day().min(30);
+				let d2 = if d1 == 30 { end.day().min(30) } else { end.day() };
+				thirty_360(start, d1, end, d2)
+			},
+			Self::Thirty360Us => {
+				let start_february = is_last_of_february(start);
+				let d1 = if start_february { 30 } else { start.day().min(30) };
+				let d2 = if start_february && is_last_of_february(end) {
+					30
+				} else if d1 == 30 {
+					end.day().min(30)
+				} else {
+					end.day()
+				};
+				thirty_360(start, d1, end, d2)
+			},
+			Self::ThirtyE360 => thirty_360(start, start.day().min(30), end, end.day().min(30)),
+			Self::ThirtyE360Isda { termination } => {
+				let d1 = if is_last_of_february(start) { 30 } else { start.day().min(30) };
+				let d2 = if is_last_of_february(end) && end != termination {
+					30
+				} else {
+					end.day().min(30)
+				};
+				thirty_360(start, d1, end, d2)
+			},
+			Self::Actual360 | Self::Actual365Fixed | Self::ActualActualIsda | Self::ActualActualIcma { .. } => actual_days(start, end),
+			Self::Business252 { calendar } => calendar.business_days_between(start, end),
+		}
+	}
+
+	/// Get the fraction of a year from `start` until `end` according to the convention.
+	///
+	/// If `end` is before `start`, the result is negative.
+	pub fn year_fraction(&self, start: Date, end: Date) -> f64 {
+		match *self {
+			Self::Thirty360 | Self::Thirty360Us | Self::ThirtyE360 | Self::ThirtyE360Isda { .. } | Self::Actual360 => {
+				self.day_count(start, end) as f64 / 360.0
+			},
+			Self::Actual365Fixed => self.day_count(start, end) as f64 / 365.0,
+			Self::ActualActualIsda => {
+				if end < start {
+					return -actual_actual_isda(end, start);
+				}
+				actual_actual_isda(start, end)
+			},
+			Self::ActualActualIcma {
+				period_start,
+				period_end,
+				frequency,
+			} => actual_days(start, end) as f64 / (frequency as f64 * actual_days(period_start, period_end) as f64),
+			Self::Business252 { .. } => self.day_count(start, end) as f64 / 252.0,
+		}
+	}
+}
+
+/// Compute the number of days between two dates in a 30/360 convention with adjusted day numbers.
+fn thirty_360(start: Date, d1: u8, end: Date, d2: u8) -> i32 {
+	let years = end.year().to_number() as i32 - start.year().to_number() as i32;
+	let months = end.month().to_number() as i32 - start.month().to_number() as i32;
+	360 * years + 30 * months + d2 as i32 - d1 as i32
+}
+
+/// Compute the actual number of days from `start` until `end`.
+fn actual_days(start: Date, end: Date) -> i32 {
+	end.days_since_year_zero() - start.days_since_year_zero()
+}
+
+/// Compute the ACT/ACT (ISDA) year fraction, with `start` on or before `end`.
+fn actual_actual_isda(start: Date, end: Date) -> f64 {
+	if start.year() == end.year() {
+		return actual_days(start, end) as f64 / start.year().total_days() as f64;
+	}
+
+	let first_year_end = start.year().next().first_day();
+	let last_year_start = end.year().first_day();
+	let full_years = end.year().to_number() as i32 - start.year().to_number() as i32 - 1;
+	actual_days(start, first_year_end) as f64 / start.year().total_days() as f64
+		+ full_years as f64
+		+ actual_days(last_year_start, end) as f64 / end.year().total_days() as f64
+}
+
+/// Check if a date is the last day of February.
+fn is_last_of_february(date: Date) -> bool {
+	date.month() == Month::February && date.day() == date.year_month().total_days()
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::util::date;
+	use assert2::assert;
+
+	fn assert_close(a: f64, b: f64) {
+		assert!((a - b).abs() < 1e-12, "{} != {}", a, b);
+	}
+
+	#[test]
+	fn thirty_360() {
+		// Examples from the ISDA 30E/360 (ISDA) illustration, extended with 30/360 US.
+		// Columns: start, end, 30/360, 30/360 US, 30E/360, 30E/360 ISDA.
+		#[rustfmt::skip]
+		let cases = [
+			(date(2007, 1, 15), date(2007, 1, 30), 15, 15, 15, 15),
+			(date(2007, 1, 15), date(2007, 2, 15), 30, 30, 30, 30),
+			(date(2007, 1, 15), date(2007, 7, 15), 180, 180, 180, 180),
+			(date(2007, 9, 30), date(2008, 3, 31), 180, 180, 180, 180),
+			(date(2007, 9, 30), date(2007, 10, 31), 30, 30, 30, 30),
+			(date(2007, 9, 30), date(2008, 9, 30), 360, 360, 360, 360),
+			(date(2007, 1, 15), date(2007, 1, 31), 16, 16, 15, 15),
+			(date(2007, 1, 31), date(2007, 2, 28), 28, 28, 28, 30),
+			(date(2007, 2, 28), date(2007, 3, 31), 33, 30, 32, 30),
+			(date(2006, 8, 31), date(2007, 2, 28), 178, 178, 178, 180),
+			(date(2007, 2, 28), date(2007, 8, 31), 183, 180, 182, 180),
+			(date(2007, 2, 14), date(2007, 2, 28), 14, 14, 14, 16),
+			(date(2007, 2, 26), date(2008, 2, 29), 363, 363, 363, 364),
+			(date(2008, 2, 29), date(2009, 2, 28), 359, 360, 359, 360),
+			(date(2008, 2, 29), date(2008, 3, 30), 31, 30, 31, 30),
+			(date(2008, 2, 29), date(2008, 3, 31), 32, 30, 31, 30),
+			(date(2007, 2, 28), date(2007, 3, 5), 7, 5, 7, 5),
+			(date(2007, 10, 31), date(2007, 11, 28), 28, 28, 28, 28),
+			(date(2007, 8, 31), date(2008, 2, 29), 179, 179, 179, 180),
+			(date(2008, 2, 29), date(2008, 8, 31), 182, 180, 181, 180),
+			(date(2008, 8, 31), date(2009, 2, 28), 178, 178, 178, 180),
+			(date(2009, 2, 28), date(2009, 8, 31), 183, 180, 182, 180),
+		];
+
+		let isda = DayCount::ThirtyE360Isda { termination: date(2099, 1, 1) };
+		for (start, end, bond_basis, us, eurobond, e_isda) in cases {
+			assert!(DayCount::Thirty360.day_count(start, end) == bond_basis, "{} {}", start, end);
+			assert!(DayCount::Thirty360Us.day_count(start, end) == us, "{} {}", start, end);
+			assert!(DayCount::ThirtyE360.day_count(start, end) == eurobond, "{} {}", start, end);
+			assert!(isda.day_count(start, end) == e_isda, "{} {}", start, end);
+			assert_close(isda.year_fraction(start, end), e_isda as f64 / 360.0);
+		}
+
+		// The last day of February is not adjusted if it is the termination date.
+		let isda = DayCount::ThirtyE360Isda { termination: date(2008, 2, 29) };
+		assert!(isda.day_count(date(2007, 8, 31), date(2008, 2, 29)) == 179);
+	}
+
+	#[test]
+	fn actual() {
+		let start = date(2003, 11, 1);
+		let end = date(2004, 5, 1);
+		assert!(DayCount::Actual360.day_count(start, end) == 182);
+		assert_close(DayCount::Actual360.year_fraction(start, end), 182.0 / 360.0);
+		assert_close(DayCount::Actual365Fixed.year_fraction(start, end), 182.0 / 365.0);
+		assert_close(DayCount::Actual365Fixed.year_fraction(end, start), -182.0 / 365.0);
+	}
+
+	#[test]
+	fn actual_actual() {
+		// Examples from the ISDA paper "EMU and market conventions: recent developments".
+		let isda = DayCount::ActualActualIsda;
+
+		// Regular semi-annual period.
+		assert_close(isda.year_fraction(date(2003, 11, 1), date(2004, 5, 1)), 61.0 / 365.0 + 121.0 / 366.0);
+		let icma = DayCount::ActualActualIcma {
+			period_start: date(2003, 11, 1),
+			period_end: date(2004, 5, 1),
+			frequency: 2,
+		};
+		assert_close(icma.year_fraction(date(2003, 11, 1), date(2004, 5, 1)), 0.5);
+
+		// Short first period.
+		assert_close(isda.year_fraction(date(1999, 2, 1), date(1999, 7, 1)), 150.0 / 365.0);
+		let icma = DayCount::ActualActualIcma {
+			period_start: date(1998, 7, 1),
+			period_end: date(1999, 7, 1),
+			frequency: 1,
+		};
+		assert_close(icma.year_fraction(date(1999, 2, 1), date(1999, 7, 1)), 150.0 / 365.0);
+
+		// Long first period, split over two notional quarterly periods.
+		assert_close(isda.year_fraction(date(2002, 8, 15), date(2003, 7, 15)), 138.0 / 365.0 + 196.0 / 365.0);
+		let first = DayCount::ActualActualIcma {
+			period_start: date(2002, 10, 15),
+			period_end: date(2003, 1, 15),
+			frequency: 4,
+		};
+		let second = DayCount::ActualActualIcma {
+			period_start: date(2003, 1, 15),
+			period_end: date(2003, 4, 15),
+			frequency: 4,
+		};
+		let fraction = first.year_fraction(date(2002, 11, 15), date(2003, 1, 15)) + second.year_fraction(date(2003, 1, 15), date(2003, 2, 15));
+		assert_close(fraction, 61.0 / 368.0 + 31.0 / 360.0);
+
+		// Semi-annual period with the accrual end on 30 January.
+		assert_close(isda.year_fraction(date(1999, 7, 30), date(2000, 1, 30)), 155.0 / 365.0 + 29.0 / 366.0);
+		let icma = DayCount::ActualActualIcma {
+			period_start: date(1999, 7, 30),
+			period_end: date(2000, 1, 30),
+			frequency: 2,
+		};
+		assert_close(icma.year_fraction(date(1999, 7, 30), date(2000, 1, 30)), 0.5);
+
+		// Multiple years and reversed order.
+		assert_close(isda.year_fraction(date(2003, 7, 1), date(2006, 7, 1)), 184.0 / 365.0 + 2.0 + 181.0 / 365.0);
+		assert_close(isda.year_fraction(date(2006, 7, 1), date(2003, 7, 1)), -(184.0 / 365.0 + 2.0 + 181.0 / 365.0));
+	}
+
+	#[test]
+	fn business_252() {
+		let calendar = BusinessCalendar::weekdays().with_holidays([date(2024, 1, 1)]);
+		let bus = DayCount::Business252 { calendar: &calendar };
+		assert!(bus.day_count(date(2024, 1, 1), date(2024, 2, 1)) == 22);
+		assert_close(bus.year_fraction(date(2024, 1, 1), date(2024, 2, 1)), 22.0 / 252.0);
+		assert!(bus.day_count(date(2024, 2, 1), date(2024, 1, 1)) == -22);
+	}
+
+	#[test]
+	fn name() {
+		assert!(DayCount::Thirty360Us.name() == "30/360 US");
+		assert!(DayCount::Actual365Fixed.name() == "ACT/365F");
+		assert!(DayCount::ActualActualIsda.name() == "ACT/ACT ISDA");
+	}
+}
@@ -0,0 +1,231 @@
+use crate::{Date, Month, Year};
+use crate::date::JULIAN_DAY_OF_YEAR_ZERO;
+
+/// The number of years after which the Gregorian Easter dates repeat.
+const GREGORIAN_EASTER_CYCLE: i32 = 5_700_000;
+
+/// The number of years after which the Julian Easter dates repeat.
+const JULIAN_EASTER_CYCLE: i32 = 532;
+
+/// The number of days in a Julian Easter cycle.
+const DAYS_IN_JULIAN_EASTER_CYCLE: i32 = JULIAN_EASTER_CYCLE * 365 + JULIAN_EASTER_CYCLE / 4;
+
+impl Year {
+	/// Get the date of Easter Sunday according to the Gregorian computus.
+	///
+	/// This is the date of Easter as celebrated by the Western churches.
+	pub const fn easter_western(self) -> Date {
+		// Easter dates repeat every 5.7 million years, so shift the year to avoid negative numbers.
+		let year = self.to_number() as i32 + GREGORIAN_EASTER_CYCLE;
+
+		// Anonymous Gregorian algorithm, also known as the Meeus/Jones/Butcher algorithm.
+		let a = year % 19;
+		let b = year / 100;
+		let c = year % 100;
+		let d = b / 4;
+		let e = b % 4;
+		let f = (b + 8) / 25;
+		let g = (b - f + 1) / 3;
+		let h = (19 * a + b - d - g + 15) % 30;
+		let i = c / 4;
+		let k = c % 4;
+		let l = (32 + 2 * e + 2 * i - h - k) % 7;
+		let m = (a + 11 * h + 22 * l) / 451;
+		let month = (h + l - 7 * m + 114) / 31;
+		let day = (h + l - 7 * m + 114) % 31 + 1;
+
+		let month = if month == 3 { Month::March } else { Month::April };
+		unsafe { self.with_month(month).with_day_unchecked(day as u8) }
+	}
+
+	/// Get the date of Easter Sunday according to the Julian computus.
+	///
+	/// This is the date of Easter as celebrated by the Eastern Orthodox churches.
+	/// The Julian computus gives a date in the Julian calendar,
+	/// which is converted to the proleptic Gregorian calendar by this function.
+	/// As a result, the returned date can fall in May.
+	pub const fn easter_orthodox(self) -> Date {
+		// Easter dates repeat every 532 years in the Julian calendar, so shift the year to avoid negative numbers.
+		let cycles = 62;
+		let year = self.to_number() as i32 + cycles * JULIAN_EASTER_CYCLE;
+
+		// Meeus' Julian algorithm.
+		let a = year % 4;
+		let b = year % 7;
+		let c = year % 19;
+		let d = (19 * c + 15) % 30;
+		let e = (2 * a + 4 * b - d + 34) % 7;
+		let month = (d + e + 114) / 31;
+		let day = (d + e + 114) % 31 + 1;
+
+		// Convert the Julian calendar date to a Julian day number, and then to a Gregorian date.
+		let shift = (14 - month) / 12;
+		let y = year + 4800 - shift;
+		let m = month + 12 * shift - 3;
+		let julian_day = day + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083;
+		Date::from_days_since_year_zero(julian_day - JULIAN_DAY_OF_YEAR_ZERO - cycles * DAYS_IN_JULIAN_EASTER_CYCLE)
+	}
+}
+
+/// A movable feast, defined by a fixed number of days before or after Easter Sunday.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MovableFeast {
+	/// Shrove Tuesday (Mardi Gras), 47 days before Easter.
+	ShroveTuesday,
+
+	/// Ash Wednesday, 46 days before Easter.
+	AshWednesday,
+
+	/// Palm Sunday, 7 days before Easter.
+	PalmSunday,
+
+	/// Maundy Thursday, 3 days before Easter.
+	MaundyThursday,
+
+	/// Good Friday, 2 days before Easter.
+	GoodFriday,
+
+	/// Holy Saturday, 1 day before Easter.
+	HolySaturday,
+
+	/// Easter Sunday.
+	EasterSunday,
+
+	/// Easter Monday, 1 day after Easter.
+	EasterMonday,
+
+	/// Ascension Day, 39 days after Easter.
+	Ascension,
+
+	/// Pentecost (Whit Sunday), 49 days after Easter.
+	Pentecost,
+
+	/// Whit Monday, 50 days after Easter.
+	WhitMonday,
+
+	/// Trinity Sunday, 56 days after Easter.
+	TrinitySunday,
+
+	/// Corpus Christi, 60 days after Easter.
+	CorpusChristi,
+}
+
+impl MovableFeast {
+	/// Get the number of days from Easter Sunday to the feast.
+	///
+	/// The number is negative for feasts before Easter.
+	pub const fn days_from_easter(self) -> i32 {
+		match self {
+			Self::ShroveTuesday => -47,
+			Self::AshWednesday => -46,
+			Self::PalmSunday => -7,
+			Self::MaundyThursday => -3,
+			Self::GoodFriday => -2,
+			Self::HolySaturday => -1,
+			Self::EasterSunday => 0,
+			Self::EasterMonday => 1,
+			Self::Ascension => 39,
+			Self::Pentecost => 49,
+			Self::WhitMonday => 50,
+			Self::TrinitySunday => 56,
+			Self::CorpusChristi => 60,
+		}
+	}
+
+	/// Get the date of the feast in a year, based on the Western date of Easter.
+	pub const fn western(self, year: Year) -> Date {
+		year.easter_western().add_days(self.days_from_easter())
+	}
+
+	/// Get the date of the feast in a year, based on the Orthodox date of Easter.
+	pub const fn orthodox(self, year: Year) -> Date {
+		year.easter_orthodox().add_days(self.days_from_easter())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, Weekday};
+	use crate::util::date;
+	use assert2::assert;
+
+	#[test]
+	fn easter_western() {
+		// Published Easter dates, including the earliest and latest possible dates.
+		assert!(Year::new(1583).easter_western() == date(1583, April, 10));
+		assert!(Year::new(1700).easter_western() == date(1700, April, 11));
+		assert!(Year::new(1818).easter_western() == date(1818, March, 22));
+		assert!(Year::new(1900).easter_western() == date(1900, April, 15));
+		assert!(Year::new(1943).easter_western() == date(1943, April, 25));
+		assert!(Year::new(1961).easter_western() == date(1961, April, 2));
+		assert!(Year::new(2000).easter_western() == date(2000, April, 23));
+		assert!(Year::new(2008).easter_western() == date(2008, March, 23));
+		assert!(Year::new(2011).easter_western() == date(2011, April, 24));
+		assert!(Year::new(2019).easter_western() == date(2019, April, 21));
+		assert!(Year::new(2024).easter_western() == date(2024, March, 31));
+		assert!(Year::new(2025).easter_western() == date(2025, April, 20));
+		assert!(Year::new(2038).easter_western() == date(2038, April, 25));
+		assert!(Year::new(2100).easter_western() == date(2100, March, 28));
+		assert!(Year::new(2285).easter_western() == date(2285, March, 22));
+		assert!(Year::new(2400).easter_western() == date(2400, April, 16));
+		assert!(Year::new(3000).easter_western() == date(3000, April, 13));
+	}
+
+	#[test]
+	fn easter_orthodox() {
+		assert!(Year::new(1583).easter_orthodox() == date(1583, April, 10));
+		assert!(Year::new(1700).easter_orthodox() == date(1700, April, 11));
+		assert!(Year::new(1818).easter_orthodox() == date(1818, April, 26));
+		assert!(Year::new(1900).easter_orthodox() == date(1900, April, 22));
+		assert!(Year::new(1961).easter_orthodox() == date(1961, April, 9));
+		assert!(Year::new(2000).easter_orthodox() == date(2000, April, 30));
+		assert!(Year::new(2008).easter_orthodox() == date(2008, April, 27));
+		assert!(Year::new(2011).easter_orthodox() == date(2011, April, 24));
+		assert!(Year::new(2019).easter_orthodox() == date(2019, April, 28));
+		assert!(Year::new(2024).easter_orthodox() == date(2024, May, 5));
+		assert!(Year::new(2025).easter_orthodox() == date(2025, April, 20));
+		assert!(Year::new(2100).easter_orthodox() == date(2100, May, 2));
+		assert!(Year::new(2285).easter_orthodox() == date(2285, April, 26));
+		assert!(Year::new(3000).easter_orthodox() == date(3000, April, 20));
+	}
+
+	#[test]
+	fn easter_is_sunday() {
+		for year in -3000..3000 {
+			let year = Year::new(year);
+			let western = year.easter_western();
+			let orthodox = year.easter_orthodox();
+			assert!(western.weekday() == Weekday::Sunday, "{:?}", western);
+			assert!(orthodox.weekday() == Weekday::Sunday, "{:?}", orthodox);
+			assert!(western >= date(year.to_number(), March, 22));
+			assert!(western <= date(year.to_number(), April, 25));
+			assert!(western.year() == year);
+			assert!(orthodox.year() == year);
+		}
+	}
+
+	#[test]
+	fn movable_feasts() {
+		let year = Year::new(2024);
+		assert!(MovableFeast::ShroveTuesday.western(year) == date(2024, February, 13));
+		assert!(MovableFeast::AshWednesday.western(year) == date(2024, February, 14));
+		assert!(MovableFeast::PalmSunday.western(year) == date(2024, March, 24));
+		assert!(MovableFeast::GoodFriday.western(year) == date(2024, March, 29));
+		assert!(MovableFeast::EasterSunday.western(year) == date(2024, March, 31));
+		assert!(MovableFeast::EasterMonday.western(year) == date(2024, April, 1));
+		assert!(MovableFeast::Ascension.western(year) == date(2024, May, 9));
+		assert!(MovableFeast::Pentecost.western(year) == date(2024, May, 19));
+		assert!(MovableFeast::WhitMonday.western(year) == date(2024, May, 20));
+		assert!(MovableFeast::TrinitySunday.western(year) == date(2024, May, 26));
+		assert!(MovableFeast::CorpusChristi.western(year) == date(2024, May, 30));
+
+		assert!(MovableFeast::GoodFriday.orthodox(year) == date(2024, May, 3));
+		assert!(MovableFeast::Pentecost.orthodox(year) == date(2024, June, 23));
+
+		let year = Year::new(1943);
+		assert!(MovableFeast::Ascension.western(year) == date(1943, June, 3));
+		assert!(MovableFeast::CorpusChristi.western(year) == date(1943, June, 24));
+	}
+}
@@ -19,6 +19,186 @@ impl InvalidDateSyntax {
 	}
 }
 
+/// The string is not a valid weekday name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidWeekdayName {
+	_private: (),
+}
+
+impl InvalidWeekdayName {
+	pub fn new() -> Self {
+		Self { _private: () }
+	}
+}
+
+/// The string is not a valid month name.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidMonthName {
+	_private: (),
+}
+
+impl InvalidMonthName {
+	pub fn new() -> Self {
+		Self { _private: () }
+	}
+}
+
+/// The string does not follow the proper syntax for a period.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidPeriodSyntax {
+	_private: (),
+}
+
+impl InvalidPeriodSyntax {
+	pub fn new() -> Self {
+		Self { _private: () }
+	}
+}
+
+/// The string is not a valid futures contract code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidImmCode {
+	_private: (),
+}
+
+impl InvalidImmCode {
+	pub fn new() -> Self {
+		Self { _private: () }
+	}
+}
+
+/// The string does not follow the proper syntax for a set of weekdays.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidWeekdaySetSyntax {
+	_private: (),
+}
+
+impl InvalidWeekdaySetSyntax {
+	pub fn new() -> Self {
+		Self { _private: () }
+	}
+}
+
+/// The text does not follow the syntax for holiday definitions.
+///
+/// See [`HolidaySet`][crate::HolidaySet] for a description of the syntax.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidHolidaySyntax {
+	/// The line number of the error, starting at 1.
+	pub line: usize,
+
+	/// The column number of the error in characters, starting at 1.
+	pub column: usize,
+
+	/// A description of what was expected at the location of the error.
+	pub expected: &'static str,
+}
+
+/// The string is not a valid cron day expression.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidCronSyntax {
+	/// A description of what was expected in the expression.
+	pub expected: &'static str,
+}
+
+impl InvalidCronSyntax {
+	pub fn new(expected: &'static str) -> Self {
+		Self { expected }
+	}
+}
+
+/// The string is not a valid list of dates and date ranges.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidDateSetSyntax {
+	_private: (),
+}
+
+impl InvalidDateSetSyntax {
+	pub fn new() -> Self {
+		Self { _private: () }
+	}
+}
+
+/// The string is not a valid ISO 8601 interval, repeating interval or duration.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidIntervalSyntax {
+	pub expected: &'static str,
+}
+
+impl InvalidIntervalSyntax {
+	pub fn new(expected: &'static str) -> Self {
+		Self { expected }
+	}
+}
+
+/// The string is not a valid date expression.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidDateExpression {
+	/// A description of what was expected at the location of the error.
+	pub expected: &'static str,
+}
+
+impl InvalidDateExpression {
+	pub fn new(expected: &'static str) -> Self {
+		Self { expected }
+	}
+}
+
+/// A date expression could not be evaluated.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DateExprError {
+	/// An operation gave a day that does not exist and the clamp policy rejects invalid dates.
+	InvalidDayOfMonth(InvalidDayOfMonth),
+
+	/// An operation gave a date outside of the supported range of years.
+	OutOfRange,
+}
+
+impl From<InvalidDayOfMonth> for DateExprError {
+	fn from(other: InvalidDayOfMonth) -> Self {
+		Self::InvalidDayOfMonth(other)
+	}
+}
+
+/// The data is not a valid iCalendar file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidICalendar {
+	/// The line number of the error, starting at 1.
+	pub line: usize,
+
+	/// A description of what was expected at the location of the error.
+	pub expected: &'static str,
+}
+
+/// The string is not a valid recurrence rule.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidRecurrenceRule {
+	/// A description of what was expected in the rule.
+	pub expected: &'static str,
+}
+
+impl InvalidRecurrenceRule {
+	pub fn new(expected: &'static str) -> Self {
+		Self { expected }
+	}
+}
+
+/// The parameters of a schedule are not valid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvalidSchedule {
+	/// The termination date is not after the effective date.
+	TerminationNotAfterEffective,
+
+	/// The frequency of the schedule is zero or negative.
+	NonPositiveFrequency,
+
+	/// The roll day is not a valid day of the month.
+	InvalidRollDay { roll_day: u8 },
+
+	/// The first or last regular date is outside of the schedule, or they are not in order.
+	RegularDatesOutOfRange,
+}
+
 /// The date is not valid.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum InvalidDate {
@@ -38,6 +218,12 @@ pub struct InvalidMonthNumber {
 	pub number: u8,
 }
 
+/// The weekday number is not valid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidWeekdayNumber {
+	pub number: u8,
+}
+
 /// The day is not valid for the year and month.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub struct InvalidDayOfMonth {
@@ -110,6 +296,21 @@ mod std_support {
 	impl std::error::Error for InvalidDateSyntax {}
 	impl std::error::Error for InvalidDate {}
 	impl std::error::Error for InvalidMonthNumber {}
+	impl std::error::Error for InvalidWeekdayNumber {}
+	impl std::error::Error for InvalidWeekdayName {}
+	impl std::error::Error for InvalidMonthName {}
+	impl std::error::Error for InvalidPeriodSyntax {}
+	impl std::error::Error for InvalidImmCode {}
+	impl std::error::Error for InvalidHolidaySyntax {}
+	impl std::error::Error for InvalidSchedule {}
+	impl std::error::Error for InvalidRecurrenceRule {}
+	impl std::error::Error for InvalidICalendar {}
+	impl std::error::Error for InvalidCronSyntax {}
+	impl std::error::Error for InvalidIntervalSyntax {}
+	impl std::error::Error for InvalidDateExpression {}
+	impl std::error::Error for DateExprError {}
+	impl std::error::Error for InvalidDateSetSyntax {}
+	impl std::error::Error for InvalidWeekdaySetSyntax {}
 	impl std::error::Error for InvalidDayOfMonth {}
 	impl std::error::Error for InvalidDayOfYear {}
 }
@@ -144,6 +345,110 @@ impl core::fmt::Display for InvalidMonthNumber {
 	}
 }
 
+impl core::fmt::Display for InvalidWeekdayNumber {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid weekday number: expected 1-7, got {}", self.number)
+	}
+}
+
+impl core::fmt::Display for InvalidWeekdayName {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid weekday name: expected a name like \"Monday\" or \"Mon\"")
+	}
+}
+
+impl core::fmt::Display for InvalidMonthName {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid month name: expected a name like \"January\" or \"Jan\"")
+	}
+}
+
+impl core::fmt::Display for InvalidHolidaySyntax {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(
+			f,
+			"invalid holiday definition on line {}, column {}: expected {}",
+			self.line, self.column, self.expected
+		)
+	}
+}
+
+impl core::fmt::Display for InvalidCronSyntax {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid cron day expression: expected {}", self.expected)
+	}
+}
+
+impl core::fmt::Display for InvalidDateSetSyntax {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid date set syntax: expected comma separated dates or ranges like \"2020-01-01/2020-01-31\"")
+	}
+}
+
+impl core::fmt::Display for InvalidIntervalSyntax {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid ISO 8601 interval: expected {}", self.expected)
+	}
+}
+
+impl core::fmt::Display for InvalidDateExpression {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid date expression: expected {}", self.expected)
+	}
+}
+
+impl core::fmt::Display for DateExprError {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::InvalidDayOfMonth(e) => write!(f, "{}", e),
+			Self::OutOfRange => write!(f, "date expression result is outside of the supported range of years"),
+		}
+	}
+}
+
+impl core::fmt::Display for InvalidICalendar {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid iCalendar data on line {}: expected {}", self.line, self.expected)
+	}
+}
+
+impl core::fmt::Display for InvalidRecurrenceRule {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid recurrence rule: expected {}", self.expected)
+	}
+}
+
+impl core::fmt::Display for InvalidSchedule {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::TerminationNotAfterEffective => write!(f, "invalid schedule: expected termination date after effective date"),
+			Self::NonPositiveFrequency => write!(f, "invalid schedule: expected a positive frequency"),
+			Self::InvalidRollDay { roll_day } => write!(f, "invalid roll day: expected 1-31, got {}", roll_day),
+			Self::RegularDatesOutOfRange => {
+				write!(f, "invalid schedule: expected regular dates in order and between the effective and termination date")
+			},
+		}
+	}
+}
+
+impl core::fmt::Display for InvalidPeriodSyntax {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid period syntax: expected a number and a unit like \"3M\" or \"1Y\"")
+	}
+}
+
+impl core::fmt::Display for InvalidImmCode {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid contract code: expected a month letter and one or two year digits like \"H5\" or \"Z24\"")
+	}
+}
+
+impl core::fmt::Display for InvalidWeekdaySetSyntax {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "invalid weekday set syntax: expected weekdays and ranges like \"Mon,Wed-Fri\"")
+	}
+}
+
 impl core::fmt::Display for InvalidDayOfMonth {
 	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
 		write!(
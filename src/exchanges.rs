@@ -0,0 +1,208 @@
+//! Built-in trading calendars for a number of stock exchanges.
+//!
+//! Each exchange is identified by its ISO 10383 market identifier code (MIC).
+//! Use [`from_mic()`] to select a calendar by MIC, or one of the functions for a specific exchange.
+//!
+//! The calendars include regular holidays, one-off closures and early closes.
+//! Opening and closing times are given in the local time of the exchange.
+//!
+//! ```
+//! # use gregorian::Date;
+//! let nyse = gregorian::exchanges::from_mic("XNYS").unwrap();
+//! assert!(nyse.is_trading_day(Date::new(2024, 7, 3).unwrap()));
+//! assert!(nyse.is_early_close(Date::new(2024, 7, 3).unwrap()));
+//! assert!(!nyse.is_trading_day(Date::new(2024, 7, 4).unwrap()));
+//! ```
+
+use crate::{holidays, HolidayDate, HolidayRule, HolidaySet, Month, Month::*, Observance, TimeOfDay, TradingCalendar, Weekday::*};
+
+/// The market identifier codes of all built-in exchange calendars.
+pub const MIC_CODES: [&str; 3] = ["XNYS", "XLON", "XETR"];
+
+/// Get the trading calendar of an exchange by its ISO 10383 market identifier code.
+///
+/// The code is matched case-insensitively.
+/// Returns `None` if there is no built-in calendar for the exchange.
+/// See [`MIC_CODES`] for the supported codes.
+pub fn from_mic(mic: &str) -> Option<TradingCalendar> {
+	if mic.eq_ignore_ascii_case("XNYS") {
+		Some(nyse())
+	} else if mic.eq_ignore_ascii_case("XLON") {
+		Some(lse())
+	} else if mic.eq_ignore_ascii_case("XETR") {
+		Some(xetra())
+	} else {
+		None
+	}
+}
+
+/// One-off closures of the New York Stock Exchange.
+const NYSE_SPECIAL_CLOSURES: &[(i16, Month, u8, &str)] = &[
+	(1985, September, 27, "Hurricane Gloria"),
+	(1994, April, 27, "Funeral of Richard Nixon"),
+	(2001, September, 11, "September 11 attacks"),
+	(2001, September, 12, "September 11 attacks"),
+	(2001, September, 13, "September 11 attacks"),
+	(2001, September, 14, "September 11 attacks"),
+	(2004, June, 11, "Funeral of Ronald Reagan"),
+	(2007, January, 2, "Funeral of Gerald Ford"),
+	(2012, October, 29, "Hurricane Sandy"),
+	(2012, October, 30, "Hurricane Sandy"),
+	(2018, December, 5, "Funeral of George H. W. Bush"),
+	(2025, January, 9, "Funeral of Jimmy Carter"),
+];
+
+/// The trading calendar of the New York Stock Exchange (XNYS).
+///
+/// Regular trading hours are 09:30 to 16:00.
+/// The exchange closes at 13:00 on the day before Independence Day, the day after Thanksgiving and on Christmas Eve.
+pub fn nyse() -> TradingCalendar {
+	let nearest = Observance::NEAREST_WEEKDAY;
+	let mut closures = HolidaySet::new()
+		.with(HolidayRule::fixed("New Year's Day", January, 1).observed(Observance::SUNDAY_TO_MONDAY))
+		.with(HolidayRule::nth_weekday("Martin Luther King Jr. Day", 3, Monday, January).since(1998))
+		.with(HolidayRule::nth_weekday("Washington's Birthday", 3, Monday, February))
+		.with(HolidayRule::easter("Good Friday", -2))
+		.with(HolidayRule::nth_weekday("Memorial Day", -1, Monday, May))
+		.with(
+			HolidayRule::fixed("Juneteenth National Independence Day", June, 19)
+				.since(2022)
+				.observed(nearest),
+		)
+		.with(HolidayRule::fixed("Independence Day", July, 4).observed(nearest))
+		.with(HolidayRule::nth_weekday("Labor Day", 1, Monday, September))
+		.with(HolidayRule::nth_weekday("Thanksgiving Day", 4, Thursday, November))
+		.with(HolidayRule::fixed("Christmas Day", December, 25).observed(nearest));
+	closures.extend(special_closures(NYSE_SPECIAL_CLOSURES));
+
+	let early_closes = HolidaySet::new()
+		.with(HolidayRule::fixed("Day before Independence Day", July, 3))
+		.with(HolidayRule::new(
+			"Day after Thanksgiving",
+			HolidayDate::WeekdayOnOrAfter {
+				month: November,
+				day: 23,
+				weekday: Friday,
+			},
+		))
+		.with(HolidayRule::fixed("Christmas Eve", December, 24));
+
+	TradingCalendar::new(closures, TimeOfDay::new(9, 30), TimeOfDay::new(16, 0)).with_early_closes(early_closes, TimeOfDay::new(13, 0))
+}
+
+/// The trading calendar of the London Stock Exchange (XLON).
+///
+/// The exchange is closed on all bank holidays of England and Wales, see [`holidays::england_and_wales()`].
+/// Regular trading hours are 08:00 to 16:30.
+/// The exchange closes at 12:30 on Christmas Eve and New Year's Eve.
+pub fn lse() -> TradingCalendar {
+	let early_closes = HolidaySet::new()
+		.with(HolidayRule::fixed("Christmas Eve", December, 24))
+		.with(HolidayRule::fixed("New Year's Eve", December, 31));
+
+	TradingCalendar::new(holidays::england_and_wales(), TimeOfDay::new(8, 0), TimeOfDay::new(16, 30))
+		.with_early_closes(early_closes, TimeOfDay::new(12, 30))
+}
+
+/// The trading calendar of the Xetra trading venue of the Frankfurt Stock Exchange (XETR).
+///
+/// Regular trading hours are 09:00 to 17:30.
+/// The calendar reflects the trading holidays in use since 2015,
+/// when Xetra started trading on Whit Monday and German Unity Day.
+pub fn xetra() -> TradingCalendar {
+	let closures = HolidaySet::new()
+		.with(HolidayRule::fixed("New Year's Day", January, 1))
+		.with(HolidayRule::easter("Good Friday", -2))
+		.with(HolidayRule::easter("Easter Monday", 1))
+		.with(HolidayRule::fixed("Labour Day", May, 1))
+		.with(HolidayRule::fixed("Christmas Eve", December, 24))
+		.with(HolidayRule::fixed("Christmas Day", December, 25))
+		.with(HolidayRule::fixed("Second Day of Christmas", December, 26))
+		.with(HolidayRule::fixed("New Year's Eve", December, 31));
+
+	TradingCalendar::new(closures, TimeOfDay::new(9, 0), TimeOfDay::new(17, 30))
+}
+
+/// Convert a table of one-off closures to holiday rules.
+fn special_closures(table: &'static [(i16, Month, u8, &'static str)]) -> impl Iterator<Item = HolidayRule> {
+	table
+		.iter()
+		.map(|&(year, month, day, name)| HolidayRule::fixed(name, month, day).since(year).until(year))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Year;
+	use crate::util::date;
+	use assert2::assert;
+
+	#[test]
+	fn from_mic() {
+		for mic in MIC_CODES {
+			assert!(super::from_mic(mic).is_some());
+		}
+		assert!(super::from_mic("xnys") == Some(super::nyse()));
+		assert!(super::from_mic("XPAR") == None);
+	}
+
+	#[test]
+	fn nyse() {
+		let nyse = super::nyse();
+		let closed: Vec<_> = Year::new(2024)
+			.days()
+			.into_iter()
+			.filter(|&date| !nyse.weekend().contains_date(date) && !nyse.is_trading_day(date))
+			.collect();
+		let expected = [
+			date(2024, January, 1),
+			date(2024, January, 15),
+			date(2024, February, 19),
+			date(2024, March, 29),
+			date(2024, May, 27),
+			date(2024, June, 19),
+			date(2024, July, 4),
+			date(2024, September, 2),
+			date(2024, November, 28),
+			date(2024, December, 25),
+		];
+		assert!(closed == expected);
+		assert!(nyse.trading_days_between(date(2024, January, 1), date(2025, January, 1)) == 252);
+
+		// New Year's Day on a Saturday is not observed.
+		assert!(nyse.is_trading_day(date(2021, December, 31)));
+		assert!(!nyse.is_trading_day(date(2021, December, 24)));
+		assert!(!nyse.is_trading_day(date(2012, October, 30)));
+		assert!(nyse.closure_name(date(2025, January, 9)) == Some("Funeral of Jimmy Carter"));
+		assert!(nyse.next_trading_day(date(2001, September, 10)) == date(2001, September, 17));
+
+		assert!(nyse.is_early_close(date(2024, July, 3)));
+		assert!(nyse.is_early_close(date(2024, November, 29)));
+		assert!(nyse.is_early_close(date(2024, December, 24)));
+		assert!(!nyse.is_early_close(date(2020, July, 3)));
+		assert!(nyse.trading_hours(date(2024, November, 29)) == Some((TimeOfDay::new(9, 30), TimeOfDay::new(13, 0))));
+	}
+
+	#[test]
+	fn lse() {
+		let lse = super::lse();
+		assert!(!lse.is_trading_day(date(2022, September, 19)));
+		assert!(!lse.is_trading_day(date(2023, May, 8)));
+		assert!(!lse.is_trading_day(date(2021, December, 28)));
+		assert!(lse.is_early_close(date(2024, December, 24)));
+		assert!(lse.is_early_close(date(2024, December, 31)));
+		assert!(lse.trading_hours(date(2024, December, 31)) == Some((TimeOfDay::new(8, 0), TimeOfDay::new(12, 30))));
+		assert!(lse.trading_days_between(date(2024, January, 1), date(2025, January, 1)) == 254);
+	}
+
+	#[test]
+	fn xetra() {
+		let xetra = super::xetra();
+		assert!(!xetra.is_trading_day(date(2024, December, 24)));
+		assert!(!xetra.is_trading_day(date(2024, December, 31)));
+		assert!(xetra.is_trading_day(date(2024, May, 20)));
+		assert!(xetra.is_trading_day(date(2024, October, 3)));
+		assert!(xetra.next_trading_day(date(2024, December, 23)) == date(2024, December, 27));
+		assert!(xetra.trading_days_between(date(2024, January, 1), date(2025, January, 1)) == 254);
+	}
+}
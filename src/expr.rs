@@ -0,0 +1,587 @@
+use crate::date::MIN_YEAR;
+use crate::{BusinessCalendar, Date, DateExprError, DateResultExt, InvalidDateExpression, InvalidDayOfMonth, Period, WeekRules, Weekday};
+
+/// A relative date expression, such as `2020-01-31 + 1M - 3D, next Friday`.
+///
+/// An expression starts with a date or `today`, followed by operations that are applied from left to right:
+/// * `+ 3D` or `- 1M`: add or subtract a [`Period`] of days (`D`), weeks (`W`), months (`M`) or years (`Y`).
+/// * `+ 2BD` or `- 2BD`: add or subtract business days.
+/// * `start of week`, `end of month`: go to the start or end of the week, month, quarter or year.
+/// * `next Friday`, `previous Mon`: go to the next or previous occurrence of a weekday.
+///
+/// The date and the operations are separated by whitespace or commas.
+/// The sign of an addition or subtraction can be attached to the amount, like `+3D`.
+/// If an expression starts with an operation, it applies to `today`.
+/// Keywords and weekday names are case insensitive.
+///
+/// Parsing an expression gives the structured form, which can be inspected or modified,
+/// and formatted back to a string in canonical form.
+/// To evaluate an expression, you need an [`ExprContext`] with the current date and a [`ClampPolicy`].
+///
+/// ```
+/// # use gregorian::{ClampPolicy, Date, DateExpr, ExprBase, ExprContext, ExprOp, Month::*, Period, Weekday};
+/// let expr: DateExpr = "2020-01-31 +1M -3D, next fri".parse().unwrap();
+/// assert!(expr.base == ExprBase::Date(Date::new(2020, January, 31).unwrap()));
+/// assert!(expr.ops == [ExprOp::Add(Period::months(1)), ExprOp::Add(Period::days(-3)), ExprOp::Next(Weekday::Friday)]);
+/// assert!(expr.to_string() == "2020-01-31 + 1M - 3D, next Friday");
+///
+/// let context = ExprContext::new(Date::new(2024, May, 15).unwrap(), ClampPolicy::PrevValid);
+/// assert!(expr.eval(&context) == Ok(Date::new(2020, February, 28).unwrap()));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DateExpr {
+	/// The date to start from.
+	pub base: ExprBase,
+
+	/// The operations to apply to the base date, in order.
+	pub ops: Vec<ExprOp>,
+}
+
+/// The starting point of a [`DateExpr`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ExprBase {
+	/// The current date of the [`ExprContext`].
+	Today,
+
+	/// A fixed date.
+	Date(Date),
+}
+
+/// An operation in a [`DateExpr`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ExprOp {
+	/// Add a calendar period, which may be negative.
+	Add(Period),
+
+	/// Add a number of business days, which may be negative.
+	///
+	/// See [`BusinessCalendar::add_business_days()`] for the exact rules.
+	BusinessDays(i32),
+
+	/// Go to the first day of the week, month, quarter or year.
+	StartOf(CalendarUnit),
+
+	/// Go to the last day of the week, month, quarter or year.
+	EndOf(CalendarUnit),
+
+	/// Go to the first date with the given weekday strictly after the date.
+	Next(Weekday),
+
+	/// Go to the last date with the given weekday strictly before the date.
+	Previous(Weekday),
+}
+
+/// A calendar unit for the [`ExprOp::StartOf`] and [`ExprOp::EndOf`] operations.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum CalendarUnit {
+	Week,
+	Month,
+	Quarter,
+	Year,
+}
+
+/// What to do when adding months or years to a date gives a day that does not exist, like February 31.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ClampPolicy {
+	/// Fail the evaluation with a [`DateExprError::InvalidDayOfMonth`] error.
+	Reject,
+
+	/// Use the last day of the month, like [`DateResultExt::or_prev_valid()`].
+	PrevValid,
+
+	/// Use the first day of the next month, like [`DateResultExt::or_next_valid()`].
+	NextValid,
+}
+
+/// The context needed to evaluate a [`DateExpr`].
+///
+/// The context holds the date used for `today`, the [`ClampPolicy`] for invalid dates,
+/// the [`WeekRules`] for the start and end of weeks, and the [`BusinessCalendar`] for business day offsets.
+#[derive(Clone, Debug)]
+pub struct ExprContext {
+	today: Date,
+	clamp: ClampPolicy,
+	week_rules: WeekRules,
+	calendar: BusinessCalendar,
+}
+
+impl ClampPolicy {
+	/// Apply the policy to a possibly invalid date.
+	pub fn apply(self, date: Result<Date, InvalidDayOfMonth>) -> Result<Date, InvalidDayOfMonth> {
+		match self {
+			Self::Reject => date,
+			Self::PrevValid => Ok(date.or_prev_valid()),
+			Self::NextValid => Ok(date.or_next_valid()),
+		}
+	}
+}
+
+impl ExprContext {
+	/// Create a new context with the date for `today` and the policy for invalid dates.
+	///
+	/// The context uses ISO 8601 weeks and a business calendar without holidays and Saturday and Sunday as weekend.
+	pub fn new(today: Date, clamp: ClampPolicy) -> Self {
+		Self {
+			today,
+			clamp,
+			week_rules: WeekRules::ISO,
+			calendar: BusinessCalendar::weekdays(),
+		}
+	}
+
+	/// Set the week rules for the start and end of weeks, returning the modified context.
+	pub fn with_week_rules(mut self, week_rules: WeekRules) -> Self {
+		self.week_rules = week_rules;
+		self
+	}
+
+	/// Set the business calendar for business day offsets, returning the modified context.
+	pub fn with_calendar(mut self, calendar: BusinessCalendar) -> Self {
+		self.calendar = calendar;
+		self
+	}
+
+	/// Get the date used for `today`.
+	pub fn today(&self) -> Date {
+		self.today
+	}
+
+	/// Get the policy for invalid dates.
+	pub fn clamp_policy(&self) -> ClampPolicy {
+		self.clamp
+	}
+
+	/// Get the week rules for the start and end of weeks.
+	pub fn week_rules(&self) -> WeekRules {
+		self.week_rules
+	}
+
+	/// Get the business calendar for business day offsets.
+	pub fn calendar(&self) -> &BusinessCalendar {
+		&self.calendar
+	}
+}
+
+impl DateExpr {
+	/// Create an expression without operations.
+	pub fn new(base: ExprBase) -> Self {
+		Self { base, ops: Vec::new() }
+	}
+
+	/// Add an operation to the end of the expression, returning the modified expression.
+	pub fn with_op(mut self, op: ExprOp) -> Self {
+		self.ops.push(op);
+		self
+	}
+
+	/// Evaluate the expression.
+	///
+	/// The clamp policy of the context is applied after every operation that gives an invalid date,
+	/// so `2020-01-31 + 1M + 1M` gives `2020-03-29` with [`ClampPolicy::PrevValid`].
+	///
+	/// If the base date or the result of any operation is outside of the supported range of years,
+	/// the evaluation fails with [`DateExprError::OutOfRange`] regardless of the clamp policy.
+	pub fn eval(&self, context: &ExprContext) -> Result<Date, DateExprError> {
+		let mut date = match self.base {
+			ExprBase::Today => context.today,
+			ExprBase::Date(date) => date,
+		};
+		if date.year().to_number() < MIN_YEAR {
+			return Err(DateExprError::OutOfRange);
+		}
+		for op in &self.ops {
+			date = op.apply(date, context)?;
+		}
+		Ok(date)
+	}
+}
+
+impl ExprOp {
+	/// Apply the operation to a date.
+	///
+	/// Fails with [`DateExprError::OutOfRange`] if the result is outside of the supported range of years.
+	/// Business day offsets also fail that way if the calendar could need more days than the range allows.
+	pub fn apply(self, date: Date, context: &ExprContext) -> Result<Date, DateExprError> {
+		let date = match self {
+			Self::Add(period) => match date.checked_add_period(period) {
+				Some(date) => Some(context.clamp.apply(date)?),
+				None => None,
+			},
+			Self::BusinessDays(days) => add_business_days(date, days, &context.calendar),
+			Self::StartOf(unit) => unit.start(date, context.week_rules),
+			Self::EndOf(unit) => unit.end(date, context.week_rules),
+			Self::Next(weekday) => match date.weekday().days_until(weekday) {
+				0 => date.checked_add_days(7),
+				days => date.checked_add_days(days as i32),
+			},
+			Self::Previous(weekday) => match weekday.days_until(date.weekday()) {
+				0 => date.checked_add_days(-7),
+				days => date.checked_add_days(-(days as i32)),
+			},
+		};
+		date.ok_or(DateExprError::OutOfRange)
+	}
+
+	/// Check if the operation is written with a `+` or `-` sign.
+	fn is_arithmetic(self) -> bool {
+		matches!(self, Self::Add(_) | Self::BusinessDays(_))
+	}
+}
+
+impl CalendarUnit {
+	/// Get the lowercase name of the unit as used in expressions.
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Week => "week",
+			Self::Month => "month",
+			Self::Quarter => "quarter",
+			Self::Year => "year",
+		}
+	}
+
+	/// Get the first day of the unit that contains a date.
+	///
+	/// Returns `None` if the week starts before the supported range of years.
+	pub fn start(self, date: Date, week_rules: WeekRules) -> Option<Date> {
+		match self {
+			Self::Week => date.checked_add_days(-(week_rules.first_weekday.days_until(date.weekday()) as i32)),
+			Self::Month => Some(date.year_month().first_day()),
+			Self::Quarter => Some(quarter_start(date).first_day()),
+			Self::Year => Some(date.year().first_day()),
+		}
+	}
+
+	/// Get the last day of the unit that contains a date.
+	///
+	/// Returns `None` if the week ends after the year [`i16::MAX`].
+	pub fn end(self, date: Date, week_rules: WeekRules) -> Option<Date> {
+		match self {
+			Self::Week => date.checked_add_days(6 - week_rules.first_weekday.days_until(date.weekday()) as i32),
+			Self::Month => Some(date.year_month().last_day()),
+			Self::Quarter => Some(quarter_start(date).add_months(2).last_day()),
+			Self::Year => Some(date.year().last_day()),
+		}
+	}
+}
+
+/// Add business days to a date, or return `None` if the result could be outside of the supported range of years.
+///
+/// Every run of business days spans at most a week per business day in the week,
+/// and every holiday adds at most one more week.
+/// If that many calendar days do not fit in the range, the calendar is not asked at all.
+fn add_business_days(date: Date, days: i32, calendar: &BusinessCalendar) -> Option<Date> {
+	let per_week = i64::from(calendar.workdays().len());
+	let weeks = i64::from(days.unsigned_abs()) / per_week + 1 + calendar.holidays().len() as i64;
+	let span = i32::try_from(weeks * 7).ok()?;
+	date.checked_add_days(if days < 0 { -span } else { span })?;
+	Some(calendar.add_business_days(date, days))
+}
+
+/// Get the first month of the quarter that contains a date.
+fn quarter_start(date: Date) -> crate::YearMonth {
+	let month = date.month().to_number();
+	date.year_month().sub_months(i32::from((month - 1) % 3))
+}
+
+impl From<Date> for ExprBase {
+	fn from(other: Date) -> Self {
+		Self::Date(other)
+	}
+}
+
+impl From<Date> for DateExpr {
+	fn from(other: Date) -> Self {
+		Self::new(ExprBase::Date(other))
+	}
+}
+
+impl core::str::FromStr for CalendarUnit {
+	type Err = InvalidDateExpression;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		[Self::Week, Self::Month, Self::Quarter, Self::Year]
+			.into_iter()
+			.find(|unit| data.eq_ignore_ascii_case(unit.name()))
+			.ok_or_else(|| InvalidDateExpression::new("`week`, `month`, `quarter` or `year`"))
+	}
+}
+
+impl core::str::FromStr for DateExpr {
+	type Err = InvalidDateExpression;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		let mut tokens = data.split(|c: char| c.is_whitespace() || c == ',').filter(|token| !token.is_empty()).peekable();
+
+		let base = match tokens.peek().copied() {
+			None => return Err(InvalidDateExpression::new("a date or `today`")),
+			Some(token) if token.eq_ignore_ascii_case("today") => ExprBase::Today,
+			Some(token) if token.starts_with(|c: char| c.is_ascii_digit()) => {
+				ExprBase::Date(token.parse().map_err(|_| InvalidDateExpression::new("a valid date as YYYY-MM-DD"))?)
+			},
+			Some(_) => return parse_ops(Self::new(ExprBase::Today), tokens),
+		};
+		tokens.next();
+		parse_ops(Self::new(base), tokens)
+	}
+}
+
+/// Parse all remaining operations of an expression.
+fn parse_ops<'a>(mut expr: DateExpr, tokens: impl Iterator<Item = &'a str>) -> Result<DateExpr, InvalidDateExpression> {
+	let mut tokens = tokens.peekable();
+	while tokens.peek().is_some() {
+		expr.ops.push(parse_op(&mut tokens)?);
+	}
+	Ok(expr)
+}
+
+/// Parse a single operation from the tokens of an expression.
+fn parse_op<'a>(tokens: &mut impl Iterator<Item = &'a str>) -> Result<ExprOp, InvalidDateExpression> {
+	let token = tokens.next().unwrap_or_default();
+	let keyword = token.to_ascii_lowercase();
+	match keyword.as_str() {
+		"start" | "end" => {
+			if !tokens.next().is_some_and(|token| token.eq_ignore_ascii_case("of")) {
+				return Err(InvalidDateExpression::new("`of` after `start` or `end`"));
+			}
+			let unit = tokens.next().unwrap_or_default().parse()?;
+			if keyword == "start" {
+				Ok(ExprOp::StartOf(unit))
+			} else {
+				Ok(ExprOp::EndOf(unit))
+			}
+		},
+		"next" | "previous" | "prev" | "last" => {
+			let weekday = tokens
+				.next()
+				.unwrap_or_default()
+				.parse()
+				.map_err(|_| InvalidDateExpression::new("a weekday name"))?;
+			if keyword == "next" {
+				Ok(ExprOp::Next(weekday))
+			} else {
+				Ok(ExprOp::Previous(weekday))
+			}
+		},
+		_ => {
+			let (sign, amount) = match token.split_at_checked(1) {
+				Some(("+", "")) => (1, tokens.next().unwrap_or_default()),
+				Some(("-", "")) => (-1, tokens.next().unwrap_or_default()),
+				Some(("+", amount)) => (1, amount),
+				Some(("-", amount)) => (-1, amount),
+				_ => return Err(InvalidDateExpression::new("`+`, `-`, `start of`, `end of`, `next` or `previous`")),
+			};
+			parse_amount(amount, sign)
+		},
+	}
+}
+
+/// Parse the amount of an addition or subtraction, like `3D` or `2BD`.
+fn parse_amount(amount: &str, sign: i32) -> Result<ExprOp, InvalidDateExpression> {
+	let invalid = || InvalidDateExpression::new("a period like 3D, 2W, 1M, 1Y or 5BD");
+	if !amount.starts_with(|c: char| c.is_ascii_digit()) {
+		return Err(invalid());
+	}
+	if let Some(days) = amount.strip_suffix("BD").or_else(|| amount.strip_suffix("bd")) {
+		let days: i32 = days.parse().map_err(|_| invalid())?;
+		return Ok(ExprOp::BusinessDays(days.checked_mul(sign).ok_or_else(invalid)?));
+	}
+	let period: Period = amount.parse().map_err(|_| invalid())?;
+	Ok(ExprOp::Add(period.checked_times(sign).ok_or_else(invalid)?))
+}
+
+impl core::fmt::Display for CalendarUnit {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+impl core::fmt::Display for ExprBase {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self {
+			Self::Today => f.write_str("today"),
+			Self::Date(date) => write!(f, "{}", date),
+		}
+	}
+}
+
+impl core::fmt::Display for ExprOp {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let sign = |count: i32| if count < 0 { '-' } else { '+' };
+		match self {
+			Self::Add(period) => write!(f, "{} {}{}", sign(period.count), period.count.unsigned_abs(), period.unit.symbol()),
+			Self::BusinessDays(days) => write!(f, "{} {}BD", sign(*days), days.unsigned_abs()),
+			Self::StartOf(unit) => write!(f, "start of {}", unit),
+			Self::EndOf(unit) => write!(f, "end of {}", unit),
+			Self::Next(weekday) => write!(f, "next {}", weekday),
+			Self::Previous(weekday) => write!(f, "previous {}", weekday),
+		}
+	}
+}
+
+impl core::fmt::Display for DateExpr {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "{}", self.base)?;
+		for op in &self.ops {
+			if op.is_arithmetic() {
+				write!(f, " {}", op)?;
+			} else {
+				write!(f, ", {}", op)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DateExpr {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for DateExpr {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = std::borrow::Cow::<'_, str>::deserialize(deserializer)?;
+		raw.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, Weekday::*};
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	fn eval(expr: &str, context: &ExprContext) -> Result<Date, DateExprError> {
+		let_assert!(Ok(expr) = expr.parse::<DateExpr>());
+		expr.eval(context)
+	}
+
+	#[test]
+	fn parse() {
+		let_assert!(Ok(expr) = "2020-01-31 + 1M - 3D, next Fri".parse::<DateExpr>());
+		assert!(expr == DateExpr::from(date(2020, January, 31))
+			.with_op(ExprOp::Add(Period::months(1)))
+			.with_op(ExprOp::Add(Period::days(-3)))
+			.with_op(ExprOp::Next(Friday)));
+
+		let_assert!(Ok(expr) = "TODAY,start of Quarter -2bd, prev monday +1w".parse::<DateExpr>());
+		assert!(expr.base == ExprBase::Today);
+		assert!(expr.ops == [
+			ExprOp::StartOf(CalendarUnit::Quarter),
+			ExprOp::BusinessDays(-2),
+			ExprOp::Previous(Monday),
+			ExprOp::Add(Period::weeks(1)),
+		]);
+
+		let_assert!(Ok(expr) = "next friday".parse::<DateExpr>());
+		assert!(expr == DateExpr::new(ExprBase::Today).with_op(ExprOp::Next(Friday)));
+		let_assert!(Ok(expr) = "- 3D".parse::<DateExpr>());
+		assert!(expr == DateExpr::new(ExprBase::Today).with_op(ExprOp::Add(Period::days(-3))));
+	}
+
+	#[test]
+	fn parse_errors() {
+		assert!(let Err(_) = "".parse::<DateExpr>());
+		assert!(let Err(_) = "2020-13-01".parse::<DateExpr>());
+		assert!(let Err(_) = "2020-01-31 +".parse::<DateExpr>());
+		assert!(let Err(_) = "2020-01-31 + 1Q".parse::<DateExpr>());
+		assert!(let Err(_) = "2020-01-31 + -1M".parse::<DateExpr>());
+		assert!(let Err(_) = "2020-01-31 1M".parse::<DateExpr>());
+		assert!(let Err(_) = "today, start of decade".parse::<DateExpr>());
+		assert!(let Err(_) = "today, start month".parse::<DateExpr>());
+		assert!(let Err(_) = "today, next funday".parse::<DateExpr>());
+		assert!(let Err(_) = "yesterday".parse::<DateExpr>());
+	}
+
+	#[test]
+	fn display() {
+		let expr = DateExpr::new(ExprBase::Today)
+			.with_op(ExprOp::Add(Period::months(-1)))
+			.with_op(ExprOp::EndOf(CalendarUnit::Month))
+			.with_op(ExprOp::BusinessDays(3))
+			.with_op(ExprOp::Previous(Sunday));
+		assert!(expr.to_string() == "today - 1M, end of month + 3BD, previous Sunday");
+		assert!(expr.to_string().parse() == Ok(expr));
+		let_assert!(Ok(expr) = "2020-01-31 +1m,-3d,next fri".parse::<DateExpr>());
+		assert!(expr.to_string() == "2020-01-31 + 1M - 3D, next Friday");
+	}
+
+	#[test]
+	fn clamp_policy() {
+		let today = date(2024, May, 15);
+		let expr = "2020-01-31 + 1M - 3D, next Fri";
+		assert!(let Err(DateExprError::InvalidDayOfMonth(_)) = eval(expr, &ExprContext::new(today, ClampPolicy::Reject)));
+		assert!(eval(expr, &ExprContext::new(today, ClampPolicy::PrevValid)) == Ok(date(2020, February, 28)));
+		assert!(eval("2020-01-31 + 1M", &ExprContext::new(today, ClampPolicy::NextValid)) == Ok(date(2020, March, 1)));
+		assert!(eval("2020-01-31 + 1M + 1M", &ExprContext::new(today, ClampPolicy::PrevValid)) == Ok(date(2020, March, 29)));
+		assert!(eval("2020-02-29 + 1Y", &ExprContext::new(today, ClampPolicy::PrevValid)) == Ok(date(2021, February, 28)));
+		assert!(eval("2020-01-31 + 2M", &ExprContext::new(today, ClampPolicy::Reject)) == Ok(date(2020, March, 31)));
+	}
+
+	#[test]
+	fn calendar_units() {
+		let context = ExprContext::new(date(2024, May, 15), ClampPolicy::Reject);
+		assert!(eval("today", &context) == Ok(date(2024, May, 15)));
+		assert!(eval("start of week", &context) == Ok(date(2024, May, 13)));
+		assert!(eval("end of week", &context) == Ok(date(2024, May, 19)));
+		assert!(eval("start of month", &context) == Ok(date(2024, May, 1)));
+		assert!(eval("end of month", &context) == Ok(date(2024, May, 31)));
+		assert!(eval("start of quarter", &context) == Ok(date(2024, April, 1)));
+		assert!(eval("end of quarter", &context) == Ok(date(2024, June, 30)));
+		assert!(eval("start of year", &context) == Ok(date(2024, January, 1)));
+		assert!(eval("end of year", &context) == Ok(date(2024, December, 31)));
+		let context = context.with_week_rules(WeekRules::US);
+		assert!(eval("start of week", &context) == Ok(date(2024, May, 12)));
+	}
+
+	#[test]
+	fn weekdays() {
+		let context = ExprContext::new(date(2024, May, 15), ClampPolicy::Reject);
+		assert!(eval("next Wednesday", &context) == Ok(date(2024, May, 22)));
+		assert!(eval("next Thu", &context) == Ok(date(2024, May, 16)));
+		assert!(eval("previous Wed", &context) == Ok(date(2024, May, 8)));
+		assert!(eval("last Tue", &context) == Ok(date(2024, May, 14)));
+	}
+
+	#[test]
+	fn business_days() {
+		let context = ExprContext::new(date(2024, May, 17), ClampPolicy::Reject);
+		assert!(eval("today + 1BD", &context) == Ok(date(2024, May, 20)));
+		assert!(eval("2024-05-20 - 2BD", &context) == Ok(date(2024, May, 16)));
+		let context = context.with_calendar(BusinessCalendar::weekdays().with_holidays([date(2024, May, 20)]));
+		assert!(eval("today + 1BD", &context) == Ok(date(2024, May, 21)));
+		assert!(eval("end of month - 1BD", &context) == Ok(date(2024, May, 30)));
+	}
+
+	#[test]
+	fn out_of_range() {
+		let context = ExprContext::new(date(2024, May, 17), ClampPolicy::NextValid);
+		assert!(eval("2020-01-01 + 40000Y", &context) == Err(DateExprError::OutOfRange));
+		assert!(eval("2020-01-01 - 40000Y", &context) == Err(DateExprError::OutOfRange));
+		assert!(eval("2020-01-01 + 999999999Y", &context) == Err(DateExprError::OutOfRange));
+		assert!(eval("2020-01-01 - 2147483647D", &context) == Err(DateExprError::OutOfRange));
+		assert!(eval("2020-01-01 + 2000000000BD", &context) == Err(DateExprError::OutOfRange));
+		assert!(eval("2020-01-01 - 2000000000BD", &context) == Err(DateExprError::OutOfRange));
+		assert!(eval("32767-12-31 + 1D", &context) == Err(DateExprError::OutOfRange));
+		assert!(eval("32767-12-31, next Monday", &context) == Err(DateExprError::OutOfRange));
+		assert!(eval("32767-12-31 + 1BD", &context) == Err(DateExprError::OutOfRange));
+		assert!(eval("32767-12-31, end of year", &context) == Ok(date(32767, December, 31)));
+		assert!(eval("32767-12-31, end of week", &context) == Ok(date(32767, December, 31)));
+		assert!(eval("32767-12-31, end of week", &context.clone().with_week_rules(WeekRules::US)) == Err(DateExprError::OutOfRange));
+		assert!(eval("32767-01-31 + 1M", &context) == Ok(date(32767, March, 1)));
+		assert!(let Err(_) = "2020-01-01 - 2147483648D".parse::<DateExpr>());
+	}
+
+	#[test]
+	#[cfg(feature = "serde")]
+	fn serde() {
+		let_assert!(Ok(expr) = serde_yaml::from_str::<DateExpr>("today +1m, start of month"));
+		assert!(expr.to_string() == "today + 1M, start of month");
+		let_assert!(Ok(yaml) = serde_yaml::to_string(&expr));
+		assert!(yaml == "today + 1M, start of month\n");
+		assert!(let Err(_) = serde_yaml::from_str::<DateExpr>("tomorrow"));
+	}
+}
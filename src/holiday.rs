@@ -0,0 +1,625 @@
+use std::borrow::Cow;
+
+use crate::{BusinessCalendar, Date, DateRange, Month, Weekday, WeekdaySet, Year};
+
+/// The rule that determines on which date a holiday falls in a given year.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum HolidayDate {
+	/// A fixed day of a month, like 25 December.
+	///
+	/// If the day does not exist in a year (29 February), the holiday does not occur in that year.
+	Fixed { month: Month, day: u8 },
+
+	/// The n-th occurence of a weekday in a month, like the fourth Thursday of November.
+	///
+	/// A negative `n` counts from the end of the month, so -1 is the last occurence.
+	/// See [`YearMonth::nth_weekday()`][crate::YearMonth::nth_weekday].
+	NthWeekday { month: Month, weekday: Weekday, n: i8 },
+
+	/// The first occurence of a weekday on or after a fixed day of a month,
+	/// like the Saturday on or after 20 June.
+	WeekdayOnOrAfter { month: Month, day: u8, weekday: Weekday },
+
+	/// The last occurence of a weekday on or before a fixed day of a month,
+	/// like the Monday on or before 24 May.
+	WeekdayOnOrBefore { month: Month, day: u8, weekday: Weekday },
+
+	/// A number of days before or after Western Easter Sunday.
+	///
+	/// See [`Year::easter_western()`].
+	Easter { offset: i16 },
+
+	/// A number of days before or after Orthodox Easter Sunday.
+	///
+	/// See [`Year::easter_orthodox()`].
+	OrthodoxEaster { offset: i16 },
+
+	/// The day of the March equinox in Japan Standard Time (UTC+9).
+	///
+	/// The date is approximated with the formula published by the National Astronomical Observatory of Japan.
+	/// It is accurate for the years 1851 to 2150.
+	MarchEquinox,
+
+	/// The day of the September equinox in Japan Standard Time (UTC+9).
+	///
+	/// The date is approximated with the formula published by the National Astronomical Observatory of Japan.
+	/// It is accurate for the years 1851 to 2150.
+	SeptemberEquinox,
+}
+
+impl HolidayDate {
+	/// Get the date of the holiday in a year.
+	///
+	/// Returns `None` if the holiday does not occur in that year.
+	pub const fn in_year(self, year: Year) -> Option<Date> {
+		match self {
+			Self::Fixed { month, day } => match year.with_month(month).with_day(day) {
+				Ok(date) => Some(date),
+				Err(_) => None,
+			},
+			Self::NthWeekday { month, weekday, n } => year.with_month(month).nth_weekday(n, weekday),
+			Self::WeekdayOnOrAfter { month, day, weekday } => match year.with_month(month).with_day(day) {
+				Ok(date) => Some(date.weekday_on_or_after(weekday)),
+				Err(_) => None,
+			},
+			Self::WeekdayOnOrBefore { month, day, weekday } => match year.with_month(month).with_day(day) {
+				Ok(date) => Some(date.weekday_on_or_before(weekday)),
+				Err(_) => None,
+			},
+			Self::Easter { offset } => Some(year.easter_western().add_days(offset as i32)),
+			Self::OrthodoxEaster { offset } => Some(year.easter_orthodox().add_days(offset as i32)),
+			Self::MarchEquinox => {
+				let day = equinox_day(year, 19_827_700, 20_835_700, 20_843_100, 21_851_000);
+				Some(unsafe { year.with_month(Month::March).with_day_unchecked(clamp(day, 19, 22)) })
+			}
+			Self::SeptemberEquinox => {
+				let day = equinox_day(year, 22_258_800, 23_258_800, 23_248_800, 24_248_800);
+				Some(unsafe { year.with_month(Month::September).with_day_unchecked(clamp(day, 21, 25)) })
+			}
+		}
+	}
+}
+
+/// Approximate the day of an equinox in Japan Standard Time.
+///
+/// The base days are given in millionths of a day for the periods 1851-1899, 1900-1979, 1980-2099 and 2100-2150.
+/// As in the formula published by the National Astronomical Observatory of Japan, the number of leap days is truncated toward zero.
+const fn equinox_day(year: Year, base_1851: i64, base_1900: i64, base_1980: i64, base_2100: i64) -> i64 {
+	let year = year.to_number() as i64;
+	let (base, leap_year) = if year < 1900 {
+		(base_1851, 1983)
+	} else if year < 1980 {
+		(base_1900, 1983)
+	} else if year < 2100 {
+		(base_1980, 1980)
+	} else {
+		(base_2100, 1980)
+	};
+	(base + 242_194 * (year - 1980) - (year - leap_year) / 4 * 1_000_000).div_euclid(1_000_000)
+}
+
+/// Clamp a day number to a range, to stay within the month when the equinox approximation breaks down.
+const fn clamp(day: i64, min: u8, max: u8) -> u8 {
+	if day < min as i64 {
+		min
+	} else if day > max as i64 {
+		max
+	} else {
+		day as u8
+	}
+}
+
+/// The rule that determines on which day a holiday is observed, if it falls on a weekend.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Observance {
+	/// Shift the holiday by a fixed number of days depending on the weekday it falls on.
+	///
+	/// The offsets are indexed by weekday, starting with Monday.
+	/// For example, `[0, 0, 0, 0, 0, -1, 1]` moves holidays on a Saturday to the Friday before,
+	/// and holidays on a Sunday to the Monday after.
+	Shift { offsets: [i8; 7] },
+
+	/// Observe the holiday on the first following day that is not a weekend day and not another holiday.
+	///
+	/// This only applies if the holiday falls on one of the given weekend days.
+	/// This is also known as a substitute holiday.
+	Substitute { weekend: WeekdaySet },
+}
+
+impl Observance {
+	/// The holiday is always observed on the actual date.
+	pub const ACTUAL: Self = Self::Shift { offsets: [0; 7] };
+
+	/// Holidays on Saturday are observed on Friday, and holidays on Sunday are observed on Monday.
+	pub const NEAREST_WEEKDAY: Self = Self::Shift {
+		offsets: [0, 0, 0, 0, 0, -1, 1],
+	};
+
+	/// Holidays on Saturday or Sunday are observed on the following Monday.
+	pub const NEXT_MONDAY: Self = Self::Shift {
+		offsets: [0, 0, 0, 0, 0, 2, 1],
+	};
+
+	/// Holidays on Sunday are observed on the following Monday.
+	pub const SUNDAY_TO_MONDAY: Self = Self::Shift {
+		offsets: [0, 0, 0, 0, 0, 0, 1],
+	};
+
+	/// Holidays on Saturday or Sunday are observed on the first following weekday that is not another holiday.
+	pub const SUBSTITUTE: Self = Self::Substitute {
+		weekend: WeekdaySet::WEEKEND,
+	};
+}
+
+/// A rule describing a named holiday.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct HolidayRule {
+	/// The name of the holiday.
+	pub name: Cow<'static, str>,
+
+	/// The rule that determines the date of the holiday.
+	pub date: HolidayDate,
+
+	/// The rule that determines the observed date of the holiday.
+	pub observance: Observance,
+
+	/// The first year in which the holiday occurs, if any.
+	pub first_year: Option<Year>,
+
+	/// The last year in which the holiday occurs, if any.
+	pub last_year: Option<Year>,
+}
+
+impl HolidayRule {
+	/// Create a new holiday rule that is always observed on the actual date and is valid in all years.
+	pub fn new(name: impl Into<Cow<'static, str>>, date: HolidayDate) -> Self {
+		Self {
+			name: name.into(),
+			date,
+			observance: Observance::ACTUAL,
+			first_year: None,
+			last_year: None,
+		}
+	}
+
+	/// Create a holiday rule for a fixed day of a month.
+	pub fn fixed(name: impl Into<Cow<'static, str>>, month: Month, day: u8) -> Self {
+		Self::new(name, HolidayDate::Fixed { month, day })
+	}
+
+	/// Create a holiday rule for the n-th weekday of a month.
+	///
+	/// A negative `n` counts from the end of the month, so -1 is the last occurence.
+	pub fn nth_weekday(name: impl Into<Cow<'static, str>>, n: i8, weekday: Weekday, month: Month) -> Self {
+		Self::new(name, HolidayDate::NthWeekday { month, weekday, n })
+	}
+
+	/// Create a holiday rule for a number of days before or after Western Easter Sunday.
+	pub fn easter(name: impl Into<Cow<'static, str>>, offset: i16) -> Self {
+		Self::new(name, HolidayDate::Easter { offset })
+	}
+
+	/// Set the observance rule, returning the modified rule.
+	pub fn observed(mut self, observance: Observance) -> Self {
+		self.observance = observance;
+		self
+	}
+
+	/// Set the first year in which the holiday occurs, returning the modified rule.
+	pub fn since(mut self, year: impl Into<Year>) -> Self {
+		self.first_year = Some(year.into());
+		self
+	}
+
+	/// Set the last year in which the holiday occurs, returning the modified rule.
+	pub fn until(mut self, year: impl Into<Year>) -> Self {
+		self.last_year = Some(year.into());
+		self
+	}
+
+	/// Check if the holiday occurs at all in a year, based on the first and last year of the rule.
+	pub fn is_valid_in(&self, year: Year) -> bool {
+		self.first_year.is_none_or(|first| year >= first) && self.last_year.is_none_or(|last| year <= last)
+	}
+
+	/// Get the actual date of the holiday in a year.
+	///
+	/// Returns `None` if the holiday does not occur in that year.
+	pub fn date_in(&self, year: Year) -> Option<Date> {
+		if self.is_valid_in(year) {
+			self.date.in_year(year)
+		} else {
+			None
+		}
+	}
+}
+
+/// A holiday on a concrete date, as produced by a [`HolidaySet`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Holiday<'a> {
+	/// The name of the holiday.
+	pub name: &'a str,
+
+	/// The actual date of the holiday.
+	pub date: Date,
+
+	/// The date on which the holiday is observed.
+	///
+	/// This is the same as `date` unless the holiday was moved according to its [`Observance`].
+	pub observed: Date,
+}
+
+/// A set of holiday rules.
+///
+/// The set can be evaluated to concrete holidays for any [`Year`] or [`DateRange`],
+/// and can be used to create a [`BusinessCalendar`].
+///
+/// # Text format
+/// A holiday set can be parsed from and formatted to a simple line-based text format with [`FromStr`][core::str::FromStr] and [`Display`][core::fmt::Display].
+/// Each line contains one holiday, as a name followed by a colon, a date and optional options:
+///
+/// ```text
+/// # Lines starting with a hash are comments.
+/// New Year's Day: 01-01 observed=nearest-weekday
+/// Memorial Day: last Mon of May since=1971
+/// Thanksgiving Day: 4th Thu of Nov
+/// Midsummer Day: Sat on or after 06-20
+/// Victoria Day: Mon on or before 05-24
+/// Whit Monday: easter+50
+/// Orthodox Good Friday: orthodox-easter-2
+/// Vernal Equinox Day: march-equinox observed=substitute(Sun)
+/// "Name: with a colon": 12-31 until=2010
+/// ```
+///
+/// The date is one of:
+/// * a month and day: `12-25`,
+/// * the n-th weekday of a month: `1st Mon of Sep`, `last Mon of May` or `2nd-last Fri of Oct`,
+/// * a weekday on or after or on or before a month and day: `Sat on or after 06-20`,
+/// * a number of days from Western or Orthodox Easter: `easter`, `easter-2` or `orthodox-easter+1`,
+/// * an equinox: `march-equinox` or `september-equinox`.
+///
+/// The available options are:
+/// * `observed=...` with `actual`, `nearest-weekday`, `next-monday`, `sunday-to-monday`, `substitute`,
+///   `substitute(<weekdays>)` or `shift(<seven offsets for Monday to Sunday>)`, see [`Observance`],
+/// * `since=<year>` and `until=<year>` to limit the years in which the holiday occurs.
+///
+/// Weekday and month names are case insensitive and may be abbreviated.
+/// Names that contain a colon or surrounding whitespace must be quoted.
+/// Parse errors report the line and column of the error.
+///
+/// With the `serde` feature, a holiday set is serialized as a list of single-line definitions.
+/// It can be deserialized from such a list, or from a single string with one definition per line.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct HolidaySet {
+	rules: Vec<HolidayRule>,
+}
+
+impl HolidaySet {
+	/// Create a new empty holiday set.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Add a rule to the set, returning the modified set.
+	pub fn with(mut self, rule: HolidayRule) -> Self {
+		self.add(rule);
+		self
+	}
+
+	/// Add a rule to the set.
+	pub fn add(&mut self, rule: HolidayRule) {
+		self.rules.push(rule);
+	}
+
+	/// Get the rules in the set.
+	pub fn rules(&self) -> &[HolidayRule] {
+		&self.rules
+	}
+
+	/// Get all holidays for which the actual date falls in the given year.
+	///
+	/// Note that the observed date of a holiday may fall in an adjacent year.
+	/// The returned holidays are sorted by their actual date.
+	pub fn holidays_in_year(&self, year: Year) -> Vec<Holiday<'_>> {
+		let mut rules: Vec<_> = self.rules.iter().filter_map(|rule| Some((rule, rule.date_in(year)?))).collect();
+		rules.sort_by_key(|(_, date)| *date);
+
+		// Apply simple shifts first, so that substitute days can avoid them.
+		let mut holidays: Vec<_> = rules
+			.iter()
+			.map(|&(rule, date)| {
+				let observed = match rule.observance {
+					Observance::Shift { offsets } => date.add_days(offsets[date.weekday() as usize - 1] as i32),
+					Observance::Substitute { .. } => date,
+				};
+				Holiday {
+					name: &rule.name,
+					date,
+					observed,
+				}
+			})
+			.collect();
+
+		// Substitute days go to the first free day, in order of the actual date of the holidays.
+		for (i, (rule, date)) in rules.iter().enumerate() {
+			if let Observance::Substitute { weekend } = rule.observance {
+				if !weekend.contains_date(*date) {
+					continue;
+				}
+				let mut observed = date.next();
+				while weekend.contains_date(observed) || holidays.iter().any(|other| other.date == observed || other.observed == observed) {
+					observed = observed.next();
+				}
+				holidays[i].observed = observed;
+			}
+		}
+
+		holidays
+	}
+
+	/// Get all holidays for which the actual or observed date falls in the given range.
+	///
+	/// The range can also be given as [`Year`] or [`YearMonth`][crate::YearMonth].
+	/// The returned holidays are sorted by their actual date.
+	pub fn holidays_in(&self, range: impl Into<DateRange>) -> Vec<Holiday<'_>> {
+		let range = range.into();
+		if range.is_empty() {
+			return Vec::new();
+		}
+		let first_year = range.start().year().prev();
+		let last_year = range.end().prev().year().next();
+
+		let mut holidays = Vec::new();
+		let mut year = first_year;
+		while year <= last_year {
+			holidays.extend(
+				self.holidays_in_year(year)
+					.into_iter()
+					.filter(|holiday| range.contains(holiday.date) || range.contains(holiday.observed)),
+			);
+			year = year.next();
+		}
+		holidays
+	}
+
+	/// Get all dates in a range that are a holiday, either because of the actual or the observed date of a holiday.
+	///
+	/// The returned dates are sorted and do not contain duplicates.
+	pub fn dates_in(&self, range: impl Into<DateRange>) -> Vec<Date> {
+		let range = range.into();
+		let mut dates: Vec<_> = self
+			.holidays_in(range)
+			.into_iter()
+			.flat_map(|holiday| [holiday.date, holiday.observed])
+			.filter(|date| range.contains(*date))
+			.collect();
+		dates.sort_unstable();
+		dates.dedup();
+		dates
+	}
+
+	/// Get the holidays that fall on a date, either on the actual or the observed date.
+	pub fn holidays_on(&self, date: Date) -> Vec<Holiday<'_>> {
+		self.holidays_in(DateRange::inclusive(date, date))
+	}
+
+	/// Check if a date is a holiday, either on the actual or the observed date.
+	pub fn is_holiday(&self, date: Date) -> bool {
+		!self.holidays_on(date).is_empty()
+	}
+
+	/// Get the name of the holiday on a date.
+	///
+	/// A holiday on its actual date takes precedence over a holiday that is observed on the date.
+	/// Returns `None` if the date is not a holiday.
+	pub fn holiday_name(&self, date: Date) -> Option<&str> {
+		let holidays = self.holidays_on(date);
+		holidays
+			.iter()
+			.find(|holiday| holiday.date == date)
+			.or_else(|| holidays.first())
+			.map(|holiday| holiday.name)
+	}
+
+	/// Create a business calendar with the given weekend and all holidays in a date range.
+	///
+	/// Both the actual and the observed dates of the holidays are used.
+	pub fn business_calendar(&self, weekend: WeekdaySet, range: impl Into<DateRange>) -> BusinessCalendar {
+		BusinessCalendar::new(weekend).with_holidays(self.dates_in(range))
+	}
+}
+
+impl FromIterator<HolidayRule> for HolidaySet {
+	fn from_iter<I: IntoIterator<Item = HolidayRule>>(iter: I) -> Self {
+		Self {
+			rules: iter.into_iter().collect(),
+		}
+	}
+}
+
+impl Extend<HolidayRule> for HolidaySet {
+	fn extend<I: IntoIterator<Item = HolidayRule>>(&mut self, iter: I) {
+		self.rules.extend(iter)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, Weekday::*};
+	use crate::util::date;
+	use assert2::assert;
+
+	#[test]
+	fn holiday_date() {
+		let year = Year::new(2024);
+		assert!(HolidayDate::Fixed { month: December, day: 25 }.in_year(year) == Some(date(2024, December, 25)));
+		assert!(HolidayDate::Fixed { month: February, day: 29 }.in_year(year) == Some(date(2024, February, 29)));
+		assert!(HolidayDate::Fixed { month: February, day: 29 }.in_year(Year::new(2023)) == None);
+		assert!(
+			HolidayDate::NthWeekday {
+				month: November,
+				weekday: Thursday,
+				n: 4
+			}
+			.in_year(year)
+				== Some(date(2024, November, 28))
+		);
+		assert!(
+			HolidayDate::NthWeekday {
+				month: May,
+				weekday: Monday,
+				n: -1
+			}
+			.in_year(year)
+				== Some(date(2024, May, 27))
+		);
+		assert!(
+			HolidayDate::WeekdayOnOrAfter {
+				month: June,
+				day: 20,
+				weekday: Saturday
+			}
+			.in_year(year)
+				== Some(date(2024, June, 22))
+		);
+		assert!(
+			HolidayDate::WeekdayOnOrBefore {
+				month: May,
+				day: 24,
+				weekday: Monday
+			}
+			.in_year(year)
+				== Some(date(2024, May, 20))
+		);
+		assert!(HolidayDate::Easter { offset: -2 }.in_year(year) == Some(date(2024, March, 29)));
+		assert!(HolidayDate::OrthodoxEaster { offset: 1 }.in_year(year) == Some(date(2024, May, 6)));
+	}
+
+	#[test]
+	fn equinox() {
+		let march = |year| HolidayDate::MarchEquinox.in_year(Year::new(year)).unwrap();
+		let september = |year| HolidayDate::SeptemberEquinox.in_year(Year::new(year)).unwrap();
+		assert!(march(1890) == date(1890, March, 21));
+		assert!(march(1900) == date(1900, March, 21));
+		assert!(september(1900) == date(1900, September, 23));
+		assert!(march(1950) == date(1950, March, 21));
+		assert!(september(1950) == date(1950, September, 23));
+		assert!(march(1960) == date(1960, March, 20));
+		assert!(september(1960) == date(1960, September, 23));
+		assert!(march(1979) == date(1979, March, 21));
+		assert!(september(1979) == date(1979, September, 24));
+		assert!(march(2023) == date(2023, March, 21));
+		assert!(september(2023) == date(2023, September, 23));
+		assert!(march(2024) == date(2024, March, 20));
+		assert!(september(2024) == date(2024, September, 22));
+		assert!(march(2025) == date(2025, March, 20));
+		assert!(september(2025) == date(2025, September, 23));
+		assert!(march(-30000).month() == March);
+		assert!(september(30000).month() == September);
+	}
+
+	#[test]
+	fn validity() {
+		let rule = HolidayRule::fixed("Liberation Day", May, 5).since(1990).until(2000);
+		assert!(rule.date_in(Year::new(1989)) == None);
+		assert!(rule.date_in(Year::new(1990)) == Some(date(1990, May, 5)));
+		assert!(rule.date_in(Year::new(2000)) == Some(date(2000, May, 5)));
+		assert!(rule.date_in(Year::new(2001)) == None);
+	}
+
+	#[test]
+	fn observed_shift() {
+		let set = HolidaySet::new()
+			.with(HolidayRule::fixed("New Year's Day", January, 1).observed(Observance::NEAREST_WEEKDAY))
+			.with(HolidayRule::fixed("Independence Day", July, 4).observed(Observance::NEAREST_WEEKDAY));
+
+		// 1 January 2022 is a Saturday, so it is observed on Friday 31 December 2021.
+		let holidays = set.holidays_in_year(Year::new(2022));
+		assert!(holidays[0].name == "New Year's Day");
+		assert!(holidays[0].date == date(2022, January, 1));
+		assert!(holidays[0].observed == date(2021, December, 31));
+
+		// 4 July 2021 is a Sunday, so it is observed on Monday 5 July.
+		let holidays = set.holidays_in_year(Year::new(2021));
+		assert!(holidays[1].observed == date(2021, July, 5));
+
+		// The observed date in the previous year shows up when asking for a range.
+		let holidays = set.holidays_in(Year::new(2021));
+		assert!(holidays.len() == 3);
+		assert!(holidays[2].date == date(2022, January, 1));
+		assert!(
+			set.dates_in(Year::new(2021).days())
+				== [
+					date(2021, January, 1),
+					date(2021, July, 4),
+					date(2021, July, 5),
+					date(2021, December, 31)
+				]
+		);
+	}
+
+	#[test]
+	fn observed_substitute() {
+		let set = HolidaySet::new()
+			.with(HolidayRule::fixed("Christmas Day", December, 25).observed(Observance::SUBSTITUTE))
+			.with(HolidayRule::fixed("Boxing Day", December, 26).observed(Observance::SUBSTITUTE));
+
+		// Christmas on Saturday, Boxing Day on Sunday.
+		let holidays = set.holidays_in_year(Year::new(2021));
+		assert!(holidays[0].observed == date(2021, December, 27));
+		assert!(holidays[1].observed == date(2021, December, 28));
+
+		// Christmas on Sunday, Boxing Day on Monday.
+		let holidays = set.holidays_in_year(Year::new(2022));
+		assert!(holidays[0].observed == date(2022, December, 27));
+		assert!(holidays[1].observed == date(2022, December, 26));
+
+		// Christmas on Friday, Boxing Day on Saturday.
+		let holidays = set.holidays_in_year(Year::new(2020));
+		assert!(holidays[0].observed == date(2020, December, 25));
+		assert!(holidays[1].observed == date(2020, December, 28));
+	}
+
+	#[test]
+	fn holidays_on() {
+		let set = HolidaySet::new()
+			.with(HolidayRule::easter("Good Friday", -2))
+			.with(HolidayRule::fixed("Christmas Day", December, 25));
+		let holidays = set.holidays_on(date(2024, March, 29));
+		assert!(holidays.len() == 1);
+		assert!(holidays[0].name == "Good Friday");
+		assert!(set.holidays_on(date(2024, March, 28)).is_empty());
+		assert!(set.is_holiday(date(2024, December, 25)));
+		assert!(!set.is_holiday(date(2024, December, 24)));
+	}
+
+	#[test]
+	fn holiday_name() {
+		let set = HolidaySet::new()
+			.with(HolidayRule::fixed("Christmas Day", December, 25).observed(Observance::SUBSTITUTE))
+			.with(HolidayRule::fixed("Boxing Day", December, 26).observed(Observance::SUBSTITUTE));
+		assert!(set.holiday_name(date(2022, December, 25)) == Some("Christmas Day"));
+		assert!(set.holiday_name(date(2022, December, 26)) == Some("Boxing Day"));
+		assert!(set.holiday_name(date(2022, December, 27)) == Some("Christmas Day"));
+		assert!(set.holiday_name(date(2022, December, 28)) == None);
+	}
+
+	#[test]
+	fn business_calendar() {
+		let set = HolidaySet::new()
+			.with(HolidayRule::fixed("New Year's Day", January, 1).observed(Observance::NEAREST_WEEKDAY))
+			.with(HolidayRule::nth_weekday("Memorial Day", -1, Monday, May))
+			.with(
+				HolidayRule::fixed("Juneteenth", June, 19)
+					.observed(Observance::NEAREST_WEEKDAY)
+					.since(2021),
+			);
+		let calendar = set.business_calendar(WeekdaySet::WEEKEND, DateRange::new(date(2020, January, 1), date(2030, January, 1)));
+		assert!(!calendar.is_business_day(date(2021, December, 31)));
+		assert!(!calendar.is_business_day(date(2024, May, 27)));
+		assert!(calendar.is_business_day(date(2020, June, 19)));
+		assert!(!calendar.is_business_day(date(2021, June, 18)));
+		assert!(calendar.next_business_day(date(2024, May, 24)) == date(2024, May, 28));
+	}
+}
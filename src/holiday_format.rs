@@ -0,0 +1,654 @@
+use crate::{HolidayDate, HolidayRule, HolidaySet, InvalidHolidaySyntax, Month, Observance, WeekdaySet, Year};
+
+const EXPECTED_NAME: &str = "a holiday name";
+const EXPECTED_COLON: &str = "\":\" after the holiday name";
+const EXPECTED_QUOTE: &str = "a closing quote";
+const EXPECTED_DATE: &str = "a date like \"12-25\", \"last Mon of May\", \"Sat on or after 06-20\" or \"easter+1\"";
+const EXPECTED_MONTH_DAY: &str = "a month and day like \"12-25\"";
+const EXPECTED_WEEKDAY: &str = "a weekday like \"Mon\" or \"Monday\"";
+const EXPECTED_MONTH: &str = "a month like \"Jan\" or \"January\"";
+const EXPECTED_OF: &str = "\"of\"";
+const EXPECTED_ON_OR: &str = "\"on or after\" or \"on or before\"";
+const EXPECTED_OFFSET: &str = "an offset in days like \"+1\" or \"-2\"";
+const EXPECTED_OPTION: &str = "an option like \"observed=substitute\", \"since=2000\" or \"until=2010\"";
+const EXPECTED_OBSERVANCE: &str =
+	"an observance like \"actual\", \"nearest-weekday\", \"next-monday\", \"sunday-to-monday\", \"substitute\", \"substitute(Sun)\" or \"shift(0,0,0,0,0,-1,1)\"";
+const EXPECTED_YEAR: &str = "a year number";
+
+/// Parse a complete holiday definition file.
+pub(crate) fn parse_holiday_set(data: &str) -> Result<HolidaySet, InvalidHolidaySyntax> {
+	let mut set = HolidaySet::new();
+	for (index, line) in data.lines().enumerate() {
+		let trimmed = line.trim_start();
+		if trimmed.is_empty() || trimmed.starts_with('#') {
+			continue;
+		}
+		set.add(parse_holiday_rule(line, index + 1)?);
+	}
+	Ok(set)
+}
+
+/// Parse a single line with a holiday definition.
+pub(crate) fn parse_holiday_rule(line: &str, line_number: usize) -> Result<HolidayRule, InvalidHolidaySyntax> {
+	let parser = LineParser { line, line_number };
+	let (name, rest) = parser.parse_name()?;
+	let tokens: Vec<_> = Tokens::new(line, rest).collect();
+	let end = rest + line[rest..].trim_end().len();
+	let (date, used) = parser.parse_date(&tokens, end)?;
+	let mut rule = HolidayRule::new(name, date);
+
+	for &(offset, token) in &tokens[used..] {
+		let (key, value) = token.split_once('=').ok_or_else(|| parser.error(offset, EXPECTED_OPTION))?;
+		let value_offset = offset + key.len() + 1;
+		match key {
+			"observed" => rule.observance = parse_observance(value).ok_or_else(|| parser.error(value_offset, EXPECTED_OBSERVANCE))?,
+			"since" => rule.first_year = Some(parse_year(value).ok_or_else(|| parser.error(value_offset, EXPECTED_YEAR))?),
+			"until" => rule.last_year = Some(parse_year(value).ok_or_else(|| parser.error(value_offset, EXPECTED_YEAR))?),
+			_ => return Err(parser.error(offset, EXPECTED_OPTION)),
+		}
+	}
+
+	Ok(rule)
+}
+
+/// Parser state for a single line.
+struct LineParser<'a> {
+	line: &'a str,
+	line_number: usize,
+}
+
+impl<'a> LineParser<'a> {
+	/// Create an error at a byte offset in the line.
+	fn error(&self, offset: usize, expected: &'static str) -> InvalidHolidaySyntax {
+		InvalidHolidaySyntax {
+			line: self.line_number,
+			column: self.line[..offset].chars().count() + 1,
+			expected,
+		}
+	}
+
+	/// Parse the name of the holiday, including the colon that follows it.
+	///
+	/// Returns the name and the byte offset of the remainder of the line.
+	fn parse_name(&self) -> Result<(String, usize), InvalidHolidaySyntax> {
+		let start = self.line.len() - self.line.trim_start().len();
+		let (name, end) = if self.line[start..].starts_with('"') {
+			self.parse_quoted_name(start)?
+		} else {
+			let colon = self.line.find(':').ok_or_else(|| self.error(self.line.len(), EXPECTED_COLON))?;
+			let name = self.line[start..colon].trim_end();
+			if name.is_empty() {
+				return Err(self.error(start, EXPECTED_NAME));
+			}
+			(name.to_string(), colon)
+		};
+
+		let colon = end + (self.line[end..].len() - self.line[end..].trim_start().len());
+		if !self.line[colon..].starts_with(':') {
+			return Err(self.error(colon, EXPECTED_COLON));
+		}
+		Ok((name, colon + 1))
+	}
+
+	/// Parse a quoted name starting at the given byte offset.
+	///
+	/// Returns the unescaped name and the byte offset after the closing quote.
+	fn parse_quoted_name(&self, start: usize) -> Result<(String, usize), InvalidHolidaySyntax> {
+		let mut name = String::new();
+		let mut chars = self.line[start + 1..].char_indices();
+		while let Some((i, c)) = chars.next() {
+			match c {
+				'"' => return Ok((name, start + 1 + i + 1)),
+				'\\' => match chars.next() {
+					Some((_, 'n')) => name.push('\n'),
+					Some((_, c)) => name.push(c),
+					None => break,
+				},
+				c => name.push(c),
+			}
+		}
+		Err(self.error(self.line.len(), EXPECTED_QUOTE))
+	}
+
+	/// Parse the date of a holiday from the tokens after the name.
+	///
+	/// Returns the date and the number of tokens used.
+	fn parse_date(&self, tokens: &[(usize, &str)], end: usize) -> Result<(HolidayDate, usize), InvalidHolidaySyntax> {
+		// Get a token or report an error at the end of the line.
+		let token = |index: usize, expected| tokens.get(index).copied().ok_or_else(|| self.error(end, expected));
+
+		let (offset, first) = token(0, EXPECTED_DATE)?;
+		if let Some(n) = parse_nth(first) {
+			let (weekday_offset, weekday) = token(1, EXPECTED_WEEKDAY)?;
+			let weekday = weekday.parse().map_err(|_| self.error(weekday_offset, EXPECTED_WEEKDAY))?;
+			let (of_offset, of) = token(2, EXPECTED_OF)?;
+			if !of.eq_ignore_ascii_case("of") {
+				return Err(self.error(of_offset, EXPECTED_OF));
+			}
+			let (month_offset, month) = token(3, EXPECTED_MONTH)?;
+			let month = month.parse().map_err(|_| self.error(month_offset, EXPECTED_MONTH))?;
+			Ok((HolidayDate::NthWeekday { month, weekday, n }, 4))
+		} else if let Ok(weekday) = first.parse() {
+			let (on_offset, on) = token(1, EXPECTED_ON_OR)?;
+			let (_, or) = token(2, EXPECTED_ON_OR)?;
+			if !on.eq_ignore_ascii_case("on") || !or.eq_ignore_ascii_case("or") {
+				return Err(self.error(on_offset, EXPECTED_ON_OR));
+			}
+			let (direction_offset, direction) = token(3, EXPECTED_ON_OR)?;
+			let (date_offset, month_day) = token(4, EXPECTED_MONTH_DAY)?;
+			let (month, day) = parse_month_day(month_day).ok_or_else(|| self.error(date_offset, EXPECTED_MONTH_DAY))?;
+			if direction.eq_ignore_ascii_case("after") {
+				Ok((HolidayDate::WeekdayOnOrAfter { month, day, weekday }, 5))
+			} else if direction.eq_ignore_ascii_case("before") {
+				Ok((HolidayDate::WeekdayOnOrBefore { month, day, weekday }, 5))
+			} else {
+				Err(self.error(direction_offset, EXPECTED_ON_OR))
+			}
+		} else if first.contains('=') {
+			Err(self.error(offset, EXPECTED_DATE))
+		} else {
+			Ok((self.parse_single_token_date(offset, first)?, 1))
+		}
+	}
+
+	/// Parse a date that consists of a single token.
+	fn parse_single_token_date(&self, offset: usize, token: &str) -> Result<HolidayDate, InvalidHolidaySyntax> {
+		if token.eq_ignore_ascii_case("march-equinox") {
+			Ok(HolidayDate::MarchEquinox)
+		} else if token.eq_ignore_ascii_case("september-equinox") {
+			Ok(HolidayDate::SeptemberEquinox)
+		} else if let Some(offset_str) = strip_prefix_ignore_case(token, "orthodox-easter") {
+			let days = parse_offset(offset_str).ok_or_else(|| self.error(offset + token.len() - offset_str.len(), EXPECTED_OFFSET))?;
+			Ok(HolidayDate::OrthodoxEaster { offset: days })
+		} else if let Some(offset_str) = strip_prefix_ignore_case(token, "easter") {
+			let days = parse_offset(offset_str).ok_or_else(|| self.error(offset + token.len() - offset_str.len(), EXPECTED_OFFSET))?;
+			Ok(HolidayDate::Easter { offset: days })
+		} else if token.starts_with(|c: char| c.is_ascii_digit()) {
+			let (month, day) = parse_month_day(token).ok_or_else(|| self.error(offset, EXPECTED_MONTH_DAY))?;
+			Ok(HolidayDate::Fixed { month, day })
+		} else {
+			Err(self.error(offset, EXPECTED_DATE))
+		}
+	}
+}
+
+/// Iterator over the whitespace separated tokens of a line, with their byte offsets.
+struct Tokens<'a> {
+	line: &'a str,
+	pos: usize,
+}
+
+impl<'a> Tokens<'a> {
+	fn new(line: &'a str, pos: usize) -> Self {
+		Self { line, pos }
+	}
+}
+
+impl<'a> Iterator for Tokens<'a> {
+	type Item = (usize, &'a str);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let rest = &self.line[self.pos..];
+		let start = self.pos + (rest.len() - rest.trim_start().len());
+		let rest = &self.line[start..];
+		if rest.is_empty() {
+			self.pos = self.line.len();
+			return None;
+		}
+		let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+		self.pos = start + len;
+		Some((start, &rest[..len]))
+	}
+}
+
+fn strip_prefix_ignore_case<'a>(data: &'a str, prefix: &str) -> Option<&'a str> {
+	if data.len() >= prefix.len() && data.is_char_boundary(prefix.len()) && data[..prefix.len()].eq_ignore_ascii_case(prefix) {
+		Some(&data[prefix.len()..])
+	} else {
+		None
+	}
+}
+
+/// Parse a month and day like `12-25`.
+///
+/// The day must exist in the month in a leap year.
+fn parse_month_day(data: &str) -> Option<(Month, u8)> {
+	let (month, day) = data.split_once('-')?;
+	if month.is_empty() || day.is_empty() || !month.bytes().all(|c| c.is_ascii_digit()) || !day.bytes().all(|c| c.is_ascii_digit()) {
+		return None;
+	}
+	let month = Month::new(month.parse().ok()?).ok()?;
+	let day: u8 = day.parse().ok()?;
+	Year::new(2000).with_month(month).with_day(day).ok()?;
+	Some((month, day))
+}
+
+/// Parse an ordinal like `1st`, `last` or `2nd-last`.
+fn parse_nth(data: &str) -> Option<i8> {
+	if data.eq_ignore_ascii_case("last") {
+		return Some(-1);
+	}
+	let (ordinal, sign) = match strip_suffix_ignore_case(data, "-last") {
+		Some(ordinal) => (ordinal, -1),
+		None => (data, 1),
+	};
+	let number = ["st", "nd", "rd", "th"]
+		.iter()
+		.find_map(|suffix| strip_suffix_ignore_case(ordinal, suffix))?;
+	if !number.bytes().all(|c| c.is_ascii_digit()) {
+		return None;
+	}
+	match number.parse::<i8>() {
+		Ok(0) | Err(_) => None,
+		Ok(n) => Some(sign * n),
+	}
+}
+
+fn strip_suffix_ignore_case<'a>(data: &'a str, suffix: &str) -> Option<&'a str> {
+	let split = data.len().checked_sub(suffix.len())?;
+	if data.is_char_boundary(split) && data[split..].eq_ignore_ascii_case(suffix) {
+		Some(&data[..split])
+	} else {
+		None
+	}
+}
+
+/// Parse an optional offset in days like `+1` or `-2`.
+fn parse_offset(data: &str) -> Option<i16> {
+	if data.is_empty() {
+		Some(0)
+	} else if data.starts_with(['+', '-']) && data[1..].bytes().all(|c| c.is_ascii_digit()) {
+		data.parse().ok()
+	} else {
+		None
+	}
+}
+
+fn parse_year(data: &str) -> Option<Year> {
+	data.parse().ok().map(Year::new)
+}
+
+fn parse_observance(data: &str) -> Option<Observance> {
+	if data.eq_ignore_ascii_case("actual") {
+		Some(Observance::ACTUAL)
+	} else if data.eq_ignore_ascii_case("nearest-weekday") {
+		Some(Observance::NEAREST_WEEKDAY)
+	} else if data.eq_ignore_ascii_case("next-monday") {
+		Some(Observance::NEXT_MONDAY)
+	} else if data.eq_ignore_ascii_case("sunday-to-monday") {
+		Some(Observance::SUNDAY_TO_MONDAY)
+	} else if data.eq_ignore_ascii_case("substitute") {
+		Some(Observance::SUBSTITUTE)
+	} else if let Some(args) = strip_prefix_ignore_case(data, "substitute(").and_then(|x| x.strip_suffix(')')) {
+		let weekend: WeekdaySet = args.parse().ok()?;
+		Some(Observance::Substitute { weekend })
+	} else if let Some(args) = strip_prefix_ignore_case(data, "shift(").and_then(|x| x.strip_suffix(')')) {
+		let mut offsets = [0; 7];
+		let mut values = args.split(',');
+		for offset in &mut offsets {
+			*offset = values.next()?.trim().parse().ok()?;
+		}
+		if values.next().is_some() {
+			return None;
+		}
+		Some(Observance::Shift { offsets })
+	} else {
+		None
+	}
+}
+
+/// Write a holiday name, quoting it if needed.
+fn write_name(f: &mut core::fmt::Formatter, name: &str) -> core::fmt::Result {
+	let needs_quotes = name.is_empty()
+		|| name.trim() != name
+		|| name.starts_with(['"', '#'])
+		|| name.contains([':', '\n', '\r']);
+	if !needs_quotes {
+		return f.write_str(name);
+	}
+	f.write_str("\"")?;
+	for c in name.chars() {
+		match c {
+			'"' => f.write_str("\\\"")?,
+			'\\' => f.write_str("\\\\")?,
+			'\n' => f.write_str("\\n")?,
+			c => write!(f, "{}", c)?,
+		}
+	}
+	f.write_str("\"")
+}
+
+/// Write an ordinal like `1st`, `last` or `2nd-last`.
+fn write_nth(f: &mut core::fmt::Formatter, n: i8) -> core::fmt::Result {
+	if n == -1 {
+		return f.write_str("last");
+	}
+	let abs = n.unsigned_abs();
+	let suffix = match (abs % 10, abs % 100) {
+		(_, 11..=13) => "th",
+		(1, _) => "st",
+		(2, _) => "nd",
+		(3, _) => "rd",
+		_ => "th",
+	};
+	write!(f, "{}{}", abs, suffix)?;
+	if n < 0 {
+		f.write_str("-last")?;
+	}
+	Ok(())
+}
+
+fn write_offset(f: &mut core::fmt::Formatter, offset: i16) -> core::fmt::Result {
+	if offset != 0 {
+		write!(f, "{:+}", offset)?;
+	}
+	Ok(())
+}
+
+impl core::fmt::Display for HolidayDate {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match *self {
+			Self::Fixed { month, day } => write!(f, "{:02}-{:02}", month.to_number(), day),
+			Self::NthWeekday { month, weekday, n } => {
+				write_nth(f, n)?;
+				write!(f, " {} of {}", weekday.short_name(), month.short_name())
+			},
+			Self::WeekdayOnOrAfter { month, day, weekday } => {
+				write!(f, "{} on or after {:02}-{:02}", weekday.short_name(), month.to_number(), day)
+			},
+			Self::WeekdayOnOrBefore { month, day, weekday } => {
+				write!(f, "{} on or before {:02}-{:02}", weekday.short_name(), month.to_number(), day)
+			},
+			Self::Easter { offset } => {
+				f.write_str("easter")?;
+				write_offset(f, offset)
+			},
+			Self::OrthodoxEaster { offset } => {
+				f.write_str("orthodox-easter")?;
+				write_offset(f, offset)
+			},
+			Self::MarchEquinox => f.write_str("march-equinox"),
+			Self::SeptemberEquinox => f.write_str("september-equinox"),
+		}
+	}
+}
+
+impl core::fmt::Display for Observance {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match *self {
+			Self::ACTUAL => f.write_str("actual"),
+			Self::NEAREST_WEEKDAY => f.write_str("nearest-weekday"),
+			Self::NEXT_MONDAY => f.write_str("next-monday"),
+			Self::SUNDAY_TO_MONDAY => f.write_str("sunday-to-monday"),
+			Self::SUBSTITUTE => f.write_str("substitute"),
+			Self::Substitute { weekend } => write!(f, "substitute({})", weekend),
+			Self::Shift { offsets } => {
+				f.write_str("shift(")?;
+				for (i, offset) in offsets.iter().enumerate() {
+					if i > 0 {
+						f.write_str(",")?;
+					}
+					write!(f, "{}", offset)?;
+				}
+				f.write_str(")")
+			},
+		}
+	}
+}
+
+impl core::fmt::Display for HolidayRule {
+	/// Format the rule as a single line in the holiday definition syntax.
+	///
+	/// See [`HolidaySet`] for a description of the syntax.
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write_name(f, &self.name)?;
+		write!(f, ": {}", self.date)?;
+		if self.observance != Observance::ACTUAL {
+			write!(f, " observed={}", self.observance)?;
+		}
+		if let Some(year) = self.first_year {
+			write!(f, " since={}", year.to_number())?;
+		}
+		if let Some(year) = self.last_year {
+			write!(f, " until={}", year.to_number())?;
+		}
+		Ok(())
+	}
+}
+
+impl core::fmt::Display for HolidaySet {
+	/// Format the set in the holiday definition syntax, with one rule per line.
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		for rule in self.rules() {
+			writeln!(f, "{}", rule)?;
+		}
+		Ok(())
+	}
+}
+
+impl core::str::FromStr for HolidayRule {
+	type Err = InvalidHolidaySyntax;
+
+	/// Parse a single holiday definition.
+	///
+	/// See [`HolidaySet`] for a description of the syntax.
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		parse_holiday_rule(data, 1)
+	}
+}
+
+impl core::str::FromStr for HolidaySet {
+	type Err = InvalidHolidaySyntax;
+
+	/// Parse holiday definitions, one per line.
+	///
+	/// See [`HolidaySet`] for a description of the syntax.
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		parse_holiday_set(data)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HolidayRule {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for HolidayRule {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = <std::borrow::Cow<'a, str>>::deserialize(deserializer)?;
+		raw.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for HolidaySet {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_seq(self.rules())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for HolidaySet {
+	/// Deserialize a holiday set from a list of definitions or from a single string with one definition per line.
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		struct Visitor;
+
+		impl<'a> serde::de::Visitor<'a> for Visitor {
+			type Value = HolidaySet;
+
+			fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+				f.write_str("a list of holiday definitions or a string with one holiday definition per line")
+			}
+
+			fn visit_str<E: serde::de::Error>(self, data: &str) -> Result<Self::Value, E> {
+				data.parse().map_err(E::custom)
+			}
+
+			fn visit_seq<A: serde::de::SeqAccess<'a>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+				let mut set = HolidaySet::new();
+				while let Some(rule) = seq.next_element()? {
+					set.add(rule);
+				}
+				Ok(set)
+			}
+		}
+
+		deserializer.deserialize_any(Visitor)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, Weekday::*};
+	use assert2::{assert, let_assert};
+
+	fn parse_rule(data: &str) -> HolidayRule {
+		let_assert!(Ok(rule) = data.parse::<HolidayRule>(), "{:?}", data);
+		rule
+	}
+
+	fn parse_error(data: &str) -> (usize, usize) {
+		let_assert!(Err(e) = data.parse::<HolidaySet>(), "{:?}", data);
+		(e.line, e.column)
+	}
+
+	#[test]
+	fn parse_dates() {
+		assert!(parse_rule("Christmas: 12-25").date == HolidayDate::Fixed { month: December, day: 25 });
+		assert!(parse_rule("Leap Day: 2-29").date == HolidayDate::Fixed { month: February, day: 29 });
+		assert!(parse_rule("Memorial Day: last Mon of May").date == HolidayDate::NthWeekday { month: May, weekday: Monday, n: -1 });
+		assert!(parse_rule("Thanksgiving: 4th thursday of november").date == HolidayDate::NthWeekday { month: November, weekday: Thursday, n: 4 });
+		assert!(parse_rule("X: 2nd-last Fri of Oct").date == HolidayDate::NthWeekday { month: October, weekday: Friday, n: -2 });
+		assert!(parse_rule("Midsummer: Sat on or after 06-20").date == HolidayDate::WeekdayOnOrAfter { month: June, day: 20, weekday: Saturday });
+		assert!(parse_rule("Victoria Day: Mon on or before 05-24").date == HolidayDate::WeekdayOnOrBefore { month: May, day: 24, weekday: Monday });
+		assert!(parse_rule("Easter: easter").date == HolidayDate::Easter { offset: 0 });
+		assert!(parse_rule("Whit Monday: easter+50").date == HolidayDate::Easter { offset: 50 });
+		assert!(parse_rule("Good Friday: Easter-2").date == HolidayDate::Easter { offset: -2 });
+		assert!(parse_rule("Orthodox Easter Monday: orthodox-easter+1").date == HolidayDate::OrthodoxEaster { offset: 1 });
+		assert!(parse_rule("Vernal Equinox Day: march-equinox").date == HolidayDate::MarchEquinox);
+		assert!(parse_rule("Autumnal Equinox Day: september-equinox").date == HolidayDate::SeptemberEquinox);
+	}
+
+	#[test]
+	fn parse_options() {
+		let rule = parse_rule("  Christmas :  12-25   observed=nearest-weekday since=1900 until=2100  ");
+		assert!(rule.name == "Christmas");
+		assert!(rule.observance == Observance::NEAREST_WEEKDAY);
+		assert!(rule.first_year == Some(Year::new(1900)));
+		assert!(rule.last_year == Some(Year::new(2100)));
+
+		assert!(parse_rule("X: 01-01 observed=substitute").observance == Observance::SUBSTITUTE);
+		assert!(parse_rule("X: 01-01 observed=substitute(Sun)").observance == Observance::Substitute { weekend: WeekdaySet::single(Sunday) });
+		assert!(parse_rule("X: 01-01 observed=shift(0,0,0,0,0,0,-1)").observance == Observance::Shift { offsets: [0, 0, 0, 0, 0, 0, -1] });
+		assert!(parse_rule("X: 01-01 observed=actual").observance == Observance::ACTUAL);
+	}
+
+	#[test]
+	fn parse_quoted_name() {
+		assert!(parse_rule(r#""Day: one": 01-01"#).name == "Day: one");
+		assert!(parse_rule(r#""Say \"hi\" \\ now" : 01-01"#).name == r#"Say "hi" \ now"#);
+		assert!(parse_rule(r#""Two\nlines": 01-01"#).name == "Two\nlines");
+	}
+
+	#[test]
+	fn parse_set() {
+		let_assert!(Ok(set) = "# Comment\n\nNew Year's Day: 01-01\r\n  # Indented comment\nChristmas: 12-25\n".parse::<HolidaySet>());
+		assert!(set.rules().len() == 2);
+		assert!(set.rules()[1].name == "Christmas");
+	}
+
+	#[test]
+	fn parse_errors() {
+		assert!(parse_error("Christmas 12-25") == (1, 16));
+		assert!(parse_error(": 12-25") == (1, 1));
+		assert!(parse_error("\"Christmas: 12-25") == (1, 18));
+		assert!(parse_error("\"Christmas\" 12-25") == (1, 13));
+		assert!(parse_error("A: 01-01\n\nChristmas:") == (3, 11));
+		assert!(parse_error("Christmas: since=2000") == (1, 12));
+		assert!(parse_error("Christmas: 13-25") == (1, 12));
+		assert!(parse_error("Christmas: 02-30") == (1, 12));
+		assert!(parse_error("Christmas: 12-25 foo") == (1, 18));
+		assert!(parse_error("Christmas: 12-25 foo=bar") == (1, 18));
+		assert!(parse_error("Christmas: 12-25 observed=never") == (1, 27));
+		assert!(parse_error("Christmas: 12-25 since=soon") == (1, 24));
+		assert!(parse_error("Memorial Day: final Mon of May") == (1, 15));
+		assert!(parse_error("Memorial Day: 0th Mon of May") == (1, 15));
+		assert!(parse_error("Memorial Day: last Moon of May") == (1, 20));
+		assert!(parse_error("Memorial Day: last Mon in May") == (1, 24));
+		assert!(parse_error("Memorial Day: last Mon of Mai") == (1, 27));
+		assert!(parse_error("Midsummer: Sat on or around 06-20") == (1, 22));
+		assert!(parse_error("Midsummer: Sat at or after 06-20") == (1, 16));
+		assert!(parse_error("Whit Monday: easter50") == (1, 20));
+		assert!(parse_error("Whit Monday: pentecost") == (1, 14));
+		assert!(parse_error("Café: 12-25 observed=x") == (1, 22));
+
+		let_assert!(Err(e) = "Christmas 12-25".parse::<HolidaySet>());
+		assert!(e.to_string() == "invalid holiday definition on line 1, column 16: expected \":\" after the holiday name");
+	}
+
+	#[test]
+	fn format() {
+		let set = HolidaySet::new()
+			.with(HolidayRule::fixed("Christmas", December, 25).observed(Observance::NEAREST_WEEKDAY))
+			.with(HolidayRule::nth_weekday("Memorial Day", -1, Monday, May).since(1971))
+			.with(HolidayRule::nth_weekday("Thanksgiving", 4, Thursday, November))
+			.with(HolidayRule::nth_weekday("Odd", -12, Friday, October).until(2000))
+			.with(HolidayRule::easter("Good Friday", -2))
+			.with(HolidayRule::new("Orthodox Easter", HolidayDate::OrthodoxEaster { offset: 0 }))
+			.with(HolidayRule::new("Midsummer", HolidayDate::WeekdayOnOrAfter { month: June, day: 20, weekday: Saturday }))
+			.with(HolidayRule::new("Equinox", HolidayDate::MarchEquinox).observed(Observance::Substitute { weekend: WeekdaySet::single(Sunday) }))
+			.with(HolidayRule::fixed("King's Day", April, 27).observed(Observance::Shift { offsets: [0, 0, 0, 0, 0, 0, -1] }))
+			.with(HolidayRule::fixed("Say: \"hi\"", January, 2));
+		let formatted = set.to_string();
+		assert!(formatted == concat!(
+			"Christmas: 12-25 observed=nearest-weekday\n",
+			"Memorial Day: last Mon of May since=1971\n",
+			"Thanksgiving: 4th Thu of Nov\n",
+			"Odd: 12th-last Fri of Oct until=2000\n",
+			"Good Friday: easter-2\n",
+			"Orthodox Easter: orthodox-easter\n",
+			"Midsummer: Sat on or after 06-20\n",
+			"Equinox: march-equinox observed=substitute(Sun)\n",
+			"King's Day: 04-27 observed=shift(0,0,0,0,0,0,-1)\n",
+			"\"Say: \\\"hi\\\"\": 01-02\n",
+		));
+		assert!(formatted.parse() == Ok(set));
+	}
+
+	#[test]
+	#[cfg(feature = "holidays")]
+	fn round_trip_builtin() {
+		use crate::holidays::*;
+		let mut sets = vec![netherlands(), germany(), england_and_wales(), scotland(), united_states(), france(), japan()];
+		sets.extend(GermanState::ALL.into_iter().map(germany_state));
+		for set in sets {
+			assert!(set.to_string().parse() == Ok(set));
+		}
+	}
+
+	#[test]
+	fn serde() {
+		#[derive(Debug, serde::Deserialize, serde::Serialize)]
+		struct Container {
+			holidays: HolidaySet,
+		}
+
+		let holidays = HolidaySet::new()
+			.with(HolidayRule::fixed("Christmas", December, 25).observed(Observance::SUBSTITUTE))
+			.with(HolidayRule::easter("Easter Monday", 1));
+		let_assert!(Ok(serialized) = serde_yaml::to_string(&Container { holidays: holidays.clone() }));
+		assert!(serialized == "holidays:\n- 'Christmas: 12-25 observed=substitute'\n- 'Easter Monday: easter+1'\n");
+		let_assert!(Ok(parsed) = serde_yaml::from_str::<Container>(&serialized));
+		assert!(parsed.holidays == holidays);
+
+		let_assert!(Ok(parsed) = serde_yaml::from_str::<Container>("holidays: |\n  Christmas: 12-25 observed=substitute\n  Easter Monday: easter+1\n"));
+		assert!(parsed.holidays == holidays);
+
+		let_assert!(Err(e) = serde_yaml::from_str::<Container>("holidays:\n- 'Christmas: 12-32'\n"));
+		assert!(e.to_string().starts_with("holidays: invalid holiday definition on line 1, column 12: expected a month and day"));
+	}
+}
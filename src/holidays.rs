@@ -0,0 +1,659 @@
+//! Built-in holiday calendars for a number of countries.
+//!
+//! Each function returns a [`HolidaySet`] with the public holidays of a country or region.
+//! Use [`HolidaySet::holidays_in()`] to list the holidays in a year,
+//! or [`HolidaySet::holiday_name()`] to check if a date is a holiday.
+//!
+//! The rules include the most important historical changes since the 1970s,
+//! such as introduced holidays and one-off holidays.
+//! Earlier dates may not be accurate.
+//!
+//! ```
+//! # use gregorian::{Date, Year};
+//! let holidays = gregorian::holidays::netherlands();
+//! assert!(holidays.holidays_in(Year::new(2024)).len() == 10);
+//! assert!(holidays.holiday_name(Date::new(2025, 4, 26).unwrap()) == Some("King's Day"));
+//! ```
+
+use crate::{Date, HolidayDate, HolidayRule, HolidaySet, Month, Month::*, Observance, Weekday::*, WeekdaySet, Year};
+
+/// The public holidays of the Netherlands.
+///
+/// Liberation Day is included every year, although it is only a day off for most employees once every five years.
+pub fn netherlands() -> HolidaySet {
+	HolidaySet::new()
+		.with(HolidayRule::fixed("New Year's Day", January, 1))
+		.with(HolidayRule::easter("Easter Sunday", 0))
+		.with(HolidayRule::easter("Easter Monday", 1))
+		.with(
+			HolidayRule::fixed("Queen's Day", April, 30)
+				.until(1979)
+				.observed(Observance::SUNDAY_TO_MONDAY),
+		)
+		.with(
+			HolidayRule::fixed("Queen's Day", April, 30)
+				.since(1980)
+				.until(2013)
+				.observed(SUNDAY_TO_SATURDAY),
+		)
+		.with(HolidayRule::fixed("King's Day", April, 27).since(2014).observed(SUNDAY_TO_SATURDAY))
+		.with(HolidayRule::fixed("Liberation Day", May, 5).since(1990))
+		.with(HolidayRule::easter("Ascension Day", 39))
+		.with(HolidayRule::easter("Whit Sunday", 49))
+		.with(HolidayRule::easter("Whit Monday", 50))
+		.with(HolidayRule::fixed("Christmas Day", December, 25))
+		.with(HolidayRule::fixed("Second Day of Christmas", December, 26))
+}
+
+/// The public holidays of Germany that apply in all states.
+pub fn germany() -> HolidaySet {
+	germany_federal(true)
+}
+
+/// The public holidays of Germany that apply in all states, optionally including the one-off Reformation Day of 2017.
+fn germany_federal(reformation_2017: bool) -> HolidaySet {
+	let mut holidays = HolidaySet::new()
+		.with(HolidayRule::fixed("New Year's Day", January, 1))
+		.with(HolidayRule::easter("Good Friday", -2))
+		.with(HolidayRule::easter("Easter Monday", 1))
+		.with(HolidayRule::fixed("Labour Day", May, 1))
+		.with(HolidayRule::easter("Ascension Day", 39))
+		.with(HolidayRule::easter("Whit Monday", 50))
+		.with(HolidayRule::fixed("German Unity Day", October, 3).since(1990))
+		.with(repentance_day().until(1994))
+		.with(HolidayRule::fixed("Christmas Day", December, 25))
+		.with(HolidayRule::fixed("Second Day of Christmas", December, 26));
+	if reformation_2017 {
+		holidays.add(one_off("Reformation Day", 2017, October, 31));
+	}
+	holidays
+}
+
+/// The public holidays of a German state, including the holidays that apply in all states.
+///
+/// Holidays that only apply in some municipalities of a state are not included,
+/// like Assumption Day in Bavaria or Corpus Christi in Saxony and Thuringia.
+pub fn germany_state(state: GermanState) -> HolidaySet {
+	use GermanState::*;
+
+	let epiphany = || HolidayRule::fixed("Epiphany", January, 6);
+	let corpus_christi = || HolidayRule::easter("Corpus Christi", 60);
+	let all_saints = || HolidayRule::fixed("All Saints' Day", November, 1);
+	let reformation = || HolidayRule::fixed("Reformation Day", October, 31);
+	let womens_day = || HolidayRule::fixed("International Women's Day", March, 8);
+
+	let extra = match state {
+		BadenWurttemberg | Bavaria => vec![epiphany(), corpus_christi(), all_saints()],
+		Berlin => vec![
+			womens_day().since(2019),
+			one_off("Liberation Day", 2020, May, 8),
+			one_off("Liberation Day", 2025, May, 8),
+		],
+		Brandenburg => vec![
+			HolidayRule::easter("Easter Sunday", 0),
+			HolidayRule::easter("Whit Sunday", 49),
+			reformation().since(1990),
+		],
+		Bremen | Hamburg | LowerSaxony | SchleswigHolstein => vec![reformation().since(2018)],
+		Hesse => vec![corpus_christi()],
+		MecklenburgVorpommern => vec![reformation().since(1990), womens_day().since(2023)],
+		NorthRhineWestphalia | RhinelandPalatinate => vec![corpus_christi(), all_saints()],
+		Saarland => vec![corpus_christi(), HolidayRule::fixed("Assumption Day", August, 15), all_saints()],
+		Saxony => vec![reformation().since(1990), repentance_day().since(1995)],
+		SaxonyAnhalt => vec![epiphany(), reformation().since(1990)],
+		Thuringia => vec![
+			reformation().since(1990),
+			HolidayRule::fixed("World Children's Day", September, 20).since(2019),
+		],
+	};
+
+	// Don't add the nationwide Reformation Day of 2017 if the state already has it every year.
+	let reformation_2017 = !extra
+		.iter()
+		.any(|rule| rule.name == "Reformation Day" && rule.is_valid_in(Year::new(2017)));
+	let mut holidays = germany_federal(reformation_2017);
+	holidays.extend(extra);
+	holidays
+}
+
+/// A state of Germany.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GermanState {
+	BadenWurttemberg,
+	Bavaria,
+	Berlin,
+	Brandenburg,
+	Bremen,
+	Hamburg,
+	Hesse,
+	LowerSaxony,
+	MecklenburgVorpommern,
+	NorthRhineWestphalia,
+	RhinelandPalatinate,
+	Saarland,
+	Saxony,
+	SaxonyAnhalt,
+	SchleswigHolstein,
+	Thuringia,
+}
+
+impl GermanState {
+	/// All German states in alphabetical order.
+	pub const ALL: [Self; 16] = [
+		Self::BadenWurttemberg,
+		Self::Bavaria,
+		Self::Berlin,
+		Self::Brandenburg,
+		Self::Bremen,
+		Self::Hamburg,
+		Self::Hesse,
+		Self::LowerSaxony,
+		Self::MecklenburgVorpommern,
+		Self::NorthRhineWestphalia,
+		Self::RhinelandPalatinate,
+		Self::Saarland,
+		Self::Saxony,
+		Self::SaxonyAnhalt,
+		Self::SchleswigHolstein,
+		Self::Thuringia,
+	];
+
+	/// Get the ISO 3166-2 subdivision code of the state without the country prefix, such as `"BY"` for Bavaria.
+	pub const fn code(self) -> &'static str {
+		match self {
+			Self::BadenWurttemberg => "BW",
+			Self::Bavaria => "BY",
+			Self::Berlin => "BE",
+			Self::Brandenburg => "BB",
+			Self::Bremen => "HB",
+			Self::Hamburg => "HH",
+			Self::Hesse => "HE",
+			Self::LowerSaxony => "NI",
+			Self::MecklenburgVorpommern => "MV",
+			Self::NorthRhineWestphalia => "NW",
+			Self::RhinelandPalatinate => "RP",
+			Self::Saarland => "SL",
+			Self::Saxony => "SN",
+			Self::SaxonyAnhalt => "ST",
+			Self::SchleswigHolstein => "SH",
+			Self::Thuringia => "TH",
+		}
+	}
+}
+
+/// The bank holidays of England and Wales.
+///
+/// Holidays on a weekend are observed on the next working day.
+pub fn england_and_wales() -> HolidaySet {
+	let mut holidays = HolidaySet::new()
+		.with(
+			HolidayRule::fixed("New Year's Day", January, 1)
+				.since(1974)
+				.observed(Observance::SUBSTITUTE),
+		)
+		.with(HolidayRule::easter("Good Friday", -2))
+		.with(HolidayRule::easter("Easter Monday", 1));
+	holidays.extend(uk_common());
+	holidays.add(HolidayRule::nth_weekday("Summer Bank Holiday", -1, Monday, August).since(1971));
+	holidays.extend(uk_christmas());
+	holidays
+}
+
+/// The bank holidays of Scotland.
+///
+/// Holidays on a weekend are observed on the next working day.
+pub fn scotland() -> HolidaySet {
+	let mut holidays = HolidaySet::new()
+		.with(HolidayRule::fixed("New Year's Day", January, 1).observed(Observance::SUBSTITUTE))
+		.with(HolidayRule::fixed("2nd January", January, 2).observed(Observance::SUBSTITUTE))
+		.with(HolidayRule::easter("Good Friday", -2));
+	holidays.extend(uk_common());
+	holidays.add(HolidayRule::nth_weekday("Summer Bank Holiday", 1, Monday, August).since(1971));
+	holidays.add(
+		HolidayRule::fixed("St Andrew's Day", November, 30)
+			.since(2007)
+			.observed(Observance::SUBSTITUTE),
+	);
+	holidays.extend(uk_christmas());
+	holidays
+}
+
+/// The bank holidays in May and the one-off bank holidays shared by England, Wales and Scotland.
+fn uk_common() -> Vec<HolidayRule> {
+	let mut rules = Vec::new();
+	rules.extend(except(
+		HolidayRule::nth_weekday("Early May Bank Holiday", 1, Monday, May).since(1978),
+		&[(1995, May, 8), (2020, May, 8)],
+	));
+	rules.extend(except(
+		HolidayRule::nth_weekday("Spring Bank Holiday", -1, Monday, May).since(1971),
+		&[(1977, June, 6), (2002, June, 4), (2012, June, 4), (2022, June, 2)],
+	));
+	rules.extend([
+		one_off("Silver Jubilee of Elizabeth II", 1977, June, 7),
+		one_off("Wedding of Prince Charles and Lady Diana Spencer", 1981, July, 29),
+		one_off("Millennium Celebrations", 1999, December, 31),
+		one_off("Golden Jubilee of Elizabeth II", 2002, June, 3),
+		one_off("Wedding of Prince William and Catherine Middleton", 2011, April, 29),
+		one_off("Diamond Jubilee of Elizabeth II", 2012, June, 5),
+		one_off("Platinum Jubilee of Elizabeth II", 2022, June, 3),
+		one_off("State Funeral of Queen Elizabeth II", 2022, September, 19),
+		one_off("Coronation of Charles III", 2023, May, 8),
+	]);
+	rules
+}
+
+/// Christmas Day and Boxing Day, as observed in the United Kingdom.
+fn uk_christmas() -> [HolidayRule; 2] {
+	[
+		HolidayRule::fixed("Christmas Day", December, 25).observed(Observance::SUBSTITUTE),
+		HolidayRule::fixed("Boxing Day", December, 26).observed(Observance::SUBSTITUTE),
+	]
+}
+
+/// The federal holidays of the United States.
+///
+/// Holidays on a Saturday are observed on the Friday before,
+/// and holidays on a Sunday are observed on the Monday after.
+pub fn united_states() -> HolidaySet {
+	let nearest = Observance::NEAREST_WEEKDAY;
+	HolidaySet::new()
+		.with(HolidayRule::fixed("New Year's Day", January, 1).observed(nearest))
+		.with(HolidayRule::nth_weekday("Martin Luther King Jr. Day", 3, Monday, January).since(1986))
+		.with(
+			HolidayRule::fixed("Washington's Birthday", February, 22)
+				.until(1970)
+				.observed(nearest),
+		)
+		.with(HolidayRule::nth_weekday("Washington's Birthday", 3, Monday, February).since(1971))
+		.with(HolidayRule::fixed("Memorial Day", May, 30).until(1970).observed(nearest))
+		.with(HolidayRule::nth_weekday("Memorial Day", -1, Monday, May).since(1971))
+		.with(
+			HolidayRule::fixed("Juneteenth National Independence Day", June, 19)
+				.since(2021)
+				.observed(nearest),
+		)
+		.with(HolidayRule::fixed("Independence Day", July, 4).observed(nearest))
+		.with(HolidayRule::nth_weekday("Labor Day", 1, Monday, September))
+		.with(HolidayRule::fixed("Columbus Day", October, 12).until(1970).observed(nearest))
+		.with(HolidayRule::nth_weekday("Columbus Day", 2, Monday, October).since(1971))
+		.with(HolidayRule::fixed("Veterans Day", November, 11).until(1970).observed(nearest))
+		.with(HolidayRule::nth_weekday("Veterans Day", 4, Monday, October).since(1971).until(1977))
+		.with(HolidayRule::fixed("Veterans Day", November, 11).since(1978).observed(nearest))
+		.with(HolidayRule::nth_weekday("Thanksgiving Day", 4, Thursday, November))
+		.with(HolidayRule::fixed("Christmas Day", December, 25).observed(nearest))
+}
+
+/// The public holidays of France.
+///
+/// The additional holidays of Alsace and Moselle are not included.
+pub fn france() -> HolidaySet {
+	HolidaySet::new()
+		.with(HolidayRule::fixed("New Year's Day", January, 1))
+		.with(HolidayRule::easter("Easter Monday", 1))
+		.with(HolidayRule::fixed("Labour Day", May, 1))
+		.with(HolidayRule::fixed("Victory in Europe Day", May, 8).until(1959))
+		.with(HolidayRule::fixed("Victory in Europe Day", May, 8).since(1982))
+		.with(HolidayRule::easter("Ascension Day", 39))
+		.with(HolidayRule::easter("Whit Monday", 50))
+		.with(HolidayRule::fixed("Bastille Day", July, 14))
+		.with(HolidayRule::fixed("Assumption Day", August, 15))
+		.with(HolidayRule::fixed("All Saints' Day", November, 1))
+		.with(HolidayRule::fixed("Armistice Day", November, 11))
+		.with(HolidayRule::fixed("Christmas Day", December, 25))
+}
+
+/// The national holidays of Japan.
+///
+/// Substitute holidays exist since 12 April 1973.
+/// Until 2006, holidays on a Sunday are observed on the Monday after.
+/// Since 2007, holidays on a Sunday are observed on the next day that is not a holiday.
+/// Citizens' holidays, which fall on a single weekday between two holidays, are not included,
+/// except for the days around the enthronement in 2019.
+pub fn japan() -> HolidaySet {
+	let substitute = Observance::Substitute {
+		weekend: WeekdaySet::single(Sunday),
+	};
+
+	let mut rules = vec![
+		HolidayRule::fixed("New Year's Day", January, 1),
+		HolidayRule::fixed("Coming of Age Day", January, 15).until(1999),
+		HolidayRule::nth_weekday("Coming of Age Day", 2, Monday, January).since(2000),
+		HolidayRule::fixed("National Foundation Day", February, 11).since(1967),
+		HolidayRule::new("Vernal Equinox Day", HolidayDate::MarchEquinox),
+		HolidayRule::fixed("Emperor's Birthday", April, 29).until(1988),
+		HolidayRule::fixed("Greenery Day", April, 29).since(1989).until(2006),
+		HolidayRule::fixed("Showa Day", April, 29).since(2007),
+		HolidayRule::fixed("Constitution Memorial Day", May, 3),
+		HolidayRule::fixed("Greenery Day", May, 4).since(2007),
+		HolidayRule::fixed("Children's Day", May, 5),
+		HolidayRule::fixed("Marine Day", July, 20).since(1996).until(2002),
+		HolidayRule::fixed("Respect for the Aged Day", September, 15)
+			.since(1966)
+			.until(2002),
+		HolidayRule::nth_weekday("Respect for the Aged Day", 3, Monday, September).since(2003),
+		HolidayRule::new("Autumnal Equinox Day", HolidayDate::SeptemberEquinox),
+		HolidayRule::fixed("Health and Sports Day", October, 10).since(1966).until(1999),
+		HolidayRule::nth_weekday("Health and Sports Day", 2, Monday, October)
+			.since(2000)
+			.until(2019),
+		HolidayRule::fixed("Culture Day", November, 3),
+		HolidayRule::fixed("Labour Thanksgiving Day", November, 23),
+		HolidayRule::fixed("Emperor's Birthday", December, 23).since(1989).until(2018),
+		HolidayRule::fixed("Emperor's Birthday", February, 23).since(2020),
+		one_off("National Holiday", 2019, April, 30),
+		one_off("Enthronement Day", 2019, May, 1),
+		one_off("National Holiday", 2019, May, 2),
+		one_off("Enthronement Ceremony Day", 2019, October, 22),
+	];
+
+	// The Olympic Games of 2020 were held in 2021, and the holidays were moved both years.
+	rules.extend(except(
+		HolidayRule::nth_weekday("Marine Day", 3, Monday, July).since(2003),
+		&[(2020, July, 23), (2021, July, 22)],
+	));
+	rules.extend(except(
+		HolidayRule::fixed("Mountain Day", August, 11).since(2016),
+		&[(2020, August, 10), (2021, August, 8)],
+	));
+	rules.extend(except(
+		HolidayRule::nth_weekday("Sports Day", 2, Monday, October).since(2020),
+		&[(2020, July, 24), (2021, July, 23)],
+	));
+
+	rules
+		.into_iter()
+		.flat_map(|rule| {
+			// Holidays before 12 April 1973 were never substituted.
+			let start = match rule.date_in(Year::new(1973)) {
+				Some(date) if date < Date::new(1973, April, 12).unwrap() => 1974,
+				_ => 1973,
+			};
+			observed_since(rule, start, Observance::SUNDAY_TO_MONDAY)
+		})
+		.flat_map(|rule| observed_since(rule, 2007, substitute))
+		.collect()
+}
+
+/// Observance that moves holidays on a Sunday to the Saturday before.
+const SUNDAY_TO_SATURDAY: Observance = Observance::Shift {
+	offsets: [0, 0, 0, 0, 0, 0, -1],
+};
+
+/// Create a rule for a holiday that occurs only once.
+fn one_off(name: &'static str, year: i16, month: Month, day: u8) -> HolidayRule {
+	HolidayRule::fixed(name, month, day).since(year).until(year)
+}
+
+/// The Day of Repentance and Prayer, on the Wednesday before 23 November.
+fn repentance_day() -> HolidayRule {
+	HolidayRule::new(
+		"Day of Repentance and Prayer",
+		HolidayDate::WeekdayOnOrBefore {
+			month: November,
+			day: 22,
+			weekday: Wednesday,
+		},
+	)
+}
+
+/// Split a rule around years in which the holiday was moved to a different date.
+///
+/// The `moves` must be sorted by year, and the rule must not have a last year.
+fn except(rule: HolidayRule, moves: &[(i16, Month, u8)]) -> Vec<HolidayRule> {
+	let mut rules = Vec::with_capacity(moves.len() * 2 + 1);
+	let mut first_year = rule.first_year;
+	for &(year, month, day) in moves {
+		if first_year.is_none_or(|first| first < year) {
+			rules.push(HolidayRule {
+				first_year,
+				last_year: Some(Year::new(year - 1)),
+				..rule.clone()
+			});
+		}
+		rules.push(one_off_like(&rule, year, month, day));
+		first_year = Some(Year::new(year + 1));
+	}
+	rules.push(HolidayRule { first_year, ..rule });
+	rules
+}
+
+/// Split a rule in the part before and since a year, using a different observance since that year.
+fn observed_since(rule: HolidayRule, year: i16, observance: Observance) -> Vec<HolidayRule> {
+	let year = Year::new(year);
+	let mut rules = Vec::with_capacity(2);
+	if rule.first_year.is_none_or(|first| first < year) {
+		rules.push(HolidayRule {
+			last_year: Some(rule.last_year.map_or(year.prev(), |last| last.min(year.prev()))),
+			..rule.clone()
+		});
+	}
+	if rule.last_year.is_none_or(|last| last >= year) {
+		rules.push(HolidayRule {
+			first_year: Some(rule.first_year.map_or(year, |first| first.max(year))),
+			..rule
+		}
+		.observed(observance));
+	}
+	rules
+}
+
+/// Create a one-off rule with the same name and observance as another rule.
+fn one_off_like(rule: &HolidayRule, year: i16, month: Month, day: u8) -> HolidayRule {
+	HolidayRule::fixed(rule.name.clone(), month, day)
+		.observed(rule.observance)
+		.since(year)
+		.until(year)
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Date;
+	use crate::util::date;
+	use assert2::assert;
+
+	/// Get the sorted observed dates of all holidays in a year.
+	fn observed(holidays: &HolidaySet, year: i16) -> Vec<Date> {
+		let mut dates: Vec<_> = holidays
+			.holidays_in_year(Year::new(year))
+			.into_iter()
+			.map(|holiday| holiday.observed)
+			.collect();
+		dates.sort();
+		dates
+	}
+
+	#[test]
+	fn netherlands() {
+		let holidays = super::netherlands();
+		assert!(observed(&holidays, 2024) == [
+			date(2024, January, 1),
+			date(2024, March, 31),
+			date(2024, April, 1),
+			date(2024, April, 27),
+			date(2024, May, 5),
+			date(2024, May, 9),
+			date(2024, May, 19),
+			date(2024, May, 20),
+			date(2024, December, 25),
+			date(2024, December, 26),
+		]);
+		assert!(holidays.holiday_name(date(2025, April, 26)) == Some("King's Day"));
+		assert!(holidays.holiday_name(date(2025, April, 27)) == Some("King's Day"));
+		assert!(holidays.holiday_name(date(2013, April, 30)) == Some("Queen's Day"));
+		assert!(holidays.holiday_name(date(2006, April, 29)) == Some("Queen's Day"));
+		assert!(holidays.holiday_name(date(2013, April, 27)) == None);
+	}
+
+	#[test]
+	fn germany() {
+		let holidays = super::germany();
+		assert!(observed(&holidays, 2024) == [
+			date(2024, January, 1),
+			date(2024, March, 29),
+			date(2024, April, 1),
+			date(2024, May, 1),
+			date(2024, May, 9),
+			date(2024, May, 20),
+			date(2024, October, 3),
+			date(2024, December, 25),
+			date(2024, December, 26),
+		]);
+		assert!(holidays.holiday_name(date(2017, October, 31)) == Some("Reformation Day"));
+		assert!(holidays.holiday_name(date(2018, October, 31)) == None);
+		assert!(holidays.holiday_name(date(1994, November, 16)) == Some("Day of Repentance and Prayer"));
+		assert!(holidays.holiday_name(date(1995, November, 22)) == None);
+	}
+
+	#[test]
+	fn germany_state() {
+		let holidays = super::germany_state(GermanState::BadenWurttemberg);
+		assert!(holidays.holidays_in(Year::new(2024)).len() == 12);
+		assert!(holidays.holiday_name(date(2024, January, 6)) == Some("Epiphany"));
+		assert!(holidays.holiday_name(date(2024, May, 30)) == Some("Corpus Christi"));
+		assert!(holidays.holiday_name(date(2024, November, 1)) == Some("All Saints' Day"));
+		assert!(holidays.holiday_name(date(2017, October, 31)) == Some("Reformation Day"));
+
+		let holidays = super::germany_state(GermanState::Saxony);
+		assert!(holidays.holidays_in(Year::new(2024)).len() == 11);
+		assert!(holidays.holiday_name(date(2024, October, 31)) == Some("Reformation Day"));
+		assert!(holidays.holiday_name(date(2024, November, 20)) == Some("Day of Repentance and Prayer"));
+		assert!(holidays.holidays_on(date(2017, October, 31)).len() == 1);
+
+		let holidays = super::germany_state(GermanState::Berlin);
+		assert!(holidays.holiday_name(date(2024, March, 8)) == Some("International Women's Day"));
+		assert!(holidays.holiday_name(date(2025, May, 8)) == Some("Liberation Day"));
+		assert!(holidays.holiday_name(date(2024, May, 8)) == None);
+
+		for state in GermanState::ALL {
+			let holidays = super::germany_state(state);
+			assert!(holidays.holidays_in(Year::new(2024)).len() >= 9, "{:?}", state);
+			assert!(holidays.holidays_on(date(2017, October, 31)).len() == 1, "{:?}", state);
+		}
+		assert!(GermanState::Bavaria.code() == "BY");
+	}
+
+	#[test]
+	fn england_and_wales() {
+		let holidays = super::england_and_wales();
+		assert!(observed(&holidays, 2022) == [
+			date(2022, January, 3),
+			date(2022, April, 15),
+			date(2022, April, 18),
+			date(2022, May, 2),
+			date(2022, June, 2),
+			date(2022, June, 3),
+			date(2022, August, 29),
+			date(2022, September, 19),
+			date(2022, December, 26),
+			date(2022, December, 27),
+		]);
+		assert!(observed(&holidays, 2023) == [
+			date(2023, January, 2),
+			date(2023, April, 7),
+			date(2023, April, 10),
+			date(2023, May, 1),
+			date(2023, May, 8),
+			date(2023, May, 29),
+			date(2023, August, 28),
+			date(2023, December, 25),
+			date(2023, December, 26),
+		]);
+		assert!(holidays.holiday_name(date(2020, May, 8)) == Some("Early May Bank Holiday"));
+		assert!(holidays.holiday_name(date(2020, May, 4)) == None);
+		assert!(holidays.holiday_name(date(2021, December, 27)) == Some("Christmas Day"));
+		assert!(holidays.holiday_name(date(2021, December, 28)) == Some("Boxing Day"));
+	}
+
+	#[test]
+	fn scotland() {
+		let holidays = super::scotland();
+		assert!(observed(&holidays, 2022)[..2] == [date(2022, January, 3), date(2022, January, 4)]);
+		assert!(observed(&holidays, 2023)[..2] == [date(2023, January, 2), date(2023, January, 3)]);
+		assert!(holidays.holiday_name(date(2023, August, 7)) == Some("Summer Bank Holiday"));
+		assert!(holidays.holiday_name(date(2023, August, 28)) == None);
+		assert!(holidays.holiday_name(date(2023, April, 10)) == None);
+		assert!(holidays.holiday_name(date(2024, December, 2)) == Some("St Andrew's Day"));
+	}
+
+	#[test]
+	fn united_states() {
+		let holidays = super::united_states();
+		assert!(observed(&holidays, 2024) == [
+			date(2024, January, 1),
+			date(2024, January, 15),
+			date(2024, February, 19),
+			date(2024, May, 27),
+			date(2024, June, 19),
+			date(2024, July, 4),
+			date(2024, September, 2),
+			date(2024, October, 14),
+			date(2024, November, 11),
+			date(2024, November, 28),
+			date(2024, December, 25),
+		]);
+		assert!(holidays.holiday_name(date(2021, December, 31)) == Some("New Year's Day"));
+		assert!(holidays.holiday_name(date(2022, June, 20)) == Some("Juneteenth National Independence Day"));
+		assert!(holidays.holiday_name(date(2022, December, 26)) == Some("Christmas Day"));
+		assert!(holidays.holiday_name(date(2020, June, 19)) == None);
+	}
+
+	#[test]
+	fn france() {
+		let holidays = super::france();
+		assert!(observed(&holidays, 2024) == [
+			date(2024, January, 1),
+			date(2024, April, 1),
+			date(2024, May, 1),
+			date(2024, May, 8),
+			date(2024, May, 9),
+			date(2024, May, 20),
+			date(2024, July, 14),
+			date(2024, August, 15),
+			date(2024, November, 1),
+			date(2024, November, 11),
+			date(2024, December, 25),
+		]);
+	}
+
+	#[test]
+	fn japan() {
+		let holidays = super::japan();
+		assert!(observed(&holidays, 2024) == [
+			date(2024, January, 1),
+			date(2024, January, 8),
+			date(2024, February, 12),
+			date(2024, February, 23),
+			date(2024, March, 20),
+			date(2024, April, 29),
+			date(2024, May, 3),
+			date(2024, May, 4),
+			date(2024, May, 6),
+			date(2024, July, 15),
+			date(2024, August, 12),
+			date(2024, September, 16),
+			date(2024, September, 23),
+			date(2024, October, 14),
+			date(2024, November, 4),
+			date(2024, November, 23),
+		]);
+		assert!(holidays.holiday_name(date(2021, July, 23)) == Some("Sports Day"));
+		assert!(holidays.holiday_name(date(2021, August, 9)) == Some("Mountain Day"));
+		assert!(holidays.holiday_name(date(2019, May, 1)) == Some("Enthronement Day"));
+		assert!(holidays.holiday_name(date(2018, December, 23)) == Some("Emperor's Birthday"));
+		assert!(holidays.holiday_name(date(2019, December, 23)) == None);
+
+		// Substitute holidays started on 12 April 1973.
+		assert!(observed(&holidays, 1970).contains(&date(1970, May, 3)));
+		assert!(!observed(&holidays, 1970).contains(&date(1970, May, 4)));
+		assert!(observed(&holidays, 1973).contains(&date(1973, February, 11)));
+		assert!(!observed(&holidays, 1973).contains(&date(1973, February, 12)));
+		assert!(observed(&holidays, 1973).contains(&date(1973, April, 30)));
+		assert!(!observed(&holidays, 1973).contains(&date(1973, April, 29)));
+
+		// Since 2007, substitute holidays move past other holidays.
+		assert!(observed(&holidays, 2008).contains(&date(2008, May, 6)));
+	}
+}
@@ -0,0 +1,305 @@
+use core::fmt::Write;
+
+use crate::{Date, DateSet, MonthGrid, WeekRules, WeekdaySet, Year, YearMonth, MONTHS, WEEKDAYS};
+
+/// Render months and years as HTML tables.
+///
+/// Each month is rendered as a `<table>` with the month name in a `<caption>`,
+/// a header row with the weekdays and one row per week.
+/// Every day is rendered as a `<td>` containing a `<time>` element with the date.
+///
+/// Days get CSS classes that can be used for styling:
+/// * `weekend` for days on the weekend,
+/// * `today` for the date set with [`Self::with_today()`],
+/// * `outside` for leading and trailing days of adjacent months,
+/// * any class added with [`Self::with_date_class()`] or [`Self::with_classifier()`].
+///
+/// The names of months and weekdays are English by default,
+/// but they can be replaced with [`Self::with_month_names()`] and [`Self::with_weekday_names()`] to render other languages.
+/// Together with [`Self::with_week_rules()`], this can be used to follow the conventions of any locale.
+///
+/// ```
+/// # use gregorian::{Date, HtmlCalendar, Month::*, YearMonth};
+/// let html = HtmlCalendar::new()
+///     .with_today(Date::new(2024, February, 14).unwrap())
+///     .render_month(YearMonth::new(2024, February));
+/// assert!(html.starts_with("<table class=\"month\">\n<caption>February 2024</caption>\n"));
+/// assert!(html.contains("<td class=\"today\"><time datetime=\"2024-02-14\">14</time></td>"));
+/// ```
+pub struct HtmlCalendar {
+	week_rules: WeekRules,
+	weekend: WeekdaySet,
+	today: Option<Date>,
+	week_numbers: bool,
+	outside_days: bool,
+	month_names: [String; 12],
+	weekday_names: [(String, String); 7],
+	classifiers: Vec<Box<dyn Fn(Date) -> Option<String>>>,
+}
+
+impl HtmlCalendar {
+	/// Create a new HTML renderer using ISO 8601 weeks and English names.
+	pub fn new() -> Self {
+		Self {
+			week_rules: WeekRules::ISO,
+			weekend: WeekdaySet::WEEKEND,
+			today: None,
+			week_numbers: false,
+			outside_days: false,
+			month_names: MONTHS.map(|month| month.name().to_string()),
+			weekday_names: WEEKDAYS.map(|weekday| (weekday.name().to_string(), weekday.short_name().to_string())),
+			classifiers: Vec::new(),
+		}
+	}
+
+	/// Set the week rules used for the first weekday and the week numbers, returning the modified renderer.
+	pub fn with_week_rules(mut self, week_rules: WeekRules) -> Self {
+		self.week_rules = week_rules;
+		self
+	}
+
+	/// Set the weekdays that get the `weekend` class, returning the modified renderer.
+	pub fn with_weekend(mut self, weekend: WeekdaySet) -> Self {
+		self.weekend = weekend;
+		self
+	}
+
+	/// Set the date that gets the `today` class, returning the modified renderer.
+	pub fn with_today(mut self, today: Date) -> Self {
+		self.today = Some(today);
+		self
+	}
+
+	/// Show the week number in front of every week, returning the modified renderer.
+	pub fn with_week_numbers(mut self, week_numbers: bool) -> Self {
+		self.week_numbers = week_numbers;
+		self
+	}
+
+	/// Show the leading and trailing days of adjacent months, returning the modified renderer.
+	///
+	/// If disabled, the cells for those days are rendered empty, but they still get the `outside` class.
+	pub fn with_outside_days(mut self, outside_days: bool) -> Self {
+		self.outside_days = outside_days;
+		self
+	}
+
+	/// Set the names of the months, from January to December, returning the modified renderer.
+	pub fn with_month_names<S: Into<String>>(mut self, names: [S; 12]) -> Self {
+		self.month_names = names.map(Into::into);
+		self
+	}
+
+	/// Set the full and abbreviated names of the weekdays, from Monday to Sunday, returning the modified renderer.
+	///
+	/// The abbreviated name is shown in the header, and the full name is used for the `abbr` attribute.
+	pub fn with_weekday_names<S: Into<String>>(mut self, names: [(S, S); 7]) -> Self {
+		self.weekday_names = names.map(|(name, short_name)| (name.into(), short_name.into()));
+		self
+	}
+
+	/// Add a CSS class to all dates in a set, returning the modified renderer.
+	pub fn with_date_class(self, class: impl Into<String>, dates: DateSet) -> Self {
+		let class = class.into();
+		self.with_classifier(move |date| dates.contains(date).then(|| class.clone()))
+	}
+
+	/// Add CSS classes to dates with a callback, returning the modified renderer.
+	///
+	/// The callback can return multiple classes separated by spaces, or `None` to add no classes.
+	pub fn with_classifier(mut self, classifier: impl Fn(Date) -> Option<String> + 'static) -> Self {
+		self.classifiers.push(Box::new(classifier));
+		self
+	}
+
+	/// Render a single month as a table.
+	pub fn render_month(&self, month: YearMonth) -> String {
+		let mut output = String::new();
+		self.write_month(&mut output, month, true);
+		output
+	}
+
+	/// Render all months of a year as a section with a heading and a table for each month.
+	pub fn render_year(&self, year: Year) -> String {
+		let mut output = String::new();
+		output.push_str("<section class=\"year\">\n");
+		let _ = writeln!(output, "<h2>{}</h2>", year.to_number());
+		for month in MONTHS {
+			self.write_month(&mut output, year.with_month(month), false);
+		}
+		output.push_str("</section>\n");
+		output
+	}
+
+	/// Get the CSS classes of a day.
+	fn classes(&self, date: Date, in_month: bool) -> String {
+		let mut classes = Vec::new();
+		if !in_month {
+			classes.push("outside".to_string());
+		}
+		if self.weekend.contains_date(date) {
+			classes.push("weekend".to_string());
+		}
+		if self.today == Some(date) {
+			classes.push("today".to_string());
+		}
+		classes.extend(self.classifiers.iter().filter_map(|classifier| classifier(date)));
+		classes.join(" ")
+	}
+
+	fn write_month(&self, output: &mut String, month: YearMonth, with_year: bool) {
+		let grid = MonthGrid::new(month).with_week_rules(self.week_rules);
+		let name = escape(&self.month_names[month.month().to_number() as usize - 1]);
+
+		output.push_str("<table class=\"month\">\n");
+		if with_year {
+			let _ = writeln!(output, "<caption>{} {}</caption>", name, month.year().to_number());
+		} else {
+			let _ = writeln!(output, "<caption>{}</caption>", name);
+		}
+
+		output.push_str("<thead>\n<tr>");
+		if self.week_numbers {
+			output.push_str("<th scope=\"col\" class=\"week\"></th>");
+		}
+		for weekday in grid.weekdays() {
+			let (name, short_name) = &self.weekday_names[weekday.to_number() as usize - 1];
+			let _ = write!(output, "<th scope=\"col\" abbr=\"{}\">{}</th>", escape(name), escape(short_name));
+		}
+		output.push_str("</tr>\n</thead>\n<tbody>\n");
+
+		for week in grid.rows() {
+			output.push_str("<tr>");
+			if self.week_numbers {
+				let _ = write!(output, "<th scope=\"row\" class=\"week\">{}</th>", week.week_number);
+			}
+			for day in week.days {
+				let classes = self.classes(day.date, day.in_month);
+				if classes.is_empty() {
+					output.push_str("<td>");
+				} else {
+					let _ = write!(output, "<td class=\"{}\">", escape(&classes));
+				}
+				if day.in_month || self.outside_days {
+					let _ = write!(output, "<time datetime=\"{}\">{}</time>", day.date, day.date.day());
+				}
+				output.push_str("</td>");
+			}
+			output.push_str("</tr>\n");
+		}
+		output.push_str("</tbody>\n</table>\n");
+	}
+}
+
+impl Default for HtmlCalendar {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Escape text for use in HTML content and quoted attribute values.
+fn escape(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for c in text.chars() {
+		match c {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&#39;"),
+			c => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Month::*;
+	use crate::util::date;
+	use assert2::assert;
+
+	#[test]
+	fn render_month() {
+		let html = HtmlCalendar::new()
+			.with_today(date(2021, February, 10))
+			.render_month(YearMonth::new(2021, February));
+		assert!(html == concat!(
+			"<table class=\"month\">\n",
+			"<caption>February 2021</caption>\n",
+			"<thead>\n",
+			"<tr><th scope=\"col\" abbr=\"Monday\">Mon</th><th scope=\"col\" abbr=\"Tuesday\">Tue</th><th scope=\"col\" abbr=\"Wednesday\">Wed</th>",
+			"<th scope=\"col\" abbr=\"Thursday\">Thu</th><th scope=\"col\" abbr=\"Friday\">Fri</th><th scope=\"col\" abbr=\"Saturday\">Sat</th>",
+			"<th scope=\"col\" abbr=\"Sunday\">Sun</th></tr>\n",
+			"</thead>\n",
+			"<tbody>\n",
+			"<tr><td><time datetime=\"2021-02-01\">1</time></td><td><time datetime=\"2021-02-02\">2</time></td>",
+			"<td><time datetime=\"2021-02-03\">3</time></td><td><time datetime=\"2021-02-04\">4</time></td>",
+			"<td><time datetime=\"2021-02-05\">5</time></td><td class=\"weekend\"><time datetime=\"2021-02-06\">6</time></td>",
+			"<td class=\"weekend\"><time datetime=\"2021-02-07\">7</time></td></tr>\n",
+			"<tr><td><time datetime=\"2021-02-08\">8</time></td><td><time datetime=\"2021-02-09\">9</time></td>",
+			"<td class=\"today\"><time datetime=\"2021-02-10\">10</time></td><td><time datetime=\"2021-02-11\">11</time></td>",
+			"<td><time datetime=\"2021-02-12\">12</time></td><td class=\"weekend\"><time datetime=\"2021-02-13\">13</time></td>",
+			"<td class=\"weekend\"><time datetime=\"2021-02-14\">14</time></td></tr>\n",
+			"<tr><td><time datetime=\"2021-02-15\">15</time></td><td><time datetime=\"2021-02-16\">16</time></td>",
+			"<td><time datetime=\"2021-02-17\">17</time></td><td><time datetime=\"2021-02-18\">18</time></td>",
+			"<td><time datetime=\"2021-02-19\">19</time></td><td class=\"weekend\"><time datetime=\"2021-02-20\">20</time></td>",
+			"<td class=\"weekend\"><time datetime=\"2021-02-21\">21</time></td></tr>\n",
+			"<tr><td><time datetime=\"2021-02-22\">22</time></td><td><time datetime=\"2021-02-23\">23</time></td>",
+			"<td><time datetime=\"2021-02-24\">24</time></td><td><time datetime=\"2021-02-25\">25</time></td>",
+			"<td><time datetime=\"2021-02-26\">26</time></td><td class=\"weekend\"><time datetime=\"2021-02-27\">27</time></td>",
+			"<td class=\"weekend\"><time datetime=\"2021-02-28\">28</time></td></tr>\n",
+			"</tbody>\n",
+			"</table>\n",
+		));
+	}
+
+	#[test]
+	fn outside_days_and_week_numbers() {
+		let calendar = HtmlCalendar::new().with_week_rules(WeekRules::US).with_week_numbers(true);
+		let html = calendar.render_month(YearMonth::new(2024, September));
+		assert!(html.contains("<tr><th scope=\"col\" class=\"week\"></th><th scope=\"col\" abbr=\"Sunday\">Sun</th>"));
+		assert!(html.contains("<tr><th scope=\"row\" class=\"week\">36</th><td class=\"weekend\"><time datetime=\"2024-09-01\">1</time></td>"));
+		assert!(html.contains("<td><time datetime=\"2024-09-30\">30</time></td><td class=\"outside\"></td>"));
+		assert!(html.ends_with("<td class=\"outside weekend\"></td></tr>\n</tbody>\n</table>\n"));
+
+		let html = calendar.with_outside_days(true).render_month(YearMonth::new(2024, September));
+		assert!(html.contains("<td class=\"outside weekend\"><time datetime=\"2024-10-05\">5</time></td></tr>\n"));
+	}
+
+	#[test]
+	fn date_classes() {
+		let holidays: DateSet = [date(2024, December, 25), date(2024, December, 26)].into_iter().collect();
+		let html = HtmlCalendar::new()
+			.with_date_class("holiday", holidays)
+			.with_classifier(|date| (date.day() == 24).then(|| "eve <b>".to_string()))
+			.render_month(YearMonth::new(2024, December));
+		assert!(html.contains("<td class=\"eve &lt;b&gt;\"><time datetime=\"2024-12-24\">24</time></td>"));
+		assert!(html.contains("<td class=\"holiday\"><time datetime=\"2024-12-25\">25</time></td>"));
+		assert!(html.contains("<td class=\"holiday\"><time datetime=\"2024-12-26\">26</time></td>"));
+	}
+
+	#[test]
+	fn localized_names() {
+		let html = HtmlCalendar::new()
+			.with_month_names([
+				"januari", "februari", "maart", "april", "mei", "juni", "juli", "augustus", "september", "oktober", "november", "december",
+			])
+			.with_weekday_names([
+				("maandag", "ma"),
+				("dinsdag", "di"),
+				("woensdag", "wo"),
+				("donderdag", "do"),
+				("vrijdag", "vr"),
+				("zaterdag", "za"),
+				("zondag", "zo"),
+			])
+			.render_year(Year::new(2024));
+		assert!(html.starts_with("<section class=\"year\">\n<h2>2024</h2>\n<table class=\"month\">\n<caption>januari</caption>\n"));
+		assert!(html.contains("<th scope=\"col\" abbr=\"maandag\">ma</th>"));
+		assert!(html.contains("<caption>december</caption>"));
+		assert!(html.matches("<table").count() == 12);
+		assert!(html.ends_with("</table>\n</section>\n"));
+	}
+}
@@ -0,0 +1,799 @@
+//! Import and export of all-day events in the iCalendar format (RFC 5545).
+//!
+//! A [`Calendar`] can be written as an `.ics` file with its [`Display`](core::fmt::Display) implementation,
+//! and read back with [`FromStr`](core::str::FromStr).
+//! Only all-day events are supported: events with a `DTSTART` that has a time of day are skipped when reading.
+//! iCalendar dates always have four-digit years, so events with dates before the year 0 or after the year 9999 are skipped when writing.
+//! Other components, such as time zones and alarms, are ignored.
+//!
+//! ```
+//! # use gregorian::{Date, Month::*, holidays};
+//! use gregorian::ical::Calendar;
+//! let calendar = Calendar::from_holidays(&holidays::netherlands(), gregorian::Year::new(2024)).with_name("Dutch holidays");
+//! let ics = calendar.to_string();
+//! assert!(ics.contains("DTSTART;VALUE=DATE:20241225\r\n"));
+//!
+//! let parsed: Calendar = ics.parse().unwrap();
+//! assert!(parsed.events[0].summary == "New Year's Day");
+//! assert!(parsed.events[0].start == Date::new(2024, January, 1).unwrap());
+//! ```
+
+use std::collections::HashSet;
+
+use crate::recurrence::parse_date_value;
+use crate::{Date, DateRange, HolidaySet, InvalidICalendar, RecurrenceRule, RecurrenceSet};
+
+const EXPECTED_PROPERTY: &str = "a property like \"NAME;PARAM=VALUE:VALUE\"";
+const EXPECTED_BEGIN_CALENDAR: &str = "\"BEGIN:VCALENDAR\"";
+const EXPECTED_END: &str = "an \"END\" matching the last \"BEGIN\"";
+const EXPECTED_END_CALENDAR: &str = "\"END:VCALENDAR\"";
+const EXPECTED_DTSTART: &str = "a DTSTART property in the event";
+const EXPECTED_DATE: &str = "a date like \"20241225\"";
+const EXPECTED_DATE_LIST: &str = "a list of dates like \"20241225,20241226\"";
+const EXPECTED_DURATION: &str = "a duration in days or weeks like \"P1D\" or \"P2W\"";
+const EXPECTED_RRULE: &str = "a valid recurrence rule";
+
+/// The product identifier written in exported calendars.
+const PRODUCT_ID: &str = "-//gregorian//gregorian-rs//EN";
+
+/// The maximum length of a content line in octets, not counting the line break.
+const MAX_LINE_LENGTH: usize = 75;
+
+/// A calendar with all-day events.
+///
+/// When the calendar is written as an `.ics` file, events with a date that can not be written with a four-digit year are skipped,
+/// since the result could not be read back.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Calendar {
+	/// The display name of the calendar (`X-WR-CALNAME`).
+	pub name: Option<String>,
+
+	/// The events in the calendar.
+	pub events: Vec<Event>,
+}
+
+/// An all-day event (`VEVENT`), possibly recurring.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Event {
+	/// The unique identifier of the event (`UID`).
+	///
+	/// If this is `None`, an identifier is generated from the start date and summary when the event is written.
+	/// When a [`Calendar`] is written, generated identifiers that are already used by another event get a numeric suffix.
+	pub uid: Option<String>,
+
+	/// The summary or title of the event (`SUMMARY`).
+	pub summary: String,
+
+	/// A longer description of the event (`DESCRIPTION`).
+	pub description: Option<String>,
+
+	/// The first day of the event (`DTSTART`).
+	pub start: Date,
+
+	/// The day after the last day of the event (`DTEND`).
+	///
+	/// If this is `None`, the event lasts for one day.
+	pub end: Option<Date>,
+
+	/// The recurrence rules of the event (`RRULE`).
+	pub rules: Vec<RecurrenceRule>,
+
+	/// Extra dates on which the event occurs (`RDATE`).
+	pub dates: Vec<Date>,
+
+	/// Dates on which the event does not occur (`EXDATE`).
+	pub exclusions: Vec<Date>,
+}
+
+impl Calendar {
+	/// Create a new calendar without events.
+	pub const fn new() -> Self {
+		Self { name: None, events: Vec::new() }
+	}
+
+	/// Create a calendar with one event for each holiday in a date range.
+	///
+	/// The events are placed on the date on which the holidays are observed.
+	pub fn from_holidays(holidays: &HolidaySet, range: impl Into<DateRange>) -> Self {
+		holidays
+			.holidays_in(range)
+			.into_iter()
+			.map(|holiday| Event::new(holiday.name, holiday.observed))
+			.collect()
+	}
+
+	/// Create a calendar with one event with the same summary for each date.
+	///
+	/// This can be used to export a list of dates, such as the adjusted dates of a [`Schedule`](crate::Schedule).
+	pub fn from_dates(summary: &str, dates: impl IntoIterator<Item = Date>) -> Self {
+		dates.into_iter().map(|date| Event::new(summary, date)).collect()
+	}
+
+	/// Set the display name of the calendar, returning the modified calendar.
+	pub fn with_name(mut self, name: impl Into<String>) -> Self {
+		self.name = Some(name.into());
+		self
+	}
+
+	/// Add an event to the calendar, returning the modified calendar.
+	pub fn with_event(mut self, event: Event) -> Self {
+		self.events.push(event);
+		self
+	}
+
+	/// Get all occurrences of all events that start in a date range, sorted by date.
+	pub fn occurrences_in(&self, range: impl Into<DateRange>) -> Vec<(Date, &Event)> {
+		let range = range.into();
+		let mut occurrences: Vec<_> = self
+			.events
+			.iter()
+			.flat_map(|event| {
+				event
+					.recurrence()
+					.iter()
+					.take_while(|&date| date < range.end())
+					.filter(|&date| date >= range.start())
+					.map(move |date| (date, event))
+					.collect::<Vec<_>>()
+			})
+			.collect();
+		occurrences.sort_by_key(|&(date, _)| date);
+		occurrences
+	}
+}
+
+impl FromIterator<Event> for Calendar {
+	fn from_iter<I: IntoIterator<Item = Event>>(iter: I) -> Self {
+		Self {
+			name: None,
+			events: iter.into_iter().collect(),
+		}
+	}
+}
+
+impl Extend<Event> for Calendar {
+	fn extend<I: IntoIterator<Item = Event>>(&mut self, iter: I) {
+		self.events.extend(iter)
+	}
+}
+
+impl Event {
+	/// Create a new event of a single day.
+	pub fn new(summary: impl Into<String>, start: Date) -> Self {
+		Self {
+			uid: None,
+			summary: summary.into(),
+			description: None,
+			start,
+			end: None,
+			rules: Vec::new(),
+			dates: Vec::new(),
+			exclusions: Vec::new(),
+		}
+	}
+
+	/// Set the unique identifier, returning the modified event.
+	pub fn with_uid(mut self, uid: impl Into<String>) -> Self {
+		self.uid = Some(uid.into());
+		self
+	}
+
+	/// Set the description, returning the modified event.
+	pub fn with_description(mut self, description: impl Into<String>) -> Self {
+		self.description = Some(description.into());
+		self
+	}
+
+	/// Set the day after the last day of the event, returning the modified event.
+	pub fn with_end(mut self, end: Date) -> Self {
+		self.end = Some(end);
+		self
+	}
+
+	/// Add a recurrence rule, returning the modified event.
+	pub fn with_rule(mut self, rule: RecurrenceRule) -> Self {
+		self.rules.push(rule);
+		self
+	}
+
+	/// Get the days on which the event starts as a [`RecurrenceSet`].
+	pub fn recurrence(&self) -> RecurrenceSet {
+		RecurrenceSet {
+			start: self.start,
+			rules: self.rules.clone(),
+			dates: self.dates.clone(),
+			exclusions: self.exclusions.clone(),
+		}
+	}
+}
+
+/// Format a date as an iCalendar date value, such as `20241225`.
+pub fn format_date(date: Date) -> String {
+	format!("{:04}{:02}{:02}", date.year().to_number(), date.month().to_number(), date.day())
+}
+
+/// Parse an iCalendar date value, such as `20241225`.
+pub fn parse_date(data: &str) -> Option<Date> {
+	if data.len() != 8 {
+		return None;
+	}
+	parse_date_value(data)
+}
+
+impl core::str::FromStr for Calendar {
+	type Err = InvalidICalendar;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		let lines = unfold(data)?;
+		let mut calendar = None;
+		let mut event: Option<PartialEvent> = None;
+		let mut skip_depth = 0;
+
+		for (line_number, line) in &lines {
+			let error = |expected| InvalidICalendar { line: *line_number, expected };
+			let property = ContentLine::parse(line).ok_or_else(|| error(EXPECTED_PROPERTY))?;
+			let calendar = match (&mut calendar, property.name.as_str()) {
+				(None, "BEGIN") if property.value.eq_ignore_ascii_case("VCALENDAR") => {
+					calendar = Some(Calendar::new());
+					continue;
+				},
+				(None, _) => return Err(error(EXPECTED_BEGIN_CALENDAR)),
+				(Some(calendar), _) => calendar,
+			};
+
+			match property.name.as_str() {
+				"BEGIN" if skip_depth > 0 => skip_depth += 1,
+				"END" if skip_depth > 0 => skip_depth -= 1,
+				_ if skip_depth > 0 => (),
+				"BEGIN" if event.is_none() && property.value.eq_ignore_ascii_case("VEVENT") => event = Some(PartialEvent::default()),
+				"BEGIN" => skip_depth = 1,
+				"END" => match event.take() {
+					Some(partial) if property.value.eq_ignore_ascii_case("VEVENT") => {
+						if let Some(event) = partial.finish(*line_number)? {
+							calendar.events.push(event);
+						}
+					},
+					None if property.value.eq_ignore_ascii_case("VCALENDAR") => {
+						let calendar = core::mem::take(calendar);
+						return Ok(calendar);
+					},
+					_ => return Err(error(EXPECTED_END)),
+				},
+				_ => match &mut event {
+					Some(event) => event.add_property(*line_number, property).map_err(error)?,
+					None if property.name == "X-WR-CALNAME" => calendar.name = Some(unescape(property.value)),
+					None => (),
+				},
+			}
+		}
+
+		let line = lines.last().map(|(line, _)| line + 1).unwrap_or(1);
+		match calendar {
+			None => Err(InvalidICalendar { line, expected: EXPECTED_BEGIN_CALENDAR }),
+			Some(_) => Err(InvalidICalendar { line, expected: EXPECTED_END_CALENDAR }),
+		}
+	}
+}
+
+impl core::fmt::Display for Calendar {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write_line(f, "BEGIN:VCALENDAR")?;
+		write_line(f, "VERSION:2.0")?;
+		write_line(f, &format!("PRODID:{}", PRODUCT_ID))?;
+		write_line(f, "CALSCALE:GREGORIAN")?;
+		if let Some(name) = &self.name {
+			write_line(f, &format!("X-WR-CALNAME:{}", escape(name)))?;
+		}
+		// Generated UIDs must be unique, even for events with the same summary on the same day.
+		let events = self.events.iter().filter(|event| event.has_ical_dates());
+		let mut used: HashSet<String> = events.clone().filter_map(|event| event.uid.clone()).collect();
+		for event in events {
+			match &event.uid {
+				Some(uid) => event.write(f, uid)?,
+				None => {
+					let mut uid = event.generated_uid(None);
+					let mut counter = 2;
+					while used.contains(&uid) {
+						uid = event.generated_uid(Some(counter));
+						counter += 1;
+					}
+					event.write(f, &uid)?;
+					used.insert(uid);
+				},
+			}
+		}
+		write_line(f, "END:VCALENDAR")
+	}
+}
+
+impl core::fmt::Display for Event {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match &self.uid {
+			Some(uid) => self.write(f, uid),
+			None => self.write(f, &self.generated_uid(None)),
+		}
+	}
+}
+
+impl Event {
+	/// Check if all dates of the event can be written as iCalendar dates.
+	fn has_ical_dates(&self) -> bool {
+		let is_ical_date = |date: &Date| (0..=9999).contains(&date.year().to_number());
+		core::iter::once(&self.start)
+			.chain(&self.end)
+			.chain(self.rules.iter().filter_map(|rule| rule.until.as_ref()))
+			.chain(&self.dates)
+			.chain(&self.exclusions)
+			.all(is_ical_date)
+	}
+
+	/// Generate a UID from the start date and summary, with an optional counter to make it unique.
+	fn generated_uid(&self, counter: Option<u32>) -> String {
+		let start = format_date(self.start);
+		match counter {
+			Some(counter) => format!("{}-{}-{}@gregorian", start, slug(&self.summary), counter),
+			None => format!("{}-{}@gregorian", start, slug(&self.summary)),
+		}
+	}
+
+	/// Write the event with the given UID.
+	fn write(&self, f: &mut core::fmt::Formatter, uid: &str) -> core::fmt::Result {
+		let start = format_date(self.start);
+		write_line(f, "BEGIN:VEVENT")?;
+		write_line(f, &format!("UID:{}", escape(uid)))?;
+		// The timestamp is derived from the event so that exporting the same events gives the same output.
+		write_line(f, &format!("DTSTAMP:{}T000000Z", start))?;
+		write_line(f, &format!("DTSTART;VALUE=DATE:{}", start))?;
+		if let Some(end) = self.end {
+			write_line(f, &format!("DTEND;VALUE=DATE:{}", format_date(end)))?;
+		}
+		for rule in &self.rules {
+			write_line(f, &format!("RRULE:{}", rule))?;
+		}
+		if !self.dates.is_empty() {
+			write_line(f, &format!("RDATE;VALUE=DATE:{}", format_dates(&self.dates)))?;
+		}
+		if !self.exclusions.is_empty() {
+			write_line(f, &format!("EXDATE;VALUE=DATE:{}", format_dates(&self.exclusions)))?;
+		}
+		write_line(f, &format!("SUMMARY:{}", escape(&self.summary)))?;
+		if let Some(description) = &self.description {
+			write_line(f, &format!("DESCRIPTION:{}", escape(description)))?;
+		}
+		write_line(f, "END:VEVENT")
+	}
+}
+
+/// An event that is being parsed.
+#[derive(Default)]
+struct PartialEvent<'a> {
+	uid: Option<String>,
+	summary: String,
+	description: Option<String>,
+	start: Option<Date>,
+	timed: bool,
+	/// Properties that can only be parsed once we know the event is an all-day event, with their line number.
+	deferred: Vec<(usize, ContentLine<'a>)>,
+}
+
+impl<'a> PartialEvent<'a> {
+	fn add_property(&mut self, line: usize, property: ContentLine<'a>) -> Result<(), &'static str> {
+		match property.name.as_str() {
+			"UID" => self.uid = Some(unescape(property.value)),
+			"SUMMARY" => self.summary = unescape(property.value),
+			"DESCRIPTION" => self.description = Some(unescape(property.value)),
+			"DTSTART" => {
+				self.timed = !property.is_date();
+				self.start = Some(parse_date_value(property.value).ok_or(EXPECTED_DATE)?);
+			},
+			"DTEND" | "DURATION" | "RRULE" | "RDATE" | "EXDATE" => self.deferred.push((line, property)),
+			_ => (),
+		}
+		Ok(())
+	}
+
+	/// Finish the event that ends on the given line.
+	///
+	/// Returns `Ok(None)` if it is not an all-day event.
+	/// The properties of timed events are not checked, since they may use features that all-day events can not.
+	fn finish(self, line: usize) -> Result<Option<Event>, InvalidICalendar> {
+		let start = self.start.ok_or(InvalidICalendar { line, expected: EXPECTED_DTSTART })?;
+		if self.timed {
+			return Ok(None);
+		}
+
+		let mut end = None;
+		let mut duration = None;
+		let mut rules = Vec::new();
+		let mut dates = Vec::new();
+		let mut exclusions = Vec::new();
+		for (line, property) in self.deferred {
+			let error = |expected| InvalidICalendar { line, expected };
+			match property.name.as_str() {
+				"DTEND" => end = Some(parse_date_value(property.value).ok_or_else(|| error(EXPECTED_DATE))?),
+				"DURATION" => duration = Some(parse_duration(property.value).ok_or_else(|| error(EXPECTED_DURATION))?),
+				"RRULE" => rules.push(property.value.parse().map_err(|_| error(EXPECTED_RRULE))?),
+				"RDATE" => dates.extend(parse_dates(property.value).ok_or_else(|| error(EXPECTED_DATE_LIST))?),
+				"EXDATE" => exclusions.extend(parse_dates(property.value).ok_or_else(|| error(EXPECTED_DATE_LIST))?),
+				_ => (),
+			}
+		}
+
+		Ok(Some(Event {
+			uid: self.uid,
+			summary: self.summary,
+			description: self.description,
+			start,
+			end: end.or_else(|| Some(start.add_days(duration?))),
+			rules,
+			dates,
+			exclusions,
+		}))
+	}
+}
+
+/// A single unfolded content line.
+struct ContentLine<'a> {
+	name: String,
+	parameters: Vec<(String, &'a str)>,
+	value: &'a str,
+}
+
+impl<'a> ContentLine<'a> {
+	fn parse(line: &'a str) -> Option<Self> {
+		let name_end = line.find([';', ':'])?;
+		let name = &line[..name_end];
+		if name.is_empty() || !name.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'-') {
+			return None;
+		}
+
+		let mut parameters = Vec::new();
+		let mut rest = &line[name_end..];
+		while let Some(parameter) = rest.strip_prefix(';') {
+			let (key, value) = parameter.split_once('=')?;
+			let (value, tail) = match value.strip_prefix('"') {
+				Some(quoted) => {
+					let end = quoted.find('"')?;
+					(&quoted[..end], &quoted[end + 1..])
+				},
+				None => {
+					let end = value.find([';', ':'])?;
+					(&value[..end], &value[end..])
+				},
+			};
+			parameters.push((key.to_ascii_uppercase(), value));
+			rest = tail;
+		}
+
+		Some(Self {
+			name: name.to_ascii_uppercase(),
+			parameters,
+			value: rest.strip_prefix(':')?,
+		})
+	}
+
+	/// Check if the value is a date rather than a date-time.
+	fn is_date(&self) -> bool {
+		let value_type = self.parameters.iter().find(|(key, _)| key == "VALUE").map(|(_, value)| *value);
+		match value_type {
+			Some(value_type) => value_type.eq_ignore_ascii_case("DATE"),
+			None => !self.value.contains(['T', 't']),
+		}
+	}
+}
+
+/// Split data into unfolded content lines with their line number.
+fn unfold(data: &str) -> Result<Vec<(usize, String)>, InvalidICalendar> {
+	let mut lines: Vec<(usize, String)> = Vec::new();
+	for (index, line) in data.split('\n').enumerate() {
+		let line = line.strip_suffix('\r').unwrap_or(line);
+		if let Some(continuation) = line.strip_prefix([' ', '\t']) {
+			let (_, last) = lines.last_mut().ok_or(InvalidICalendar {
+				line: index + 1,
+				expected: EXPECTED_PROPERTY,
+			})?;
+			last.push_str(continuation);
+		} else if !line.is_empty() {
+			lines.push((index + 1, line.to_owned()));
+		}
+	}
+	Ok(lines)
+}
+
+/// Write a content line, folded to the maximum line length.
+fn write_line(f: &mut core::fmt::Formatter, line: &str) -> core::fmt::Result {
+	let mut rest = line;
+	let mut max = MAX_LINE_LENGTH;
+	while rest.len() > max {
+		let mut split = max;
+		while !rest.is_char_boundary(split) {
+			split -= 1;
+		}
+		write!(f, "{}\r\n ", &rest[..split])?;
+		rest = &rest[split..];
+		// Continuation lines start with a space, which counts towards the length.
+		max = MAX_LINE_LENGTH - 1;
+	}
+	write!(f, "{}\r\n", rest)
+}
+
+/// Escape a text value.
+fn escape(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for c in text.chars() {
+		match c {
+			'\\' => escaped.push_str("\\\\"),
+			';' => escaped.push_str("\\;"),
+			',' => escaped.push_str("\\,"),
+			'\n' => escaped.push_str("\\n"),
+			'\r' => (),
+			c => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+/// Unescape a text value.
+fn unescape(text: &str) -> String {
+	let mut unescaped = String::with_capacity(text.len());
+	let mut chars = text.chars();
+	while let Some(c) = chars.next() {
+		match (c, c == '\\') {
+			(_, true) => match chars.next() {
+				Some('n' | 'N') => unescaped.push('\n'),
+				Some(c) => unescaped.push(c),
+				None => unescaped.push('\\'),
+			},
+			(c, false) => unescaped.push(c),
+		}
+	}
+	unescaped
+}
+
+/// Create a lowercase identifier from a summary, for use in a generated UID.
+fn slug(text: &str) -> String {
+	let mut slug = String::with_capacity(text.len());
+	for c in text.chars() {
+		if c.is_ascii_alphanumeric() {
+			slug.push(c.to_ascii_lowercase());
+		} else if !slug.is_empty() && !slug.ends_with('-') {
+			slug.push('-');
+		}
+	}
+	let len = slug.trim_end_matches('-').len();
+	slug.truncate(len);
+	slug
+}
+
+fn format_dates(dates: &[Date]) -> String {
+	dates.iter().map(|&date| format_date(date)).collect::<Vec<_>>().join(",")
+}
+
+fn parse_dates(data: &str) -> Option<Vec<Date>> {
+	data.split(',').map(parse_date_value).collect()
+}
+
+/// Parse a duration of whole days or weeks, such as `P1D` or `P2W`.
+fn parse_duration(data: &str) -> Option<i32> {
+	let data = data.strip_prefix('+').unwrap_or(data);
+	let data = data.strip_prefix(['P', 'p'])?;
+	let (count, unit) = data.split_at(data.len().checked_sub(1)?);
+	if count.is_empty() || !count.bytes().all(|c| c.is_ascii_digit()) {
+		return None;
+	}
+	let count: i32 = count.parse().ok()?;
+	match unit {
+		"D" | "d" => Some(count),
+		"W" | "w" => count.checked_mul(7),
+		_ => None,
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{holidays, Month::*, Year, YearMonth};
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	#[test]
+	fn write() {
+		let calendar = Calendar::new()
+			.with_name("Test, with comma")
+			.with_event(Event::new("Christmas Day", date(2024, December, 25)).with_end(date(2024, December, 27)))
+			.with_event(
+				Event::new("Stand-up", date(2024, January, 1))
+					.with_uid("standup@example.com")
+					.with_description("Line 1\nLine 2; with \\ backslash")
+					.with_rule("FREQ=WEEKLY;BYDAY=MO".parse().unwrap()),
+			);
+		assert!(calendar.to_string() == [
+			"BEGIN:VCALENDAR",
+			"VERSION:2.0",
+			"PRODID:-//gregorian//gregorian-rs//EN",
+			"CALSCALE:GREGORIAN",
+			"X-WR-CALNAME:Test\\, with comma",
+			"BEGIN:VEVENT",
+			"UID:20241225-christmas-day@gregorian",
+			"DTSTAMP:20241225T000000Z",
+			"DTSTART;VALUE=DATE:20241225",
+			"DTEND;VALUE=DATE:20241227",
+			"SUMMARY:Christmas Day",
+			"END:VEVENT",
+			"BEGIN:VEVENT",
+			"UID:standup@example.com",
+			"DTSTAMP:20240101T000000Z",
+			"DTSTART;VALUE=DATE:20240101",
+			"RRULE:FREQ=WEEKLY;BYDAY=MO",
+			"SUMMARY:Stand-up",
+			"DESCRIPTION:Line 1\\nLine 2\\; with \\\\ backslash",
+			"END:VEVENT",
+			"END:VCALENDAR",
+			"",
+		]
+		.join("\r\n"));
+	}
+
+	#[test]
+	fn fold() {
+		let summary = "é".repeat(50);
+		let ics = Calendar::new().with_event(Event::new(summary.as_str(), date(2024, January, 1))).to_string();
+		for line in ics.split("\r\n") {
+			assert!(line.len() <= MAX_LINE_LENGTH);
+		}
+		let_assert!(Ok(parsed) = ics.parse::<Calendar>());
+		assert!(parsed.events[0].summary == summary);
+	}
+
+	#[test]
+	fn read() {
+		let ics = [
+			"BEGIN:VCALENDAR",
+			"VERSION:2.0",
+			"PRODID:-//Example//EN",
+			"X-WR-CALNAME:Team calendar",
+			"BEGIN:VTIMEZONE",
+			"TZID:Europe/Amsterdam",
+			"BEGIN:STANDARD",
+			"DTSTART:19701025T030000",
+			"END:STANDARD",
+			"END:VTIMEZONE",
+			"BEGIN:VEVENT",
+			"UID:1@example.com",
+			"DTSTART;VALUE=DATE:20240527",
+			"DURATION:P1W",
+			"SUMMARY:Holiday\\, finally",
+			"DESCRIPTION:A long description that was folded",
+			"  over two lines",
+			"BEGIN:VALARM",
+			"ACTION:DISPLAY",
+			"END:VALARM",
+			"END:VEVENT",
+			"BEGIN:VEVENT",
+			"UID:2@example.com",
+			"DTSTART;TZID=\"Europe/Amsterdam\":20240601T090000",
+			"DURATION:PT1H",
+			"RRULE:FREQ=WEEKLY;BYDAY=MO;BYHOUR=9",
+			"EXDATE;TZID=\"Europe/Amsterdam\":20240610T090000",
+			"SUMMARY:Timed meeting",
+			"END:VEVENT",
+			"begin:vevent",
+			"dtstart;value=date:20240101",
+			"rrule:FREQ=MONTHLY;BYMONTHDAY=1",
+			"exdate;value=date:20240301,20240401",
+			"rdate:20240315",
+			"summary:Monthly",
+			"end:vevent",
+			"END:VCALENDAR",
+		]
+		.join("\n");
+		let_assert!(Ok(calendar) = ics.parse::<Calendar>());
+		assert!(calendar.name.as_deref() == Some("Team calendar"));
+		assert!(calendar.events.len() == 2);
+
+		let event = &calendar.events[0];
+		assert!(event.uid.as_deref() == Some("1@example.com"));
+		assert!(event.summary == "Holiday, finally");
+		assert!(event.description.as_deref() == Some("A long description that was folded over two lines"));
+		assert!(event.start == date(2024, May, 27));
+		assert!(event.end == Some(date(2024, June, 3)));
+
+		let event = &calendar.events[1];
+		assert!(event.rules.len() == 1);
+		assert!(event.dates == [date(2024, March, 15)]);
+		assert!(event.exclusions == [date(2024, March, 1), date(2024, April, 1)]);
+
+		let occurrences: Vec<_> = calendar
+			.occurrences_in(YearMonth::new(2024, January).first_day()..YearMonth::new(2024, June).first_day())
+			.into_iter()
+			.map(|(date, event)| (date, event.summary.as_str()))
+			.collect();
+		assert!(occurrences == [
+			(date(2024, January, 1), "Monthly"),
+			(date(2024, February, 1), "Monthly"),
+			(date(2024, March, 15), "Monthly"),
+			(date(2024, May, 1), "Monthly"),
+			(date(2024, May, 27), "Holiday, finally"),
+		]);
+	}
+
+	#[test]
+	fn errors() {
+		let error = |data: &[&str]| data.join("\r\n").parse::<Calendar>().map_err(|e| (e.line, e.expected));
+		assert!(error(&[]) == Err((1, EXPECTED_BEGIN_CALENDAR)));
+		assert!(error(&["BEGIN:VEVENT"]) == Err((1, EXPECTED_BEGIN_CALENDAR)));
+		assert!(error(&["BEGIN:VCALENDAR", "BEGIN:VEVENT", "END:VEVENT"]) == Err((3, EXPECTED_DTSTART)));
+		assert!(error(&["BEGIN:VCALENDAR", "BEGIN:VEVENT", "END:VCALENDAR"]) == Err((3, EXPECTED_END)));
+		assert!(error(&["BEGIN:VCALENDAR", "BEGIN:VEVENT", "DTSTART;VALUE=DATE:2024-01-01"]) == Err((3, EXPECTED_DATE)));
+		let event = |property| {
+			["BEGIN:VCALENDAR", "BEGIN:VEVENT", "DTSTART;VALUE=DATE:20240101", property, "END:VEVENT", "END:VCALENDAR"]
+		};
+		assert!(error(&event("DURATION:PT1H")) == Err((4, EXPECTED_DURATION)));
+		assert!(error(&event("RRULE:FREQ=SECONDLY")) == Err((4, EXPECTED_RRULE)));
+		assert!(error(&event("DTEND;VALUE=DATE:2024-01-02")) == Err((4, EXPECTED_DATE)));
+		assert!(error(&event("EXDATE;VALUE=DATE:20240102,")) == Err((4, EXPECTED_DATE_LIST)));
+		assert!(error(&["BEGIN:VCALENDAR", "no colon"]) == Err((2, EXPECTED_PROPERTY)));
+		assert!(error(&[" folded", "BEGIN:VCALENDAR"]) == Err((1, EXPECTED_PROPERTY)));
+		assert!(error(&["BEGIN:VCALENDAR", "VERSION:2.0"]) == Err((3, EXPECTED_END_CALENDAR)));
+	}
+
+	#[test]
+	fn holidays() {
+		let calendar = Calendar::from_holidays(&holidays::united_states(), Year::new(2021));
+		let_assert!(Ok(parsed) = calendar.to_string().parse::<Calendar>());
+		let independence_day = parsed.events.iter().find(|event| event.summary == "Independence Day");
+		let_assert!(Some(independence_day) = independence_day);
+		assert!(independence_day.start == date(2021, July, 5));
+		assert!(independence_day.uid.as_deref() == Some("20210705-independence-day@gregorian"));
+	}
+
+	#[test]
+	fn dates() {
+		let dates = [date(2024, March, 20), date(2024, June, 20)];
+		let calendar = Calendar::from_dates("Coupon", dates);
+		let_assert!(Ok(parsed) = calendar.to_string().parse::<Calendar>());
+		assert!(parsed.events.iter().map(|event| (event.start, event.summary.as_str())).collect::<Vec<_>>() == [
+			(date(2024, March, 20), "Coupon"),
+			(date(2024, June, 20), "Coupon"),
+		]);
+		assert!(parsed.occurrences_in(Year::new(2024)).into_iter().map(|(date, _)| date).collect::<Vec<_>>() == dates);
+	}
+
+	#[test]
+	fn dates_out_of_range() {
+		let calendar = Calendar::from_dates("Day", [date(-5, January, 1), date(2024, January, 1), date(10000, January, 1)])
+			.with_event(Event::new("Long", date(9999, December, 1)).with_end(date(10000, January, 2)));
+		let_assert!(Ok(parsed) = calendar.to_string().parse::<Calendar>());
+		assert!(parsed.events.iter().map(|event| event.start).collect::<Vec<_>>() == [date(2024, January, 1)]);
+		let_assert!(Ok(parsed) = Calendar::from_dates("Day", [date(0, January, 1), date(9999, December, 31)]).to_string().parse::<Calendar>());
+		assert!(parsed.events.len() == 2);
+	}
+
+	#[test]
+	fn unique_uids() {
+		let day = date(2024, March, 20);
+		let calendar = Calendar::from_dates("Coupon", [day, day, day]).with_event(Event::new("Coupon", day).with_uid("20240320-coupon-2@gregorian"));
+		let_assert!(Ok(parsed) = calendar.to_string().parse::<Calendar>());
+		assert!(parsed.events.iter().map(|event| event.uid.as_deref().unwrap()).collect::<Vec<_>>() == [
+			"20240320-coupon@gregorian",
+			"20240320-coupon-3@gregorian",
+			"20240320-coupon-4@gregorian",
+			"20240320-coupon-2@gregorian",
+		]);
+	}
+
+	#[test]
+	fn date_values() {
+		assert!(format_date(date(2024, January, 5)) == "20240105");
+		assert!(parse_date("20240105") == Some(date(2024, January, 5)));
+		assert!(parse_date("20240105T000000Z") == None);
+		assert!(parse_date("20240230") == None);
+	}
+
+	#[test]
+	fn text() {
+		assert!(escape("a,b;c\\d\ne") == "a\\,b\\;c\\\\d\\ne");
+		assert!(unescape("a\\,b\\;c\\\\d\\ne\\Nf") == "a,b;c\\d\ne\nf");
+		assert!(slug("New Year's Day") == "new-year-s-day");
+	}
+}
@@ -0,0 +1,459 @@
+//! IMM dates, CDS dates and futures contract codes.
+//!
+//! IMM dates are the third Wednesday of March, June, September and December,
+//! used as the standard expiry and settlement dates of many futures and swaps.
+//! CDS dates are the 20th of the same months, used as the standard maturity and coupon dates of credit default swaps.
+//!
+//! Futures contracts are identified by a month letter and the last digits of the year, such as `H5` or `Z24`.
+//! These can be parsed and formatted with [`ImmCode`].
+//!
+//! ```
+//! # use gregorian::{Date, Month::*};
+//! use gregorian::imm;
+//! let date = Date::new(2024, May, 1).unwrap();
+//! assert!(imm::next_imm_date(date) == Date::new(2024, June, 19).unwrap());
+//! assert!(imm::next_cds_date(date) == Date::new(2024, June, 20).unwrap());
+//! ```
+
+use crate::{Date, InvalidImmCode, Month, Period, Weekday, YearMonth};
+
+/// The expiry of a contract on the n-th occurence of a weekday in the contract month.
+///
+/// Contract months are either all months or only March, June, September and December.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ExpiryRule {
+	/// The occurence of the weekday in the month, counting from the end of the month if negative.
+	///
+	/// See [`YearMonth::nth_weekday()`].
+	pub n: i8,
+
+	/// The weekday of the expiry.
+	pub weekday: Weekday,
+
+	/// If true, only March, June, September and December are contract months.
+	pub quarterly: bool,
+}
+
+impl ExpiryRule {
+	/// The IMM dates: the third Wednesday of March, June, September and December.
+	pub const IMM: Self = Self::new(3, Weekday::Wednesday).quarterly();
+
+	/// Create a rule for a monthly expiry on the n-th occurence of a weekday.
+	pub const fn new(n: i8, weekday: Weekday) -> Self {
+		Self { n, weekday, quarterly: false }
+	}
+
+	/// Restrict the contract months to March, June, September and December.
+	pub const fn quarterly(self) -> Self {
+		Self { quarterly: true, ..self }
+	}
+
+	/// Check if a month is a contract month for this rule.
+	pub const fn is_contract_month(self, month: Month) -> bool {
+		!self.quarterly || is_quarter_month(month)
+	}
+
+	/// Get the expiry date in a month.
+	///
+	/// Returns `None` if the month is not a contract month,
+	/// or if the month does not have the requested occurence of the weekday.
+	pub const fn expiry(self, year_month: YearMonth) -> Option<Date> {
+		if !self.is_contract_month(year_month.month()) {
+			return None;
+		}
+		year_month.nth_weekday(self.n, self.weekday)
+	}
+
+	/// Check if a date is an expiry date for this rule.
+	pub fn is_expiry(self, date: Date) -> bool {
+		self.expiry(date.year_month()) == Some(date)
+	}
+
+	/// Get the first expiry date strictly after a date.
+	///
+	/// Returns `None` if `n` is zero or if its magnitude is larger than 5.
+	pub fn next_expiry(self, date: Date) -> Option<Date> {
+		if self.n == 0 || self.n.unsigned_abs() > 5 {
+			return None;
+		}
+		let mut year_month = date.year_month();
+		loop {
+			if let Some(expiry) = self.expiry(year_month) {
+				if expiry > date {
+					return Some(expiry);
+				}
+			}
+			year_month = year_month.next();
+		}
+	}
+
+	/// Get the last expiry date strictly before a date.
+	///
+	/// Returns `None` if `n` is zero or if its magnitude is larger than 5.
+	pub fn prev_expiry(self, date: Date) -> Option<Date> {
+		if self.n == 0 || self.n.unsigned_abs() > 5 {
+			return None;
+		}
+		let mut year_month = date.year_month();
+		loop {
+			if let Some(expiry) = self.expiry(year_month) {
+				if expiry < date {
+					return Some(expiry);
+				}
+			}
+			year_month = year_month.prev();
+		}
+	}
+}
+
+/// Check if a month is March, June, September or December.
+pub const fn is_quarter_month(month: Month) -> bool {
+	matches!(month, Month::March | Month::June | Month::September | Month::December)
+}
+
+/// Get the IMM date of a month: the third Wednesday.
+///
+/// This gives the serial IMM date for months other than March, June, September and December.
+pub const fn imm_date(year_month: YearMonth) -> Date {
+	year_month.first_day().weekday_on_or_after(Weekday::Wednesday).add_days(14)
+}
+
+/// Check if a date is a quarterly IMM date.
+pub fn is_imm_date(date: Date) -> bool {
+	ExpiryRule::IMM.is_expiry(date)
+}
+
+/// Get the first quarterly IMM date strictly after a date.
+pub fn next_imm_date(date: Date) -> Date {
+	let year_month = next_quarter_month(date.year_month());
+	let imm = imm_date(year_month);
+	if imm > date {
+		imm
+	} else {
+		imm_date(next_quarter_month(year_month.next()))
+	}
+}
+
+/// Get the last quarterly IMM date strictly before a date.
+pub fn prev_imm_date(date: Date) -> Date {
+	let year_month = prev_quarter_month(date.year_month());
+	let imm = imm_date(year_month);
+	if imm < date {
+		imm
+	} else {
+		imm_date(prev_quarter_month(year_month.prev()))
+	}
+}
+
+/// Check if a date is a CDS date: the 20th of March, June, September or December.
+pub const fn is_cds_date(date: Date) -> bool {
+	date.day() == 20 && is_quarter_month(date.month())
+}
+
+/// Get the first CDS date strictly after a date.
+pub fn next_cds_date(date: Date) -> Date {
+	let year_month = next_quarter_month(date.year_month());
+	let cds = cds_date(year_month);
+	if cds > date {
+		cds
+	} else {
+		cds_date(next_quarter_month(year_month.next()))
+	}
+}
+
+/// Get the last CDS date strictly before a date.
+pub fn prev_cds_date(date: Date) -> Date {
+	let year_month = prev_quarter_month(date.year_month());
+	let cds = cds_date(year_month);
+	if cds < date {
+		cds
+	} else {
+		cds_date(prev_quarter_month(year_month.prev()))
+	}
+}
+
+/// Get the last semi-annual CDS roll date on or before a date.
+///
+/// Since December 2015, standard CDS contracts roll to a new maturity on the 20th of March and September.
+pub fn cds_roll_date(date: Date) -> Date {
+	let year = date.year();
+	let march = cds_date(year.with_month(Month::March));
+	let september = cds_date(year.with_month(Month::September));
+	if date >= september {
+		september
+	} else if date >= march {
+		march
+	} else {
+		cds_date(year.prev().with_month(Month::September))
+	}
+}
+
+/// Get the maturity of a standard CDS contract traded on a date.
+///
+/// Contracts traded from the 20th of March until the 19th of September mature on the 20th of June,
+/// and contracts traded from the 20th of September until the 19th of March mature on the 20th of December.
+/// The maturity is then moved forward by the tenor, which is normally a whole number of years.
+///
+/// # Panics
+/// This function panics if the maturity is outside of the supported range of years, like [`Date::add_period()`].
+pub fn cds_maturity(trade_date: Date, tenor: Period) -> Date {
+	let maturity = cds_date(cds_roll_date(trade_date).year_month().add_months(3));
+	match maturity.add_period(tenor) {
+		Ok(date) => date,
+		Err(_) => unreachable!(),
+	}
+}
+
+/// Get the CDS date of a month: the 20th.
+const fn cds_date(year_month: YearMonth) -> Date {
+	match year_month.with_day(20) {
+		Ok(date) => date,
+		Err(_) => unreachable!(),
+	}
+}
+
+/// Get the first quarter month on or after a month.
+const fn next_quarter_month(year_month: YearMonth) -> YearMonth {
+	year_month.add_months((3 - year_month.month().to_number() as i32 % 3) % 3)
+}
+
+/// Get the last quarter month on or before a month.
+const fn prev_quarter_month(year_month: YearMonth) -> YearMonth {
+	year_month.sub_months(year_month.month().to_number() as i32 % 3)
+}
+
+/// Get the futures month code of a month, such as `'H'` for March or `'Z'` for December.
+pub const fn month_code(month: Month) -> char {
+	match month {
+		Month::January => 'F',
+		Month::February => 'G',
+		Month::March => 'H',
+		Month::April => 'J',
+		Month::May => 'K',
+		Month::June => 'M',
+		Month::July => 'N',
+		Month::August => 'Q',
+		Month::September => 'U',
+		Month::October => 'V',
+		Month::November => 'X',
+		Month::December => 'Z',
+	}
+}
+
+/// Get the month of a futures month code.
+///
+/// The code is matched case-insensitively.
+pub const fn month_from_code(code: char) -> Option<Month> {
+	match code.to_ascii_uppercase() {
+		'F' => Some(Month::January),
+		'G' => Some(Month::February),
+		'H' => Some(Month::March),
+		'J' => Some(Month::April),
+		'K' => Some(Month::May),
+		'M' => Some(Month::June),
+		'N' => Some(Month::July),
+		'Q' => Some(Month::August),
+		'U' => Some(Month::September),
+		'V' => Some(Month::October),
+		'X' => Some(Month::November),
+		'Z' => Some(Month::December),
+		_ => None,
+	}
+}
+
+/// A futures contract code, such as `H5` or `Z24`.
+///
+/// The code consists of a month letter and the last one or two digits of the year.
+/// Because the year is abbreviated, it has to be resolved relative to a reference month with [`ImmCode::resolve()`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct ImmCode {
+	/// The contract month.
+	pub month: Month,
+
+	/// The last digits of the year.
+	pub year: u8,
+
+	/// The number of year digits in the code: 1 or 2.
+	pub year_digits: u8,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ImmCode {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for ImmCode {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = std::borrow::Cow::<'_, str>::deserialize(deserializer)?;
+		raw.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+impl ImmCode {
+	/// Create the code for a contract month with one or two year digits.
+	///
+	/// A value for `year_digits` other than 1 is treated as 2.
+	pub const fn new(year_month: YearMonth, year_digits: u8) -> Self {
+		let year_digits = if year_digits == 1 { 1 } else { 2 };
+		let modulus = if year_digits == 1 { 10 } else { 100 };
+		Self {
+			month: year_month.month(),
+			year: year_month.year().to_number().rem_euclid(modulus) as u8,
+			year_digits,
+		}
+	}
+
+	/// Get the first contract month on or after `reference` that matches the code.
+	pub fn resolve(self, reference: YearMonth) -> YearMonth {
+		let modulus = if self.year_digits == 1 { 10 } else { 100 };
+		let reference_year = reference.year().to_number();
+		let year = reference_year - reference_year.rem_euclid(modulus) + i16::from(self.year);
+		let year_month = YearMonth::new(year, self.month);
+		if year_month < reference {
+			year_month.add_years(modulus)
+		} else {
+			year_month
+		}
+	}
+}
+
+impl core::str::FromStr for ImmCode {
+	type Err = InvalidImmCode;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		let mut chars = data.chars();
+		let month = chars.next().and_then(month_from_code).ok_or_else(InvalidImmCode::new)?;
+		let digits = chars.as_str();
+		if digits.is_empty() || digits.len() > 2 || !digits.bytes().all(|c| c.is_ascii_digit()) {
+			return Err(InvalidImmCode::new());
+		}
+		Ok(Self {
+			month,
+			year: digits.parse().map_err(|_| InvalidImmCode::new())?,
+			year_digits: digits.len() as u8,
+		})
+	}
+}
+
+impl core::fmt::Display for ImmCode {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "{}{:0width$}", month_code(self.month), self.year, width = usize::from(self.year_digits))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, Year};
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	#[test]
+	fn imm_dates() {
+		assert!(imm_date(YearMonth::new(2024, March)) == date(2024, March, 20));
+		assert!(imm_date(YearMonth::new(2024, June)) == date(2024, June, 19));
+		assert!(imm_date(YearMonth::new(2025, January)) == date(2025, January, 15));
+		assert!(is_imm_date(date(2024, December, 18)));
+		assert!(!is_imm_date(date(2025, January, 15)));
+
+		assert!(next_imm_date(date(2024, January, 1)) == date(2024, March, 20));
+		assert!(next_imm_date(date(2024, March, 19)) == date(2024, March, 20));
+		assert!(next_imm_date(date(2024, March, 20)) == date(2024, June, 19));
+		assert!(next_imm_date(date(2024, December, 20)) == date(2025, March, 19));
+		assert!(prev_imm_date(date(2024, March, 20)) == date(2023, December, 20));
+		assert!(prev_imm_date(date(2024, March, 21)) == date(2024, March, 20));
+		assert!(prev_imm_date(date(2024, May, 1)) == date(2024, March, 20));
+	}
+
+	#[test]
+	fn expiry_rule() {
+		// Monthly equity options expire on the third Friday.
+		let options = ExpiryRule::new(3, Weekday::Friday);
+		assert!(options.next_expiry(date(2024, January, 19)) == Some(date(2024, February, 16)));
+		assert!(options.prev_expiry(date(2024, January, 19)) == Some(date(2023, December, 15)));
+		assert!(options.is_expiry(date(2024, January, 19)));
+
+		let last_thursday = ExpiryRule::new(-1, Weekday::Thursday).quarterly();
+		assert!(last_thursday.next_expiry(date(2024, April, 1)) == Some(date(2024, June, 27)));
+		assert!(last_thursday.expiry(YearMonth::new(2024, April)) == None);
+
+		assert!(ExpiryRule::new(5, Weekday::Monday).next_expiry(date(2024, January, 1)) == Some(date(2024, January, 29)));
+		assert!(ExpiryRule::new(5, Weekday::Monday).next_expiry(date(2024, January, 29)) == Some(date(2024, April, 29)));
+		assert!(ExpiryRule::new(0, Weekday::Monday).next_expiry(date(2024, January, 1)) == None);
+		assert!(ExpiryRule::new(6, Weekday::Monday).prev_expiry(date(2024, January, 1)) == None);
+	}
+
+	#[test]
+	fn cds_dates() {
+		assert!(is_cds_date(date(2024, June, 20)));
+		assert!(!is_cds_date(date(2024, July, 20)));
+		assert!(next_cds_date(date(2024, June, 20)) == date(2024, September, 20));
+		assert!(next_cds_date(date(2024, December, 21)) == date(2025, March, 20));
+		assert!(prev_cds_date(date(2024, June, 20)) == date(2024, March, 20));
+		assert!(prev_cds_date(date(2024, February, 1)) == date(2023, December, 20));
+
+		assert!(cds_roll_date(date(2024, March, 19)) == date(2023, September, 20));
+		assert!(cds_roll_date(date(2024, March, 20)) == date(2024, March, 20));
+		assert!(cds_roll_date(date(2024, December, 1)) == date(2024, September, 20));
+
+		assert!(cds_maturity(date(2024, April, 1), Period::years(5)) == date(2029, June, 20));
+		assert!(cds_maturity(date(2024, October, 1), Period::years(5)) == date(2029, December, 20));
+		assert!(cds_maturity(date(2025, January, 10), Period::years(5)) == date(2029, December, 20));
+	}
+
+	#[test]
+	fn month_codes() {
+		for month in Year::new(2024).months() {
+			assert!(month_from_code(month_code(month.month())) == Some(month.month()));
+		}
+		assert!(month_from_code('z') == Some(December));
+		assert!(month_from_code('A') == None);
+	}
+
+	#[test]
+	fn parse_code() {
+		assert!("H5".parse() == Ok(ImmCode { month: March, year: 5, year_digits: 1 }));
+		assert!("z24".parse() == Ok(ImmCode { month: December, year: 24, year_digits: 2 }));
+		assert!("Z04".parse() == Ok(ImmCode { month: December, year: 4, year_digits: 2 }));
+		assert!(let Err(_) = "".parse::<ImmCode>());
+		assert!(let Err(_) = "H".parse::<ImmCode>());
+		assert!(let Err(_) = "A5".parse::<ImmCode>());
+		assert!(let Err(_) = "H123".parse::<ImmCode>());
+		assert!(let Err(_) = "H+5".parse::<ImmCode>());
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn format_code() {
+		assert!(ImmCode::new(YearMonth::new(2025, March), 1).to_string() == "H5");
+		assert!(ImmCode::new(YearMonth::new(2024, December), 2).to_string() == "Z24");
+		assert!(ImmCode::new(YearMonth::new(2004, December), 2).to_string() == "Z04");
+	}
+
+	#[test]
+	fn resolve_code() {
+		let_assert!(Ok(code) = "H5".parse::<ImmCode>());
+		assert!(code.resolve(YearMonth::new(2024, June)) == YearMonth::new(2025, March));
+		assert!(code.resolve(YearMonth::new(2025, March)) == YearMonth::new(2025, March));
+		assert!(code.resolve(YearMonth::new(2025, April)) == YearMonth::new(2035, March));
+		let_assert!(Ok(code) = "Z24".parse::<ImmCode>());
+		assert!(code.resolve(YearMonth::new(2024, June)) == YearMonth::new(2024, December));
+		assert!(code.resolve(YearMonth::new(2098, January)) == YearMonth::new(2124, December));
+	}
+
+	#[test]
+	fn serde() {
+		#[derive(Debug, serde::Deserialize, serde::Serialize)]
+		struct Container {
+			contract: ImmCode,
+		}
+
+		let_assert!(Ok(serialized) = serde_yaml::to_string(&Container { contract: ImmCode::new(YearMonth::new(2024, June), 2) }));
+		assert!(serialized == "contract: M24\n");
+		let_assert!(Ok(parsed) = serde_yaml::from_str::<Container>("contract: U5"));
+		assert!(parsed.contract == ImmCode { month: September, year: 5, year_digits: 1 });
+	}
+}
@@ -0,0 +1,952 @@
+use crate::date::MIN_YEAR;
+use crate::{Date, DateRange, DateResultExt, InvalidIntervalSyntax, Year};
+
+/// A duration in years, months, weeks and days, as used in ISO 8601 intervals.
+///
+/// A duration can be parsed from and formatted as an ISO 8601 duration, such as `"P3M"`, `"P1W"` or `"P1Y2M10D"`.
+/// Durations with a time component, such as `"PT12H"`, are not supported.
+///
+/// When a duration is added to a date, the years and months are added first.
+/// If that results in an invalid day of the month, the last day of the month is used instead.
+/// The weeks and days are added after that.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct DateDuration {
+	/// The number of years in the duration.
+	pub years: u32,
+
+	/// The number of months in the duration.
+	pub months: u32,
+
+	/// The number of weeks in the duration.
+	pub weeks: u32,
+
+	/// The number of days in the duration.
+	pub days: u32,
+}
+
+impl DateDuration {
+	/// Create a new duration from a number of years, months, weeks and days.
+	pub const fn new(years: u32, months: u32, weeks: u32, days: u32) -> Self {
+		Self { years, months, weeks, days }
+	}
+
+	/// Create a duration of a number of years.
+	pub const fn years(years: u32) -> Self {
+		Self::new(years, 0, 0, 0)
+	}
+
+	/// Create a duration of a number of months.
+	pub const fn months(months: u32) -> Self {
+		Self::new(0, months, 0, 0)
+	}
+
+	/// Create a duration of a number of weeks.
+	pub const fn weeks(weeks: u32) -> Self {
+		Self::new(0, 0, weeks, 0)
+	}
+
+	/// Create a duration of a number of days.
+	pub const fn days(days: u32) -> Self {
+		Self::new(0, 0, 0, days)
+	}
+
+	/// Check if the duration has a length of zero.
+	pub const fn is_zero(self) -> bool {
+		self.years == 0 && self.months == 0 && self.weeks == 0 && self.days == 0
+	}
+
+	/// Add the duration `factor` times to a date.
+	///
+	/// A negative factor subtracts the duration.
+	/// Returns `None` if the result does not fit in a [`Date`].
+	fn shift(self, date: Date, factor: i64) -> Option<Date> {
+		let months = (i64::from(self.years) * 12 + i64::from(self.months)).checked_mul(factor)?;
+		let days = (i64::from(self.weeks) * 7 + i64::from(self.days)).checked_mul(factor)?;
+
+		let month_index = i64::from(date.year().to_number()) * 12 + i64::from(date.month().to_number() - 1) + months;
+		let year = i16::try_from(month_index.div_euclid(12)).ok().filter(|&year| year >= MIN_YEAR)?;
+		let month = crate::Month::new(month_index.rem_euclid(12) as u8 + 1).ok()?;
+		let date = Year::new(year).with_month(month).with_day(date.day()).or_prev_valid();
+
+		let min = Date::new_const(Year::new(MIN_YEAR), crate::Month::January, 1).ok()?;
+		let max = Date::new_const(Year::new(i16::MAX), crate::Month::December, 31).ok()?;
+		let days = i64::from(date.days_since_year_zero()) + days;
+		if days < i64::from(min.days_since_year_zero()) || days > i64::from(max.days_since_year_zero()) {
+			return None;
+		}
+		Some(Date::from_days_since_year_zero(days as i32))
+	}
+}
+
+impl Date {
+	/// Compute a date by adding a duration.
+	///
+	/// If adding the years and months gives an invalid day of the month, the last day of the month is used.
+	///
+	/// # Panics
+	/// This function panics if the result does not fit in a [`Date`].
+	pub fn add_duration(self, duration: DateDuration) -> Self {
+		duration.shift(self, 1).expect("date out of range")
+	}
+
+	/// Compute a date by subtracting a duration.
+	///
+	/// If subtracting the years and months gives an invalid day of the month, the last day of the month is used.
+	///
+	/// # Panics
+	/// This function panics if the result does not fit in a [`Date`].
+	pub fn sub_duration(self, duration: DateDuration) -> Self {
+		duration.shift(self, -1).expect("date out of range")
+	}
+}
+
+/// An interval of dates, possibly unbounded on either side.
+///
+/// An interval can be parsed from and formatted as an ISO 8601 time interval,
+/// such as `"2020-01-01/2020-03-31"`, `"2020-01-01/P3M"` or `"P1W/2020-02-01"`.
+/// In ISO 8601 notation, both the start date and the end date are included in the interval.
+/// A duration describes the number of days in the interval,
+/// so `"2020-01-01/P3M"` is the same interval as `"2020-01-01/2020-03-31"`.
+///
+/// The ISO 8601-2 notation `".."` can be used for an unbounded start or end, as in `"2020-01-01/.."`.
+///
+/// The interval is always formatted as a start and end date.
+///
+/// Intervals can be created as half-open intervals with [`DateInterval::new()`],
+/// or as closed intervals with [`DateInterval::inclusive()`].
+/// Internally, all intervals are stored as half-open intervals.
+///
+/// ```
+/// # use gregorian::{Date, DateInterval};
+/// let interval: DateInterval = "2020-01-01/P3M".parse().unwrap();
+/// assert!(interval.start() == Some(Date::new(2020, 1, 1).unwrap()));
+/// assert!(interval.last() == Some(Date::new(2020, 3, 31).unwrap()));
+/// assert!(interval.to_string() == "2020-01-01/2020-03-31");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DateInterval {
+	start: Option<Date>,
+	end: Option<Date>,
+}
+
+impl DateInterval {
+	/// An interval that contains all dates.
+	pub const UNBOUNDED: Self = Self { start: None, end: None };
+
+	/// Create a new interval from a start date (inclusive) and an end date (exclusive).
+	pub const fn new(start: Date, end: Date) -> Self {
+		Self {
+			start: Some(start),
+			end: Some(end),
+		}
+	}
+
+	/// Create a new interval from a first and last date, both inclusive.
+	///
+	/// # Panics
+	/// This function panics if the last date is the last day of the year [`i16::MAX`].
+	pub const fn inclusive(first: Date, last: Date) -> Self {
+		Self::new(first, last.next())
+	}
+
+	/// Create an interval that starts at a date (inclusive) and has no end.
+	pub const fn starting_at(start: Date) -> Self {
+		Self { start: Some(start), end: None }
+	}
+
+	/// Create an interval that ends before a date (exclusive) and has no start.
+	pub const fn ending_before(end: Date) -> Self {
+		Self { start: None, end: Some(end) }
+	}
+
+	/// Create an interval from a start date (inclusive) and a duration.
+	///
+	/// # Panics
+	/// This function panics if the end of the interval does not fit in a [`Date`].
+	pub fn from_start(start: Date, duration: DateDuration) -> Self {
+		Self::new(start, start.add_duration(duration))
+	}
+
+	/// Create an interval from a duration and a last date (inclusive).
+	///
+	/// # Panics
+	/// This function panics if the start of the interval does not fit in a [`Date`].
+	pub fn from_last(duration: DateDuration, last: Date) -> Self {
+		let end = last.next();
+		Self::new(end.sub_duration(duration), end)
+	}
+
+	/// Get the first date of the interval, or `None` if the interval has no start.
+	pub const fn start(self) -> Option<Date> {
+		self.start
+	}
+
+	/// Get the end date of the interval, or `None` if the interval has no end.
+	///
+	/// The end date itself is not part of the interval.
+	pub const fn end(self) -> Option<Date> {
+		self.end
+	}
+
+	/// Get the last date of the interval, or `None` if the interval has no end.
+	pub const fn last(self) -> Option<Date> {
+		match self.end {
+			Some(end) => Some(end.prev()),
+			None => None,
+		}
+	}
+
+	/// Check if the interval is empty.
+	pub const fn is_empty(self) -> bool {
+		match (self.start, self.end) {
+			(Some(start), Some(end)) => end.days_since_year_zero() <= start.days_since_year_zero(),
+			_ => false,
+		}
+	}
+
+	/// Check if the interval contains a date.
+	pub const fn contains(self, date: Date) -> bool {
+		let date = date.days_since_year_zero();
+		let after_start = match self.start {
+			Some(start) => date >= start.days_since_year_zero(),
+			None => true,
+		};
+		let before_end = match self.end {
+			Some(end) => date < end.days_since_year_zero(),
+			None => true,
+		};
+		after_start && before_end
+	}
+
+	/// Convert the interval to a [`DateRange`], or `None` if the interval is unbounded.
+	pub const fn to_range(self) -> Option<DateRange> {
+		match (self.start, self.end) {
+			(Some(start), Some(end)) => Some(DateRange::new(start, end)),
+			_ => None,
+		}
+	}
+
+	/// Get the number of days in the interval, or `None` if the interval is unbounded.
+	pub const fn len_days(self) -> Option<u32> {
+		match self.to_range() {
+			Some(range) => Some(range.len()),
+			None => None,
+		}
+	}
+
+	/// Check if the interval has at least one date in common with another interval.
+	pub fn overlaps(self, other: Self) -> bool {
+		self.intersection(other).is_some()
+	}
+
+	/// Get the dates that are in both intervals, or `None` if the intervals do not overlap.
+	pub fn intersection(self, other: Self) -> Option<Self> {
+		let intersection = Self {
+			start: later_start(self.start, other.start),
+			end: earlier_end(self.end, other.end),
+		};
+		if intersection.is_empty() {
+			None
+		} else {
+			Some(intersection)
+		}
+	}
+
+	/// Get the smallest interval that contains both intervals.
+	///
+	/// Any gap between the two intervals is included in the span.
+	/// Empty intervals are ignored.
+	pub fn span(self, other: Self) -> Self {
+		if self.is_empty() {
+			other
+		} else if other.is_empty() {
+			self
+		} else {
+			Self {
+				start: earlier_start(self.start, other.start),
+				end: later_end(self.end, other.end),
+			}
+		}
+	}
+
+	/// Get the dates between two intervals, or `None` if the intervals overlap or are adjacent.
+	pub fn gap_between(self, other: Self) -> Option<Self> {
+		if self.is_empty() || other.is_empty() {
+			return None;
+		}
+		let gap = if ends_before(self.end, other.start) {
+			Self {
+				start: self.end,
+				end: other.start,
+			}
+		} else if ends_before(other.end, self.start) {
+			Self {
+				start: other.end,
+				end: self.start,
+			}
+		} else {
+			return None;
+		};
+		if gap.is_empty() {
+			None
+		} else {
+			Some(gap)
+		}
+	}
+
+	/// Check if all dates of another interval are also in this interval.
+	///
+	/// An empty interval is contained in every interval.
+	pub fn contains_interval(self, other: Self) -> bool {
+		other.is_empty() || (later_start(self.start, other.start) == other.start && earlier_end(self.end, other.end) == other.end)
+	}
+
+	/// Split the interval in the dates before a date and the dates on or after it.
+	///
+	/// If the date is not in the interval, one of the returned intervals is empty.
+	pub fn split_at(self, date: Date) -> (Self, Self) {
+		let before = Self {
+			start: self.start,
+			end: earlier_end(self.end, Some(date)),
+		};
+		let after = Self {
+			start: later_start(self.start, Some(date)),
+			end: self.end,
+		};
+		(before, after)
+	}
+}
+
+/// Get the latest of two start dates, where `None` means unbounded.
+fn later_start(a: Option<Date>, b: Option<Date>) -> Option<Date> {
+	match (a, b) {
+		(Some(a), Some(b)) => Some(a.max(b)),
+		(a, None) | (None, a) => a,
+	}
+}
+
+/// Get the earliest of two start dates, where `None` means unbounded.
+fn earlier_start(a: Option<Date>, b: Option<Date>) -> Option<Date> {
+	Some(a?.min(b?))
+}
+
+/// Get the earliest of two end dates, where `None` means unbounded.
+fn earlier_end(a: Option<Date>, b: Option<Date>) -> Option<Date> {
+	match (a, b) {
+		(Some(a), Some(b)) => Some(a.min(b)),
+		(a, None) | (None, a) => a,
+	}
+}
+
+/// Get the latest of two end dates, where `None` means unbounded.
+fn later_end(a: Option<Date>, b: Option<Date>) -> Option<Date> {
+	Some(a?.max(b?))
+}
+
+/// Check if an interval with end date `end` lies entirely before an interval with start date `start`.
+fn ends_before(end: Option<Date>, start: Option<Date>) -> bool {
+	match (end, start) {
+		(Some(end), Some(start)) => end <= start,
+		_ => false,
+	}
+}
+
+impl From<DateRange> for DateInterval {
+	fn from(other: DateRange) -> Self {
+		Self::new(other.start(), other.end())
+	}
+}
+
+/// A repeating interval, such as every month starting at a date.
+///
+/// A repeating interval can be parsed from and formatted as an ISO 8601 repeating interval,
+/// such as `"R5/2020-01-01/P1M"` for five consecutive months starting on 1 January 2020.
+/// The number after the `R` is the number of intervals.
+/// It can be left out (or given as `-1`) for an unbounded number of intervals.
+///
+/// If the repeating interval is anchored at an end date, as in `"R5/P1M/2020-12-31"`,
+/// the intervals repeat backwards in time, and the iterator yields them from the last interval to the first.
+///
+/// Each interval is computed directly from the anchor date, so adding months does not drift:
+/// repeating `"P1M"` from 31 January gives intervals starting on the last day of each month.
+///
+/// ```
+/// # use gregorian::{Date, RepeatingInterval};
+/// let repeat: RepeatingInterval = "R3/2020-01-01/P1M".parse().unwrap();
+/// let starts: Vec<_> = repeat.iter().map(|interval| interval.start().unwrap().to_string()).collect();
+/// assert!(starts == ["2020-01-01", "2020-02-01", "2020-03-01"]);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RepeatingInterval {
+	repetitions: Option<u32>,
+	anchor: RepeatAnchor,
+	duration: DateDuration,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+enum RepeatAnchor {
+	/// The intervals repeat forward from a start date.
+	Start(Date),
+
+	/// The intervals repeat backwards from an end date (exclusive).
+	End(Date),
+}
+
+impl RepeatingInterval {
+	/// Create a repeating interval that repeats forward from a start date.
+	///
+	/// If `repetitions` is `None`, the intervals repeat forever.
+	pub const fn forward(start: Date, duration: DateDuration, repetitions: Option<u32>) -> Self {
+		Self {
+			repetitions,
+			anchor: RepeatAnchor::Start(start),
+			duration,
+		}
+	}
+
+	/// Create a repeating interval that repeats backwards from a last date (inclusive).
+	///
+	/// If `repetitions` is `None`, the intervals repeat forever.
+	///
+	/// # Panics
+	/// This function panics if the last date is the last day of the year [`i16::MAX`].
+	pub const fn backward(duration: DateDuration, last: Date, repetitions: Option<u32>) -> Self {
+		Self {
+			repetitions,
+			anchor: RepeatAnchor::End(last.next()),
+			duration,
+		}
+	}
+
+	/// Get the number of intervals, or `None` if the intervals repeat forever.
+	pub const fn repetitions(self) -> Option<u32> {
+		self.repetitions
+	}
+
+	/// Get the duration of each interval.
+	pub const fn duration(self) -> DateDuration {
+		self.duration
+	}
+
+	/// Check if the intervals repeat backwards from an end date.
+	pub const fn is_backward(self) -> bool {
+		matches!(self.anchor, RepeatAnchor::End(_))
+	}
+
+	/// Get the interval with the given index.
+	///
+	/// For forward repetitions, index `0` is the first interval.
+	/// For backward repetitions, index `0` is the last interval.
+	///
+	/// Returns `None` if the index is past the number of repetitions, or if the interval does not fit in the range of [`Date`].
+	pub fn get(self, index: u32) -> Option<DateInterval> {
+		if self.repetitions.is_some_and(|repetitions| index >= repetitions) {
+			return None;
+		}
+		let index = i64::from(index);
+		match self.anchor {
+			RepeatAnchor::Start(start) => {
+				let first = self.duration.shift(start, index)?;
+				let end = self.duration.shift(start, index + 1)?;
+				Some(DateInterval::new(first, end))
+			},
+			RepeatAnchor::End(end) => {
+				let first = self.duration.shift(end, -index - 1)?;
+				let end = self.duration.shift(end, -index)?;
+				Some(DateInterval::new(first, end))
+			},
+		}
+	}
+
+	/// Iterate over the intervals.
+	pub fn iter(self) -> RepeatingIntervalIter {
+		RepeatingIntervalIter { repeat: self, index: 0 }
+	}
+}
+
+impl IntoIterator for RepeatingInterval {
+	type Item = DateInterval;
+	type IntoIter = RepeatingIntervalIter;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// Iterator over the intervals of a [`RepeatingInterval`].
+#[derive(Clone, Debug)]
+pub struct RepeatingIntervalIter {
+	repeat: RepeatingInterval,
+	index: u32,
+}
+
+impl Iterator for RepeatingIntervalIter {
+	type Item = DateInterval;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let interval = self.repeat.get(self.index)?;
+		self.index = self.index.checked_add(1)?;
+		Some(interval)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DateDuration {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for DateDuration {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = std::borrow::Cow::<'_, str>::deserialize(deserializer)?;
+		raw.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DateInterval {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for DateInterval {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = std::borrow::Cow::<'_, str>::deserialize(deserializer)?;
+		raw.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RepeatingInterval {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for RepeatingInterval {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = std::borrow::Cow::<'_, str>::deserialize(deserializer)?;
+		raw.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+impl core::str::FromStr for DateDuration {
+	type Err = InvalidIntervalSyntax;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		const EXPECTED: &str = "a duration in years, months, weeks and days like \"P3M\" or \"P1Y2M10D\"";
+		let mut rest = data.strip_prefix(['P', 'p']).ok_or(InvalidIntervalSyntax::new(EXPECTED))?;
+		if rest.is_empty() {
+			return Err(InvalidIntervalSyntax::new(EXPECTED));
+		}
+
+		// The designators must appear in this order, each at most once.
+		let mut duration = Self::default();
+		let mut next_unit = 0;
+		while !rest.is_empty() {
+			let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+			if digits == 0 || digits == rest.len() {
+				return Err(InvalidIntervalSyntax::new(EXPECTED));
+			}
+			let count: u32 = rest[..digits].parse().map_err(|_| InvalidIntervalSyntax::new(EXPECTED))?;
+			let unit = match rest.as_bytes()[digits].to_ascii_uppercase() {
+				b'Y' => 0,
+				b'M' => 1,
+				b'W' => 2,
+				b'D' => 3,
+				_ => return Err(InvalidIntervalSyntax::new(EXPECTED)),
+			};
+			if unit < next_unit {
+				return Err(InvalidIntervalSyntax::new(EXPECTED));
+			}
+			match unit {
+				0 => duration.years = count,
+				1 => duration.months = count,
+				2 => duration.weeks = count,
+				_ => duration.days = count,
+			}
+			next_unit = unit + 1;
+			rest = &rest[digits + 1..];
+		}
+		Ok(duration)
+	}
+}
+
+impl core::fmt::Display for DateDuration {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		if self.is_zero() {
+			return write!(f, "P0D");
+		}
+		write!(f, "P")?;
+		for (count, unit) in [(self.years, 'Y'), (self.months, 'M'), (self.weeks, 'W'), (self.days, 'D')] {
+			if count != 0 {
+				write!(f, "{}{}", count, unit)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// One side of an ISO 8601 interval.
+enum Part {
+	Date(Date),
+	Duration(DateDuration),
+	Open,
+}
+
+impl Part {
+	fn parse(data: &str) -> Result<Self, InvalidIntervalSyntax> {
+		if data == ".." {
+			Ok(Self::Open)
+		} else if data.starts_with(['P', 'p']) {
+			Ok(Self::Duration(data.parse()?))
+		} else {
+			let date = data
+				.parse()
+				.map_err(|_| InvalidIntervalSyntax::new("a date like \"2020-01-01\", a duration like \"P3M\" or \"..\""))?;
+			Ok(Self::Date(date))
+		}
+	}
+}
+
+impl core::str::FromStr for DateInterval {
+	type Err = InvalidIntervalSyntax;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		let (start, end) = data
+			.split_once('/')
+			.ok_or(InvalidIntervalSyntax::new("a start and end separated by \"/\""))?;
+		let out_of_range = || InvalidIntervalSyntax::new("a duration that stays within the supported range of years");
+		match (Part::parse(start)?, Part::parse(end)?) {
+			(Part::Date(first), Part::Date(last)) => {
+				let end = end_after(last)?;
+				// An empty interval is formatted with the last date just before the start date, so accept that too.
+				if end < first {
+					return Err(InvalidIntervalSyntax::new("an end date on or after the start date"));
+				}
+				Ok(Self::new(first, end))
+			},
+			(Part::Date(start), Part::Duration(duration)) => {
+				let end = duration.shift(start, 1).ok_or_else(out_of_range)?;
+				Ok(Self::new(start, end))
+			},
+			(Part::Duration(duration), Part::Date(last)) => {
+				let end = end_after(last)?;
+				let start = duration.shift(end, -1).ok_or_else(out_of_range)?;
+				Ok(Self::new(start, end))
+			},
+			(Part::Date(start), Part::Open) => Ok(Self::starting_at(start)),
+			(Part::Open, Part::Date(last)) => Ok(Self::ending_before(end_after(last)?)),
+			(Part::Open, Part::Open) => Ok(Self::UNBOUNDED),
+			(Part::Duration(_), _) | (_, Part::Duration(_)) => Err(InvalidIntervalSyntax::new("at least one date next to a duration")),
+		}
+	}
+}
+
+/// Get the exclusive end date after an inclusive last date, if it is within the supported range of years.
+fn end_after(last: Date) -> Result<Date, InvalidIntervalSyntax> {
+	last.checked_add_days(1)
+		.ok_or(InvalidIntervalSyntax::new("a last date before the end of the supported range of years"))
+}
+
+impl core::fmt::Display for DateInterval {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match self.start {
+			Some(start) => write!(f, "{}", start)?,
+			None => write!(f, "..")?,
+		}
+		match self.last() {
+			Some(last) => write!(f, "/{}", last),
+			None => write!(f, "/.."),
+		}
+	}
+}
+
+impl core::str::FromStr for RepeatingInterval {
+	type Err = InvalidIntervalSyntax;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		let (repetitions, interval) = data
+			.strip_prefix(['R', 'r'])
+			.and_then(|data| data.split_once('/'))
+			.ok_or(InvalidIntervalSyntax::new("a repeating interval like \"R5/2020-01-01/P1M\""))?;
+		let repetitions = match repetitions {
+			"" | "-1" => None,
+			count => {
+				if !count.bytes().all(|c| c.is_ascii_digit()) {
+					return Err(InvalidIntervalSyntax::new("a number of repetitions"));
+				}
+				Some(count.parse().map_err(|_| InvalidIntervalSyntax::new("a number of repetitions"))?)
+			},
+		};
+
+		let (start, end) = interval
+			.split_once('/')
+			.ok_or(InvalidIntervalSyntax::new("a start and end separated by \"/\""))?;
+		match (Part::parse(start)?, Part::parse(end)?) {
+			(Part::Date(start), Part::Duration(duration)) => Ok(Self::forward(start, duration, repetitions)),
+			(Part::Duration(duration), Part::Date(last)) => Ok(Self {
+				repetitions,
+				anchor: RepeatAnchor::End(end_after(last)?),
+				duration,
+			}),
+			(Part::Date(first), Part::Date(last)) => {
+				if last < first {
+					return Err(InvalidIntervalSyntax::new("an end date on or after the start date"));
+				}
+				let days = first.days_since(last) as u32 + 1;
+				Ok(Self::forward(first, DateDuration::days(days), repetitions))
+			},
+			_ => Err(InvalidIntervalSyntax::new("a bounded interval with a start date or end date")),
+		}
+	}
+}
+
+impl core::fmt::Display for RepeatingInterval {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "R")?;
+		if let Some(repetitions) = self.repetitions {
+			write!(f, "{}", repetitions)?;
+		}
+		match self.anchor {
+			RepeatAnchor::Start(start) => write!(f, "/{}/{}", start, self.duration),
+			RepeatAnchor::End(end) => write!(f, "/{}/{}", self.duration, end.prev()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Month::*;
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	#[test]
+	fn parse_duration() {
+		assert!("P3M".parse() == Ok(DateDuration::months(3)));
+		assert!("P1W".parse() == Ok(DateDuration::weeks(1)));
+		assert!("P1Y2M10D".parse() == Ok(DateDuration::new(1, 2, 0, 10)));
+		assert!("p2y".parse() == Ok(DateDuration::years(2)));
+		assert!(let Err(_) = "P".parse::<DateDuration>());
+		assert!(let Err(_) = "3M".parse::<DateDuration>());
+		assert!(let Err(_) = "PM".parse::<DateDuration>());
+		assert!(let Err(_) = "P3".parse::<DateDuration>());
+		assert!(let Err(_) = "P1D2M".parse::<DateDuration>());
+		assert!(let Err(_) = "P1M1M".parse::<DateDuration>());
+		assert!(let Err(_) = "PT12H".parse::<DateDuration>());
+		assert!(let Err(_) = "P-1D".parse::<DateDuration>());
+	}
+
+	#[test]
+	fn format_duration() {
+		assert!(DateDuration::months(3).to_string() == "P3M");
+		assert!(DateDuration::new(1, 2, 0, 10).to_string() == "P1Y2M10D");
+		assert!(DateDuration::default().to_string() == "P0D");
+	}
+
+	#[test]
+	fn add_duration() {
+		assert!(date(2020, January, 31).add_duration(DateDuration::months(1)) == date(2020, February, 29));
+		assert!(date(2020, January, 31).add_duration(DateDuration::new(0, 1, 0, 1)) == date(2020, March, 1));
+		assert!(date(2020, March, 31).sub_duration(DateDuration::months(1)) == date(2020, February, 29));
+		assert!(date(2020, February, 29).add_duration(DateDuration::years(1)) == date(2021, February, 28));
+		assert!(date(2020, January, 1).add_duration(DateDuration::weeks(2)) == date(2020, January, 15));
+	}
+
+	#[test]
+	fn parse_interval() {
+		let_assert!(Ok(interval) = "2020-01-01/2020-03-31".parse::<DateInterval>());
+		assert!(interval == DateInterval::new(date(2020, January, 1), date(2020, April, 1)));
+
+		let_assert!(Ok(interval) = "2020-01-01/P3M".parse::<DateInterval>());
+		assert!(interval == DateInterval::new(date(2020, January, 1), date(2020, April, 1)));
+
+		let_assert!(Ok(interval) = "P1W/2020-02-01".parse::<DateInterval>());
+		assert!(interval == DateInterval::inclusive(date(2020, January, 26), date(2020, February, 1)));
+		assert!(interval.to_range().unwrap().len() == 7);
+
+		let_assert!(Ok(interval) = "2020-01-01/..".parse::<DateInterval>());
+		assert!(interval == DateInterval::starting_at(date(2020, January, 1)));
+		assert!(interval.contains(date(9999, December, 31)));
+		assert!(!interval.contains(date(2019, December, 31)));
+
+		let_assert!(Ok(interval) = "../2020-01-01".parse::<DateInterval>());
+		assert!(interval.last() == Some(date(2020, January, 1)));
+		assert!(interval.start() == None);
+
+		assert!("../..".parse() == Ok(DateInterval::UNBOUNDED));
+
+		assert!(let Err(_) = "2020-01-01".parse::<DateInterval>());
+		assert!(let Err(_) = "P1M/P1D".parse::<DateInterval>());
+		assert!(let Err(_) = "P1M/..".parse::<DateInterval>());
+		assert!(let Err(_) = "2020-01-01/2020-13-01".parse::<DateInterval>());
+		assert!(let Err(_) = "2020-01-01/2020-02-01/P1D".parse::<DateInterval>());
+		assert!(let Err(_) = "2020-03-01/2020-01-01".parse::<DateInterval>());
+		assert!(let Err(_) = "2020-01-01/P4294967295Y".parse::<DateInterval>());
+		assert!(let Err(_) = "P99999Y/2020-01-01".parse::<DateInterval>());
+		assert!(let Err(_) = "2020-01-01/32767-12-31".parse::<DateInterval>());
+		assert!(let Err(_) = "../32767-12-31".parse::<DateInterval>());
+		assert!(let Err(_) = "P1D/32767-12-31".parse::<DateInterval>());
+		assert!("2020-01-01/32767-12-30".parse() == Ok(DateInterval::inclusive(date(2020, January, 1), date(32767, December, 30))));
+
+		// Empty intervals are written with the last date before the start date.
+		let empty = DateInterval::new(date(2020, January, 1), date(2020, January, 1));
+		assert!(empty.to_string().parse() == Ok(empty));
+	}
+
+	#[test]
+	fn format_interval() {
+		assert!(DateInterval::new(date(2020, January, 1), date(2020, April, 1)).to_string() == "2020-01-01/2020-03-31");
+		assert!(DateInterval::starting_at(date(2020, January, 1)).to_string() == "2020-01-01/..");
+		assert!(DateInterval::ending_before(date(2020, January, 1)).to_string() == "../2019-12-31");
+		assert!(DateInterval::UNBOUNDED.to_string() == "../..");
+	}
+
+	#[test]
+	fn overlaps_and_intersection() {
+		let q1 = DateInterval::inclusive(date(2020, January, 1), date(2020, March, 31));
+		let march = DateInterval::inclusive(date(2020, March, 1), date(2020, March, 31));
+		let april = DateInterval::inclusive(date(2020, April, 1), date(2020, April, 30));
+		let from_march = DateInterval::starting_at(date(2020, March, 15));
+
+		assert!(q1.overlaps(march));
+		assert!(!q1.overlaps(april));
+		assert!(q1.overlaps(from_march));
+		assert!(q1.overlaps(DateInterval::UNBOUNDED));
+		assert!(!q1.overlaps(DateInterval::new(date(2020, February, 1), date(2020, February, 1))));
+
+		assert!(q1.intersection(march) == Some(march));
+		assert!(q1.intersection(april) == None);
+		assert!(q1.intersection(from_march) == Some(DateInterval::inclusive(date(2020, March, 15), date(2020, March, 31))));
+		assert!(from_march.intersection(DateInterval::UNBOUNDED) == Some(from_march));
+	}
+
+	#[test]
+	fn span_and_gap() {
+		let january = DateInterval::inclusive(date(2020, January, 1), date(2020, January, 31));
+		let february = DateInterval::inclusive(date(2020, February, 1), date(2020, February, 29));
+		let april = DateInterval::inclusive(date(2020, April, 1), date(2020, April, 30));
+
+		assert!(january.span(april) == DateInterval::inclusive(date(2020, January, 1), date(2020, April, 30)));
+		assert!(april.span(january) == january.span(april));
+		assert!(january.span(DateInterval::ending_before(date(2019, June, 1))) == DateInterval::ending_before(date(2020, February, 1)));
+
+		assert!(january.gap_between(april) == Some(DateInterval::inclusive(date(2020, February, 1), date(2020, March, 31))));
+		assert!(april.gap_between(january) == january.gap_between(april));
+		assert!(january.gap_between(february) == None);
+		assert!(january.gap_between(january.span(february)) == None);
+		assert!(DateInterval::ending_before(date(2020, January, 1)).gap_between(april) == Some(DateInterval::new(date(2020, January, 1), date(2020, April, 1))));
+	}
+
+	#[test]
+	fn contains_interval() {
+		let q1 = DateInterval::inclusive(date(2020, January, 1), date(2020, March, 31));
+		assert!(q1.contains_interval(q1));
+		assert!(q1.contains_interval(DateInterval::inclusive(date(2020, February, 1), date(2020, February, 29))));
+		assert!(!q1.contains_interval(DateInterval::inclusive(date(2020, March, 1), date(2020, April, 1))));
+		assert!(!q1.contains_interval(DateInterval::starting_at(date(2020, February, 1))));
+		assert!(DateInterval::UNBOUNDED.contains_interval(q1));
+		assert!(DateInterval::starting_at(date(2019, January, 1)).contains_interval(q1));
+		assert!(q1.contains_interval(DateInterval::new(date(2021, January, 1), date(2021, January, 1))));
+	}
+
+	#[test]
+	fn split_at() {
+		let q1 = DateInterval::inclusive(date(2020, January, 1), date(2020, March, 31));
+		let (before, after) = q1.split_at(date(2020, February, 1));
+		assert!(before == DateInterval::inclusive(date(2020, January, 1), date(2020, January, 31)));
+		assert!(after == DateInterval::inclusive(date(2020, February, 1), date(2020, March, 31)));
+		assert!(before.len_days() == Some(31));
+		assert!(after.len_days() == Some(60));
+
+		let (before, after) = q1.split_at(date(2020, June, 1));
+		assert!(before == q1);
+		assert!(after.is_empty());
+
+		let (before, after) = DateInterval::UNBOUNDED.split_at(date(2020, June, 1));
+		assert!(before == DateInterval::ending_before(date(2020, June, 1)));
+		assert!(after == DateInterval::starting_at(date(2020, June, 1)));
+		assert!(after.len_days() == None);
+	}
+
+	#[test]
+	fn repeating_interval() {
+		let_assert!(Ok(repeat) = "R5/2020-01-01/P1M".parse::<RepeatingInterval>());
+		assert!(repeat.repetitions() == Some(5));
+		assert!(repeat.to_string() == "R5/2020-01-01/P1M");
+		assert!(repeat.iter().map(|x| x.to_string()).collect::<Vec<_>>() == [
+			"2020-01-01/2020-01-31",
+			"2020-02-01/2020-02-29",
+			"2020-03-01/2020-03-31",
+			"2020-04-01/2020-04-30",
+			"2020-05-01/2020-05-31",
+		]);
+
+		// Months are added to the anchor date, so the day of the month does not drift.
+		let_assert!(Ok(repeat) = "R/2020-01-31/P1M".parse::<RepeatingInterval>());
+		assert!(repeat.repetitions() == None);
+		assert!(repeat.iter().take(3).map(|x| x.start().unwrap()).collect::<Vec<_>>() == [
+			date(2020, January, 31),
+			date(2020, February, 29),
+			date(2020, March, 31),
+		]);
+	}
+
+	#[test]
+	fn repeating_interval_backward() {
+		let_assert!(Ok(repeat) = "R3/P1W/2020-02-01".parse::<RepeatingInterval>());
+		assert!(repeat.is_backward());
+		assert!(repeat.to_string() == "R3/P1W/2020-02-01");
+		assert!(repeat.iter().map(|x| x.to_string()).collect::<Vec<_>>() == [
+			"2020-01-26/2020-02-01",
+			"2020-01-19/2020-01-25",
+			"2020-01-12/2020-01-18",
+		]);
+	}
+
+	#[test]
+	fn repeating_interval_from_dates() {
+		let_assert!(Ok(repeat) = "R2/2020-01-01/2020-01-07".parse::<RepeatingInterval>());
+		assert!(repeat.duration() == DateDuration::days(7));
+		assert!(repeat.to_string() == "R2/2020-01-01/P7D");
+		assert!(repeat.iter().count() == 2);
+		assert!(repeat.get(1) == Some(DateInterval::inclusive(date(2020, January, 8), date(2020, January, 14))));
+		assert!("R-1/2020-01-01/P1D".parse::<RepeatingInterval>().unwrap().repetitions() == None);
+
+		assert!(let Err(_) = "R2/2020-01-07/2020-01-01".parse::<RepeatingInterval>());
+		assert!(let Err(_) = "R2/2020-01-01/..".parse::<RepeatingInterval>());
+		assert!(let Err(_) = "Rx/2020-01-01/P1D".parse::<RepeatingInterval>());
+		assert!(let Err(_) = "2020-01-01/P1D".parse::<RepeatingInterval>());
+		assert!(let Err(_) = "R/P1D/32767-12-31".parse::<RepeatingInterval>());
+	}
+
+	#[test]
+	fn repeating_interval_ends_at_date_limit() {
+		let repeat = RepeatingInterval::forward(date(i16::MAX, October, 1), DateDuration::months(1), None);
+		assert!(repeat.iter().count() == 2);
+	}
+
+	#[test]
+	fn serde() {
+		#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+		struct Config {
+			period: DateInterval,
+			billing: RepeatingInterval,
+			grace: DateDuration,
+		}
+
+		let_assert!(Ok(config) = serde_yaml::from_str::<Config>("period: 2020-01-01/P3M\nbilling: R12/2020-01-01/P1M\ngrace: P2W\n"));
+		assert!(config.period == DateInterval::new(date(2020, January, 1), date(2020, April, 1)));
+		assert!(config.billing == RepeatingInterval::forward(date(2020, January, 1), DateDuration::months(1), Some(12)));
+		assert!(config.grace == DateDuration::weeks(2));
+		let_assert!(Ok(yaml) = serde_yaml::to_string(&config));
+		assert!(yaml == "period: 2020-01-01/2020-03-31\nbilling: R12/2020-01-01/P1M\ngrace: P2W\n");
+		assert!(let Err(_) = serde_yaml::from_str::<Config>("period: 2020-01-01\nbilling: R12/2020-01-01/P1M\ngrace: P2W\n"));
+	}
+}
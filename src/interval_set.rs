@@ -0,0 +1,318 @@
+use crate::{Date, DateInterval};
+
+/// A set of dates, stored as a sorted list of disjoint intervals.
+///
+/// The intervals are kept normalized: they are sorted, non-empty,
+/// and overlapping or adjacent intervals are merged into a single interval.
+/// Two sets with the same dates therefore always have the same intervals.
+///
+/// ```
+/// # use gregorian::{Date, DateInterval, IntervalSet};
+/// let date = |month, day| Date::new(2020, month, day).unwrap();
+/// let booked: IntervalSet = [
+///     DateInterval::inclusive(date(1, 10), date(1, 14)),
+///     DateInterval::inclusive(date(1, 15), date(1, 20)),
+/// ].into_iter().collect();
+/// assert!(booked.intervals() == [DateInterval::inclusive(date(1, 10), date(1, 20))]);
+///
+/// let january = DateInterval::inclusive(date(1, 1), date(1, 31));
+/// let free = IntervalSet::from(january).difference(&booked);
+/// assert!(free.intervals() == [
+///     DateInterval::inclusive(date(1, 1), date(1, 9)),
+///     DateInterval::inclusive(date(1, 21), date(1, 31)),
+/// ]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct IntervalSet {
+	intervals: Vec<DateInterval>,
+}
+
+impl IntervalSet {
+	/// Create a new empty set.
+	pub const fn new() -> Self {
+		Self { intervals: Vec::new() }
+	}
+
+	/// Create a set that contains all dates.
+	pub fn unbounded() -> Self {
+		Self::from(DateInterval::UNBOUNDED)
+	}
+
+	/// Get the intervals of the set, sorted by start date.
+	pub fn intervals(&self) -> &[DateInterval] {
+		&self.intervals
+	}
+
+	/// Iterate over the intervals of the set, sorted by start date.
+	pub fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, DateInterval>> {
+		self.intervals.iter().copied()
+	}
+
+	/// Check if the set is empty.
+	pub fn is_empty(&self) -> bool {
+		self.intervals.is_empty()
+	}
+
+	/// Get the total number of days in the set, or `None` if the set is unbounded.
+	pub fn len_days(&self) -> Option<u64> {
+		self.intervals.iter().map(|interval| interval.len_days().map(u64::from)).sum()
+	}
+
+	/// Get the smallest interval that contains all dates in the set, or `None` if the set is empty.
+	pub fn span(&self) -> Option<DateInterval> {
+		Some(self.intervals.first()?.span(*self.intervals.last()?))
+	}
+
+	/// Check if the set contains a date.
+	pub fn contains(&self, date: Date) -> bool {
+		// Find the last interval that starts on or before the date.
+		let index = self.intervals.partition_point(|interval| interval.start().is_none_or(|start| start <= date));
+		index > 0 && self.intervals[index - 1].contains(date)
+	}
+
+	/// Check if all dates of an interval are in the set.
+	pub fn contains_interval(&self, interval: DateInterval) -> bool {
+		interval.is_empty() || self.intervals.iter().any(|x| x.contains_interval(interval))
+	}
+
+	/// Check if the set has at least one date in common with an interval.
+	pub fn overlaps(&self, interval: DateInterval) -> bool {
+		self.intervals.iter().any(|x| x.overlaps(interval))
+	}
+
+	/// Add all dates of an interval to the set.
+	pub fn insert(&mut self, interval: DateInterval) {
+		self.intervals.push(interval);
+		self.normalize();
+	}
+
+	/// Remove all dates of an interval from the set.
+	pub fn remove(&mut self, interval: DateInterval) {
+		*self = self.difference(&Self::from(interval));
+	}
+
+	/// Get the dates that are in either set.
+	pub fn union(&self, other: &Self) -> Self {
+		self.iter().chain(other.iter()).collect()
+	}
+
+	/// Get the dates that are in both sets.
+	pub fn intersection(&self, other: &Self) -> Self {
+		let mut intervals = Vec::new();
+		let mut a = self.intervals.iter().peekable();
+		let mut b = other.intervals.iter().peekable();
+		while let (Some(&&x), Some(&&y)) = (a.peek(), b.peek()) {
+			intervals.extend(x.intersection(y));
+			// Advance past the interval that ends first, it can not overlap anything else.
+			if ends_first(x, y) {
+				a.next();
+			} else {
+				b.next();
+			}
+		}
+		// The intersection of normalized sets is already normalized.
+		Self { intervals }
+	}
+
+	/// Get the dates that are in this set but not in the other set.
+	pub fn difference(&self, other: &Self) -> Self {
+		self.intersection(&other.complement())
+	}
+
+	/// Get all dates that are not in the set.
+	pub fn complement(&self) -> Self {
+		let (Some(first), Some(last)) = (self.intervals.first(), self.intervals.last()) else {
+			return Self::unbounded();
+		};
+
+		let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+		if let Some(start) = first.start() {
+			intervals.push(DateInterval::ending_before(start));
+		}
+		intervals.extend(self.intervals.windows(2).filter_map(|pair| pair[0].gap_between(pair[1])));
+		if let Some(end) = last.end() {
+			intervals.push(DateInterval::starting_at(end));
+		}
+		Self { intervals }
+	}
+
+	/// Sort the intervals, drop empty intervals and merge overlapping or adjacent intervals.
+	fn normalize(&mut self) {
+		self.intervals.retain(|interval| !interval.is_empty());
+		self.intervals.sort_by_key(|interval| interval.start());
+
+		let mut merged: Vec<DateInterval> = Vec::with_capacity(self.intervals.len());
+		for interval in self.intervals.drain(..) {
+			match merged.last_mut() {
+				Some(last) if touches(*last, interval) => *last = last.span(interval),
+				_ => merged.push(interval),
+			}
+		}
+		self.intervals = merged;
+	}
+}
+
+/// Check if an interval overlaps or is adjacent to a later interval.
+fn touches(earlier: DateInterval, later: DateInterval) -> bool {
+	match (earlier.end(), later.start()) {
+		(Some(end), Some(start)) => start <= end,
+		_ => true,
+	}
+}
+
+/// Check if interval `a` ends on or before interval `b`.
+fn ends_first(a: DateInterval, b: DateInterval) -> bool {
+	match (a.end(), b.end()) {
+		(Some(a), Some(b)) => a <= b,
+		(Some(_), None) => true,
+		(None, _) => false,
+	}
+}
+
+impl From<DateInterval> for IntervalSet {
+	fn from(other: DateInterval) -> Self {
+		core::iter::once(other).collect()
+	}
+}
+
+impl FromIterator<DateInterval> for IntervalSet {
+	fn from_iter<I: IntoIterator<Item = DateInterval>>(iter: I) -> Self {
+		let mut set = Self {
+			intervals: iter.into_iter().collect(),
+		};
+		set.normalize();
+		set
+	}
+}
+
+impl Extend<DateInterval> for IntervalSet {
+	fn extend<I: IntoIterator<Item = DateInterval>>(&mut self, iter: I) {
+		self.intervals.extend(iter);
+		self.normalize();
+	}
+}
+
+impl<'a> IntoIterator for &'a IntervalSet {
+	type Item = DateInterval;
+	type IntoIter = core::iter::Copied<core::slice::Iter<'a, DateInterval>>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Month::*;
+	use crate::util::date;
+	use assert2::assert;
+
+	fn interval(first: Date, last: Date) -> DateInterval {
+		DateInterval::inclusive(first, last)
+	}
+
+	#[test]
+	fn normalize() {
+		let set: IntervalSet = [
+			interval(date(2020, March, 1), date(2020, March, 10)),
+			interval(date(2020, January, 1), date(2020, January, 10)),
+			interval(date(2020, January, 5), date(2020, January, 20)),
+			interval(date(2020, January, 21), date(2020, January, 31)),
+			DateInterval::new(date(2020, February, 1), date(2020, February, 1)),
+		]
+		.into_iter()
+		.collect();
+		assert!(set.intervals() == [
+			interval(date(2020, January, 1), date(2020, January, 31)),
+			interval(date(2020, March, 1), date(2020, March, 10)),
+		]);
+		assert!(set.len_days() == Some(41));
+		assert!(set.span() == Some(interval(date(2020, January, 1), date(2020, March, 10))));
+		assert!(set.contains(date(2020, January, 1)));
+		assert!(set.contains(date(2020, March, 10)));
+		assert!(!set.contains(date(2020, February, 1)));
+		assert!(!set.contains(date(2019, December, 31)));
+		assert!(set.contains_interval(interval(date(2020, January, 10), date(2020, January, 25))));
+		assert!(!set.contains_interval(interval(date(2020, January, 10), date(2020, March, 5))));
+	}
+
+	#[test]
+	fn unbounded_intervals() {
+		let mut set = IntervalSet::new();
+		set.insert(DateInterval::starting_at(date(2020, June, 1)));
+		set.insert(interval(date(2020, January, 1), date(2020, February, 29)));
+		set.insert(DateInterval::ending_before(date(2019, January, 1)));
+		assert!(set.intervals() == [
+			DateInterval::ending_before(date(2019, January, 1)),
+			interval(date(2020, January, 1), date(2020, February, 29)),
+			DateInterval::starting_at(date(2020, June, 1)),
+		]);
+		assert!(set.len_days() == None);
+		assert!(set.contains(date(-2000, January, 1)));
+		assert!(set.contains(date(9999, January, 1)));
+
+		set.insert(interval(date(2019, January, 1), date(2020, July, 1)));
+		assert!(set == IntervalSet::unbounded());
+	}
+
+	#[test]
+	fn complement() {
+		let set: IntervalSet = [
+			interval(date(2020, January, 1), date(2020, January, 31)),
+			interval(date(2020, March, 1), date(2020, March, 31)),
+		]
+		.into_iter()
+		.collect();
+		assert!(set.complement().intervals() == [
+			DateInterval::ending_before(date(2020, January, 1)),
+			interval(date(2020, February, 1), date(2020, February, 29)),
+			DateInterval::starting_at(date(2020, April, 1)),
+		]);
+		assert!(set.complement().complement() == set);
+		assert!(IntervalSet::new().complement() == IntervalSet::unbounded());
+		assert!(IntervalSet::unbounded().complement() == IntervalSet::new());
+	}
+
+	#[test]
+	fn set_algebra() {
+		let a: IntervalSet = [
+			interval(date(2020, January, 1), date(2020, January, 20)),
+			interval(date(2020, February, 1), date(2020, February, 20)),
+		]
+		.into_iter()
+		.collect();
+		let b: IntervalSet = [
+			interval(date(2020, January, 10), date(2020, February, 10)),
+			DateInterval::starting_at(date(2020, March, 1)),
+		]
+		.into_iter()
+		.collect();
+
+		assert!(a.union(&b).intervals() == [
+			interval(date(2020, January, 1), date(2020, February, 20)),
+			DateInterval::starting_at(date(2020, March, 1)),
+		]);
+		assert!(a.intersection(&b).intervals() == [
+			interval(date(2020, January, 10), date(2020, January, 20)),
+			interval(date(2020, February, 1), date(2020, February, 10)),
+		]);
+		assert!(a.difference(&b).intervals() == [
+			interval(date(2020, January, 1), date(2020, January, 9)),
+			interval(date(2020, February, 11), date(2020, February, 20)),
+		]);
+		assert!(b.difference(&a).intervals() == [
+			interval(date(2020, January, 21), date(2020, January, 31)),
+			DateInterval::starting_at(date(2020, March, 1)),
+		]);
+
+		let mut c = a.clone();
+		c.remove(interval(date(2020, January, 5), date(2020, February, 5)));
+		assert!(c.intervals() == [
+			interval(date(2020, January, 1), date(2020, January, 4)),
+			interval(date(2020, February, 6), date(2020, February, 20)),
+		]);
+		assert!(c.overlaps(interval(date(2020, February, 1), date(2020, February, 6))));
+		assert!(!c.overlaps(interval(date(2020, February, 1), date(2020, February, 5))));
+	}
+}
@@ -9,7 +9,11 @@
 //! The [`Date`] type represents a date (year, month and day),
 //! the [`Year`] type represents a calendar year,
 //! the [`Month`] type represents a calendar month,
-//! and the [`YearMonth`] type represents a month of a specific year.
+//! the [`YearMonth`] type represents a month of a specific year,
+//! and the [`Weekday`] type represents a day of the week.
+//!
+//! Week numbers can be computed with [`WeekRules`],
+//! which supports ISO 8601 weeks and other week-numbering systems.
 //!
 //! Where possible, things are implemented as `const fn`.
 //! Currently, this excludes trait implementations and functions that rely on traits.
@@ -48,18 +52,101 @@
 //! assert!(date.add_months(1).or_prev_valid() == Date::new(2020, 2, 29).unwrap());
 //! ```
 
+#[cfg(feature = "std")]
+mod bucket;
+#[cfg(feature = "std")]
+mod business;
+mod cron;
 mod date;
+#[cfg(feature = "std")]
+mod date_map;
+mod date_range;
+#[cfg(feature = "std")]
+mod date_set;
+#[cfg(feature = "std")]
+mod day_count;
+mod easter;
 mod error;
+#[cfg(feature = "holidays")]
+pub mod exchanges;
+#[cfg(feature = "std")]
+mod expr;
 mod ext;
+#[cfg(feature = "std")]
+mod holiday;
+#[cfg(feature = "std")]
+mod html_calendar;
+#[cfg(feature = "std")]
+mod holiday_format;
+#[cfg(feature = "holidays")]
+pub mod holidays;
+#[cfg(feature = "ical")]
+pub mod ical;
+pub mod imm;
+mod interval;
+#[cfg(feature = "std")]
+mod interval_set;
 mod month;
+mod month_grid;
+mod period;
 mod raw;
+#[cfg(feature = "std")]
+mod recurrence;
+#[cfg(feature = "std")]
+mod rotation;
+#[cfg(feature = "std")]
+mod schedule;
+#[cfg(feature = "std")]
+mod text_calendar;
+#[cfg(feature = "std")]
+mod trading;
 mod util;
+mod week;
+mod weekday;
+mod weekday_set;
 mod year;
 mod year_month;
 
+#[cfg(feature = "std")]
+pub use bucket::*;
+#[cfg(feature = "std")]
+pub use business::*;
+pub use cron::*;
 pub use date::*;
+#[cfg(feature = "std")]
+pub use date_map::*;
+pub use date_range::*;
+#[cfg(feature = "std")]
+pub use date_set::*;
+#[cfg(feature = "std")]
+pub use day_count::*;
+pub use easter::*;
 pub use error::*;
+#[cfg(feature = "std")]
+pub use expr::*;
 pub use ext::*;
+#[cfg(feature = "std")]
+pub use holiday::*;
+#[cfg(feature = "std")]
+pub use html_calendar::*;
+pub use interval::*;
+#[cfg(feature = "std")]
+pub use interval_set::*;
 pub use month::*;
+pub use month_grid::*;
+pub use period::*;
+#[cfg(feature = "std")]
+pub use recurrence::*;
+#[cfg(feature = "std")]
+pub use rotation::*;
+#[cfg(feature = "std")]
+pub use schedule::*;
+#[cfg(feature = "std")]
+pub use text_calendar::*;
+#[cfg(feature = "std")]
+pub use trading::*;
+pub use week::*;
+pub use weekday::*;
+pub use weekday_set::*;
 pub use year::*;
 pub use year_month::*;
@@ -1,4 +1,4 @@
-use crate::{InvalidMonthNumber, Year, YearMonth};
+use crate::{InvalidMonthName, InvalidMonthNumber, Year, YearMonth};
 
 /// All months in order as array.
 pub const MONTHS: [Month; 12] = [
@@ -85,6 +85,42 @@ impl Month {
 		}
 	}
 
+	/// Get the full English name of the month, such as `"January"`.
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::January => "January",
+			Self::February => "February",
+			Self::March => "March",
+			Self::April => "April",
+			Self::May => "May",
+			Self::June => "June",
+			Self::July => "July",
+			Self::August => "August",
+			Self::September => "September",
+			Self::October => "October",
+			Self::November => "November",
+			Self::December => "December",
+		}
+	}
+
+	/// Get the abbreviated English name of the month, such as `"Jan"`.
+	pub const fn short_name(self) -> &'static str {
+		match self {
+			Self::January => "Jan",
+			Self::February => "Feb",
+			Self::March => "Mar",
+			Self::April => "Apr",
+			Self::May => "May",
+			Self::June => "Jun",
+			Self::July => "Jul",
+			Self::August => "Aug",
+			Self::September => "Sep",
+			Self::October => "Oct",
+			Self::November => "Nov",
+			Self::December => "Dec",
+		}
+	}
+
 	/// Combine the month with a year to create a [`YearMonth`].
 	pub fn with_year(self, year: impl Into<Year>) -> YearMonth {
 		YearMonth::new(year, self)
@@ -133,6 +169,21 @@ impl From<Month> for u8 {
 	}
 }
 
+impl core::str::FromStr for Month {
+	type Err = InvalidMonthName;
+
+	/// Parse a month from the full or abbreviated English name.
+	///
+	/// The comparison is case insensitive.
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		MONTHS
+			.iter()
+			.copied()
+			.find(|month| data.eq_ignore_ascii_case(month.name()) || data.eq_ignore_ascii_case(month.short_name()))
+			.ok_or_else(InvalidMonthName::new)
+	}
+}
+
 impl PartialEq<u8> for Month {
 	fn eq(&self, other: &u8) -> bool {
 		self.to_number() == *other
@@ -210,6 +261,18 @@ mod test {
 		assert!(December == 12);
 	}
 
+	#[test]
+	fn parse() {
+		for month in MONTHS {
+			assert!(month.name().parse() == Ok(month));
+			assert!(month.short_name().parse() == Ok(month));
+		}
+		assert!("september".parse() == Ok(September));
+		assert!("DEC".parse() == Ok(December));
+		assert!(let Err(_) = "Sept".parse::<Month>());
+		assert!(let Err(_) = "".parse::<Month>());
+	}
+
 	#[test]
 	fn with_year() {
 		assert!(January.with_year(2020) == YearMonth::new(2020, January));
@@ -0,0 +1,207 @@
+use crate::{Date, WeekRules, Weekday, YearMonth};
+
+/// The layout of a month as a grid of weeks, like a wall calendar.
+///
+/// Each row of the grid is one week, starting at the first weekday of the [`WeekRules`].
+/// The first and last row can contain days of the previous and next month to fill up the week.
+/// A month spans four to six rows.
+///
+/// By default, the grid uses [`WeekRules::ISO`]: weeks start on Monday and are numbered by ISO 8601.
+///
+/// ```
+/// # use gregorian::{Date, Month::*, MonthGrid, YearMonth};
+/// let grid = MonthGrid::new(YearMonth::new(2024, February));
+/// assert!(grid.row_count() == 5);
+///
+/// let first = grid.rows().next().unwrap();
+/// assert!(first.week_number == 5);
+/// assert!(first.days[0].date == Date::new(2024, January, 29).unwrap());
+/// assert!(!first.days[0].in_month);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MonthGrid {
+	month: YearMonth,
+	week_rules: WeekRules,
+}
+
+/// A week in a [`MonthGrid`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GridWeek {
+	/// The week number of the week according to the week rules of the grid.
+	pub week_number: u8,
+
+	/// The days of the week, starting at the first weekday.
+	pub days: [GridDay; 7],
+}
+
+/// A day in a [`MonthGrid`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct GridDay {
+	/// The date of the day.
+	pub date: Date,
+
+	/// If the day is in the month of the grid, or if it is a leading or trailing day from an adjacent month.
+	pub in_month: bool,
+}
+
+impl MonthGrid {
+	/// Create the grid for a month, using ISO 8601 weeks.
+	pub const fn new(month: YearMonth) -> Self {
+		Self {
+			month,
+			week_rules: WeekRules::ISO,
+		}
+	}
+
+	/// Set the week rules used for the first weekday and the week numbers, returning the modified grid.
+	pub const fn with_week_rules(mut self, week_rules: WeekRules) -> Self {
+		self.week_rules = week_rules;
+		self
+	}
+
+	/// Set the first weekday of the rows, returning the modified grid.
+	///
+	/// The week numbers still follow the other week rules of the grid.
+	pub const fn with_first_weekday(mut self, first_weekday: Weekday) -> Self {
+		self.week_rules.first_weekday = first_weekday;
+		self
+	}
+
+	/// Get the month of the grid.
+	pub const fn month(self) -> YearMonth {
+		self.month
+	}
+
+	/// Get the week rules of the grid.
+	pub const fn week_rules(self) -> WeekRules {
+		self.week_rules
+	}
+
+	/// Get the weekdays of the columns of the grid, in order.
+	pub const fn weekdays(self) -> [Weekday; 7] {
+		let first = self.week_rules.first_weekday;
+		let mut weekdays = [first; 7];
+		let mut i = 1;
+		while i < 7 {
+			weekdays[i] = first.wrapping_add(i as i8);
+			i += 1;
+		}
+		weekdays
+	}
+
+	/// Get the first date of the grid.
+	///
+	/// This can be a date in the previous month.
+	pub const fn first_date(self) -> Date {
+		self.week_rules.week_start(self.month.first_day())
+	}
+
+	/// Get the last date of the grid.
+	///
+	/// This can be a date in the next month.
+	pub const fn last_date(self) -> Date {
+		self.week_rules.week_end(self.month.last_day())
+	}
+
+	/// Get the number of rows (weeks) in the grid.
+	pub const fn row_count(self) -> usize {
+		(self.first_date().days_since(self.last_date()) as usize + 1) / 7
+	}
+
+	/// Get a row of the grid, or `None` if the index is past the last row.
+	pub const fn row(self, index: usize) -> Option<GridWeek> {
+		if index >= self.row_count() {
+			return None;
+		}
+		let start = self.first_date().add_days(index as i32 * 7);
+		let mut days = [GridDay {
+			date: start,
+			in_month: false,
+		}; 7];
+		let mut i = 0;
+		while i < 7 {
+			let date = start.add_days(i as i32);
+			let month = date.year_month();
+			days[i] = GridDay {
+				date,
+				in_month: month.year().to_number() == self.month.year().to_number()
+					&& month.month().to_number() == self.month.month().to_number(),
+			};
+			i += 1;
+		}
+		Some(GridWeek {
+			week_number: self.week_rules.week_of_year(start),
+			days,
+		})
+	}
+
+	/// Iterate over the rows of the grid.
+	pub fn rows(self) -> impl Iterator<Item = GridWeek> {
+		(0..self.row_count()).filter_map(move |index| self.row(index))
+	}
+
+	/// Get the row and column of a date in the grid, or `None` if the date is not in the grid.
+	pub const fn position(self, date: Date) -> Option<(usize, usize)> {
+		let offset = self.first_date().days_since(date);
+		if offset < 0 || offset as usize >= self.row_count() * 7 {
+			None
+		} else {
+			Some((offset as usize / 7, offset as usize % 7))
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, Weekday::*};
+	use crate::util::date;
+	use assert2::assert;
+
+	#[test]
+	fn row_count() {
+		// February 2021 starts on Monday and has exactly four weeks.
+		assert!(MonthGrid::new(YearMonth::new(2021, February)).row_count() == 4);
+		assert!(MonthGrid::new(YearMonth::new(2021, February)).with_first_weekday(Sunday).row_count() == 5);
+		assert!(MonthGrid::new(YearMonth::new(2024, March)).row_count() == 5);
+		// September 2024 starts on Sunday and ends on Monday.
+		assert!(MonthGrid::new(YearMonth::new(2024, September)).row_count() == 6);
+		assert!(MonthGrid::new(YearMonth::new(2024, September)).with_first_weekday(Sunday).row_count() == 5);
+	}
+
+	#[test]
+	fn rows() {
+		let grid = MonthGrid::new(YearMonth::new(2024, September));
+		assert!(grid.weekdays() == [Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday]);
+		assert!(grid.first_date() == date(2024, August, 26));
+		assert!(grid.last_date() == date(2024, October, 6));
+
+		let rows: Vec<_> = grid.rows().collect();
+		assert!(rows.len() == 6);
+		assert!(rows.iter().map(|row| row.week_number).collect::<Vec<_>>() == [35, 36, 37, 38, 39, 40]);
+		assert!(rows[0].days.iter().filter(|day| day.in_month).count() == 1);
+		assert!(rows[0].days[6] == GridDay { date: date(2024, September, 1), in_month: true });
+		assert!(rows[5].days[0] == GridDay { date: date(2024, September, 30), in_month: true });
+		assert!(rows[5].days[1] == GridDay { date: date(2024, October, 1), in_month: false });
+		assert!(grid.row(6) == None);
+	}
+
+	#[test]
+	fn week_rules() {
+		let grid = MonthGrid::new(YearMonth::new(2025, January)).with_week_rules(WeekRules::US);
+		assert!(grid.weekdays()[0] == Sunday);
+		assert!(grid.first_date() == date(2024, December, 29));
+		assert!(grid.rows().map(|row| row.week_number).collect::<Vec<_>>() == [1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn position() {
+		let grid = MonthGrid::new(YearMonth::new(2024, September));
+		assert!(grid.position(date(2024, August, 26)) == Some((0, 0)));
+		assert!(grid.position(date(2024, September, 1)) == Some((0, 6)));
+		assert!(grid.position(date(2024, September, 18)) == Some((3, 2)));
+		assert!(grid.position(date(2024, October, 6)) == Some((5, 6)));
+		assert!(grid.position(date(2024, October, 7)) == None);
+		assert!(grid.position(date(2024, August, 25)) == None);
+	}
+}
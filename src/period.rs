@@ -0,0 +1,331 @@
+use crate::{Date, InvalidDayOfMonth, InvalidPeriodSyntax};
+
+/// The unit of a [`Period`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PeriodUnit {
+	Days,
+	Weeks,
+	Months,
+	Years,
+}
+
+impl PeriodUnit {
+	/// Get the single letter symbol of the unit: `D`, `W`, `M` or `Y`.
+	pub const fn symbol(self) -> char {
+		match self {
+			Self::Days => 'D',
+			Self::Weeks => 'W',
+			Self::Months => 'M',
+			Self::Years => 'Y',
+		}
+	}
+}
+
+/// A calendar period, such as 3 months or 1 year.
+///
+/// A period can be parsed from and formatted as a number followed by a unit, such as `"3M"` or `"-1Y"`.
+/// The units are `D` for days, `W` for weeks, `M` for months and `Y` for years.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Period {
+	/// The number of units in the period.
+	pub count: i32,
+
+	/// The unit of the period.
+	pub unit: PeriodUnit,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Period {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for Period {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = std::borrow::Cow::<'_, str>::deserialize(deserializer)?;
+		raw.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+impl Period {
+	/// Create a new period from a count and a unit.
+	pub const fn new(count: i32, unit: PeriodUnit) -> Self {
+		Self { count, unit }
+	}
+
+	/// Create a period of a number of days.
+	pub const fn days(count: i32) -> Self {
+		Self::new(count, PeriodUnit::Days)
+	}
+
+	/// Create a period of a number of weeks.
+	pub const fn weeks(count: i32) -> Self {
+		Self::new(count, PeriodUnit::Weeks)
+	}
+
+	/// Create a period of a number of months.
+	pub const fn months(count: i32) -> Self {
+		Self::new(count, PeriodUnit::Months)
+	}
+
+	/// Create a period of a number of years.
+	pub const fn years(count: i32) -> Self {
+		Self::new(count, PeriodUnit::Years)
+	}
+
+	/// Check if the period has a length of zero.
+	pub const fn is_zero(self) -> bool {
+		self.count == 0
+	}
+
+	/// Check if the period is negative.
+	pub const fn is_negative(self) -> bool {
+		self.count < 0
+	}
+
+	/// Get the length of the period in days, if it is a period of days or weeks.
+	///
+	/// Returns `None` for periods of months and years, or if the number of days does not fit in an `i32`.
+	pub const fn to_days(self) -> Option<i32> {
+		match self.unit {
+			PeriodUnit::Days => Some(self.count),
+			PeriodUnit::Weeks => self.count.checked_mul(7),
+			PeriodUnit::Months | PeriodUnit::Years => None,
+		}
+	}
+
+	/// Get the length of the period in months, if it is a period of months or years.
+	///
+	/// Returns `None` for periods of days and weeks, or if the number of months does not fit in an `i32`.
+	pub const fn to_months(self) -> Option<i32> {
+		match self.unit {
+			PeriodUnit::Days | PeriodUnit::Weeks => None,
+			PeriodUnit::Months => Some(self.count),
+			PeriodUnit::Years => self.count.checked_mul(12),
+		}
+	}
+
+	/// Multiply the period by a factor, or return `None` if the count overflows.
+	pub const fn checked_times(self, factor: i32) -> Option<Self> {
+		match self.count.checked_mul(factor) {
+			Some(count) => Some(Self::new(count, self.unit)),
+			None => None,
+		}
+	}
+
+	/// Multiply the period by a factor.
+	///
+	/// # Panics
+	/// This function panics if the count overflows.
+	/// Use [`Self::checked_times()`] to handle overflow.
+	pub const fn times(self, factor: i32) -> Self {
+		match self.checked_times(factor) {
+			Some(period) => period,
+			None => panic!("period count overflow"),
+		}
+	}
+}
+
+impl Date {
+	/// Compute a date by adding a period.
+	///
+	/// Periods of days and weeks always give a valid date.
+	/// Periods of months and years can give an invalid date, just like [`Date::add_months()`].
+	///
+	/// # Panics
+	/// This function panics if the result is outside of the supported range of years.
+	/// Use [`Self::checked_add_period()`] to handle that case.
+	pub const fn add_period(self, period: Period) -> Result<Self, InvalidDayOfMonth> {
+		match self.checked_add_period(period) {
+			Some(date) => date,
+			None => panic!("date out of range"),
+		}
+	}
+
+	/// Compute a date by subtracting a period.
+	///
+	/// Periods of days and weeks always give a valid date.
+	/// Periods of months and years can give an invalid date, just like [`Date::add_months()`].
+	///
+	/// # Panics
+	/// This function panics if the result is outside of the supported range of years.
+	/// Use [`Self::checked_sub_period()`] to handle that case.
+	pub const fn sub_period(self, period: Period) -> Result<Self, InvalidDayOfMonth> {
+		match self.checked_sub_period(period) {
+			Some(date) => date,
+			None => panic!("date out of range"),
+		}
+	}
+
+	/// Compute a date by adding a period, or return `None` if the result is outside of the supported range of years.
+	///
+	/// The supported range is from the year -32400 until the year 32767.
+	/// Periods of months and years can give an invalid date, just like [`Date::add_months()`].
+	pub const fn checked_add_period(self, period: Period) -> Option<Result<Self, InvalidDayOfMonth>> {
+		match period.unit {
+			PeriodUnit::Days | PeriodUnit::Weeks => match period.to_days() {
+				Some(days) => match self.checked_add_days(days) {
+					Some(date) => Some(Ok(date)),
+					None => None,
+				},
+				None => None,
+			},
+			PeriodUnit::Months | PeriodUnit::Years => match period.to_months() {
+				Some(months) => self.checked_add_months(months),
+				None => None,
+			},
+		}
+	}
+
+	/// Compute a date by subtracting a period, or return `None` if the result is outside of the supported range of years.
+	///
+	/// The supported range is from the year -32400 until the year 32767.
+	/// Periods of months and years can give an invalid date, just like [`Date::add_months()`].
+	pub const fn checked_sub_period(self, period: Period) -> Option<Result<Self, InvalidDayOfMonth>> {
+		match period.checked_times(-1) {
+			Some(period) => self.checked_add_period(period),
+			None => None,
+		}
+	}
+}
+
+impl core::ops::Neg for Period {
+	type Output = Self;
+
+	/// Negate the period.
+	///
+	/// Panics if the count overflows, like [`Period::times()`].
+	fn neg(self) -> Self {
+		self.times(-1)
+	}
+}
+
+impl core::ops::Mul<i32> for Period {
+	type Output = Self;
+
+	/// Multiply the period by a factor.
+	///
+	/// Panics if the count overflows, like [`Period::times()`].
+	fn mul(self, other: i32) -> Self {
+		self.times(other)
+	}
+}
+
+impl core::str::FromStr for Period {
+	type Err = InvalidPeriodSyntax;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		let split = data.len().checked_sub(1).ok_or_else(InvalidPeriodSyntax::new)?;
+		if !data.is_char_boundary(split) {
+			return Err(InvalidPeriodSyntax::new());
+		}
+		let (count, unit) = data.split_at(split);
+		let unit = match unit {
+			"D" | "d" => PeriodUnit::Days,
+			"W" | "w" => PeriodUnit::Weeks,
+			"M" | "m" => PeriodUnit::Months,
+			"Y" | "y" => PeriodUnit::Years,
+			_ => return Err(InvalidPeriodSyntax::new()),
+		};
+		let digits = count.strip_prefix(['-', '+']).unwrap_or(count);
+		if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+			return Err(InvalidPeriodSyntax::new());
+		}
+		let count = count.parse().map_err(|_| InvalidPeriodSyntax::new())?;
+		Ok(Self::new(count, unit))
+	}
+}
+
+impl core::fmt::Display for Period {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "{}{}", self.count, self.unit.symbol())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{DateResultExt, Month::*};
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	#[test]
+	fn add_period() {
+		let start = date(2024, January, 31);
+		assert!(start.add_period(Period::days(1)) == Ok(date(2024, February, 1)));
+		assert!(start.add_period(Period::weeks(-2)) == Ok(date(2024, January, 17)));
+		assert!(start.add_period(Period::months(1)).or_prev_valid() == date(2024, February, 29));
+		assert!(start.add_period(Period::months(2)) == Ok(date(2024, March, 31)));
+		assert!(start.add_period(Period::years(1)) == Ok(date(2025, January, 31)));
+		assert!(start.sub_period(Period::months(2)).or_prev_valid() == date(2023, November, 30));
+		assert!(start.sub_period(Period::years(1)) == Ok(date(2023, January, 31)));
+		assert!(date(2024, February, 29).add_period(Period::years(1)).or_next_valid() == date(2025, March, 1));
+	}
+
+	#[test]
+	fn checked_add_period() {
+		let start = date(2020, January, 1);
+		assert!(start.checked_add_period(Period::years(1)) == Some(Ok(date(2021, January, 1))));
+		assert!(start.checked_add_period(Period::years(30747)) == Some(Ok(date(32767, January, 1))));
+		assert!(start.checked_add_period(Period::years(30748)) == None);
+		assert!(start.checked_add_period(Period::years(-34420)) == Some(Ok(date(-32400, January, 1))));
+		assert!(start.checked_add_period(Period::years(-34421)) == None);
+		assert!(start.checked_add_period(Period::years(999_999_999)) == None);
+		assert!(start.checked_add_period(Period::months(i32::MIN)) == None);
+		assert!(start.checked_add_period(Period::weeks(i32::MAX)) == None);
+		assert!(start.checked_add_period(Period::days(i32::MAX)) == None);
+		assert!(start.checked_add_period(Period::days(-366)) == Some(Ok(date(2018, December, 31))));
+		assert!(start.checked_sub_period(Period::days(i32::MIN)) == None);
+	}
+
+	#[test]
+	fn conversions() {
+		assert!(Period::weeks(2).to_days() == Some(14));
+		assert!(Period::weeks(2).to_months() == None);
+		assert!(Period::years(2).to_months() == Some(24));
+		assert!(Period::years(i32::MAX).to_months() == None);
+		assert!(Period::weeks(i32::MIN).to_days() == None);
+		assert!(Period::months(i32::MAX).checked_times(2) == None);
+		assert!(Period::months(3).checked_times(-2) == Some(Period::months(-6)));
+		assert!(Period::months(3) * 4 == Period::months(12));
+		assert!(-Period::months(3) == Period::months(-3));
+		assert!(Period::months(-3).is_negative());
+		assert!(Period::days(0).is_zero());
+	}
+
+	#[test]
+	fn parse() {
+		assert!("3M".parse() == Ok(Period::months(3)));
+		assert!("1y".parse() == Ok(Period::years(1)));
+		assert!("-2W".parse() == Ok(Period::weeks(-2)));
+		assert!("+10D".parse() == Ok(Period::days(10)));
+		assert!(let Err(_) = "".parse::<Period>());
+		assert!(let Err(_) = "M".parse::<Period>());
+		assert!(let Err(_) = "-M".parse::<Period>());
+		assert!(let Err(_) = "3 M".parse::<Period>());
+		assert!(let Err(_) = "3Q".parse::<Period>());
+		assert!(let Err(_) = "3é".parse::<Period>());
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn format() {
+		assert!(Period::months(3).to_string() == "3M");
+		assert!(Period::years(-1).to_string() == "-1Y");
+	}
+
+	#[test]
+	fn serde() {
+		#[derive(Debug, serde::Deserialize, serde::Serialize)]
+		struct Container {
+			period: Period,
+		}
+
+		let_assert!(Ok(serialized) = serde_yaml::to_string(&Container { period: Period::months(6) }));
+		assert!(serialized == "period: 6M\n");
+		let_assert!(Ok(parsed) = serde_yaml::from_str::<Container>("period: 1Y"));
+		assert!(parsed.period == Period::years(1));
+	}
+}
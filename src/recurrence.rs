@@ -0,0 +1,1001 @@
+use crate::{Date, InvalidRecurrenceRule, Month, WeekRules, Weekday, Year};
+
+const EXPECTED_PART: &str = "a rule part like \"FREQ=WEEKLY\"";
+const EXPECTED_KNOWN_PART: &str = "one of FREQ, UNTIL, COUNT, INTERVAL, BYMONTH, BYWEEKNO, BYYEARDAY, BYMONTHDAY, BYDAY, BYSETPOS or WKST";
+const EXPECTED_UNIQUE_PART: &str = "each rule part at most once";
+const EXPECTED_FREQ: &str = "a FREQ part";
+const EXPECTED_FREQUENCY: &str = "a frequency of DAILY, WEEKLY, MONTHLY or YEARLY";
+const EXPECTED_UNTIL: &str = "an UNTIL date like \"20241231\" or \"20241231T000000Z\"";
+const EXPECTED_COUNT: &str = "a COUNT that is a non-negative number";
+const EXPECTED_COUNT_OR_UNTIL: &str = "either COUNT or UNTIL, not both";
+const EXPECTED_INTERVAL: &str = "an INTERVAL that is a positive number";
+const EXPECTED_MONTH: &str = "a BYMONTH list of months from 1 to 12";
+const EXPECTED_WEEK_NO: &str = "a BYWEEKNO list of week numbers from 1 to 53 or -53 to -1";
+const EXPECTED_YEAR_DAY: &str = "a BYYEARDAY list of days from 1 to 366 or -366 to -1";
+const EXPECTED_MONTH_DAY: &str = "a BYMONTHDAY list of days from 1 to 31 or -31 to -1";
+const EXPECTED_DAY: &str = "a BYDAY list of weekdays like \"MO\", \"2TU\" or \"-1FR\"";
+const EXPECTED_SET_POS: &str = "a BYSETPOS list of positions from 1 to 366 or -366 to -1";
+const EXPECTED_WEEK_START: &str = "a WKST weekday like \"MO\" or \"SU\"";
+
+/// The last date that a recurrence rule can produce.
+///
+/// Iteration stops after this date, so rules that never match do not loop forever.
+const LAST_DATE: Date = match Date::new_const(Year::new(9999), Month::December, 31) {
+	Ok(date) => date,
+	Err(_) => unreachable!(),
+};
+
+/// The frequency of a recurrence rule.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Frequency {
+	Daily,
+	Weekly,
+	Monthly,
+	Yearly,
+}
+
+impl Frequency {
+	/// Get the name of the frequency as used in a recurrence rule, such as `"WEEKLY"`.
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Daily => "DAILY",
+			Self::Weekly => "WEEKLY",
+			Self::Monthly => "MONTHLY",
+			Self::Yearly => "YEARLY",
+		}
+	}
+}
+
+/// A weekday with an optional occurence within the month or year, as used in `BYDAY`.
+///
+/// For example, `MO` is every Monday, `2TU` is the second Tuesday and `-1FR` is the last Friday.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct WeekdayNum {
+	/// The occurence of the weekday, counting from the end if negative.
+	///
+	/// The occurence only applies to monthly and yearly rules, and is ignored otherwise.
+	pub n: Option<i8>,
+
+	/// The weekday.
+	pub weekday: Weekday,
+}
+
+impl WeekdayNum {
+	/// Match every occurence of a weekday.
+	pub const fn every(weekday: Weekday) -> Self {
+		Self { n: None, weekday }
+	}
+
+	/// Match the n-th occurence of a weekday.
+	pub const fn nth(n: i8, weekday: Weekday) -> Self {
+		Self { n: Some(n), weekday }
+	}
+}
+
+/// A recurrence rule as defined by RFC 5545, evaluated on dates.
+///
+/// The rule can be parsed from and formatted as an `RRULE` value,
+/// such as `"FREQ=MONTHLY;COUNT=10;BYDAY=1FR"`.
+/// The time parts `BYHOUR`, `BYMINUTE` and `BYSECOND` are not supported.
+///
+/// Dates are generated by [`RecurrenceRule::iter()`], starting from a given date (the `DTSTART` of the rule).
+/// The start date is only produced if it matches the rule.
+/// Use [`RecurrenceSet`] to always include the start date and to add or exclude individual dates.
+///
+/// ```
+/// # use gregorian::{Date, RecurrenceRule};
+/// let rule: RecurrenceRule = "FREQ=MONTHLY;COUNT=3;BYDAY=-1FR".parse().unwrap();
+/// let dates: Vec<_> = rule.iter(Date::new(2024, 1, 1).unwrap()).collect();
+/// assert!(dates == [
+///   Date::new(2024, 1, 26).unwrap(),
+///   Date::new(2024, 2, 23).unwrap(),
+///   Date::new(2024, 3, 29).unwrap(),
+/// ]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RecurrenceRule {
+	/// The frequency of the rule (`FREQ`).
+	pub frequency: Frequency,
+
+	/// The number of periods between each recurrence (`INTERVAL`).
+	pub interval: u32,
+
+	/// The maximum number of dates to produce (`COUNT`).
+	pub count: Option<u32>,
+
+	/// The last date that may be produced (`UNTIL`).
+	pub until: Option<Date>,
+
+	/// The months to limit or expand the recurrence to (`BYMONTH`).
+	pub by_month: Vec<Month>,
+
+	/// The ISO 8601 week numbers to expand the recurrence to (`BYWEEKNO`), counting from the end if negative.
+	pub by_week_no: Vec<i8>,
+
+	/// The days of the year to limit or expand the recurrence to (`BYYEARDAY`), counting from the end if negative.
+	pub by_year_day: Vec<i16>,
+
+	/// The days of the month to limit or expand the recurrence to (`BYMONTHDAY`), counting from the end if negative.
+	pub by_month_day: Vec<i8>,
+
+	/// The weekdays to limit or expand the recurrence to (`BYDAY`).
+	pub by_day: Vec<WeekdayNum>,
+
+	/// The positions within each period to keep (`BYSETPOS`), counting from the end if negative.
+	pub by_set_pos: Vec<i16>,
+
+	/// The first day of the week (`WKST`).
+	///
+	/// This affects weekly rules with an interval and `BYWEEKNO`.
+	pub week_start: Weekday,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RecurrenceRule {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for RecurrenceRule {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = std::borrow::Cow::<'_, str>::deserialize(deserializer)?;
+		raw.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+impl RecurrenceRule {
+	/// Create a new rule with the given frequency, an interval of 1 and no limits.
+	pub const fn new(frequency: Frequency) -> Self {
+		Self {
+			frequency,
+			interval: 1,
+			count: None,
+			until: None,
+			by_month: Vec::new(),
+			by_week_no: Vec::new(),
+			by_year_day: Vec::new(),
+			by_month_day: Vec::new(),
+			by_day: Vec::new(),
+			by_set_pos: Vec::new(),
+			week_start: Weekday::Monday,
+		}
+	}
+
+	/// Iterate over the dates produced by the rule, starting at `start`.
+	pub fn iter(&self, start: Date) -> RecurrenceIter<'_> {
+		RecurrenceIter::new(self, start)
+	}
+}
+
+impl core::str::FromStr for RecurrenceRule {
+	type Err = InvalidRecurrenceRule;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		let data = match data.get(..6) {
+			Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &data[6..],
+			_ => data,
+		};
+
+		let mut frequency = None;
+		let mut rule = Self::new(Frequency::Daily);
+		let mut seen = Vec::new();
+		for part in data.split(';') {
+			let (name, value) = part.split_once('=').ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_PART))?;
+			let name = name.to_ascii_uppercase();
+			if seen.contains(&name) {
+				return Err(InvalidRecurrenceRule::new(EXPECTED_UNIQUE_PART));
+			}
+			match name.as_str() {
+				"FREQ" => frequency = Some(parse_frequency(value).ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_FREQUENCY))?),
+				"UNTIL" => rule.until = Some(parse_date_value(value).ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_UNTIL))?),
+				"COUNT" => rule.count = Some(parse_number(value).ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_COUNT))?),
+				"INTERVAL" => {
+					rule.interval = parse_number(value)
+						.filter(|&interval| interval > 0)
+						.ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_INTERVAL))?
+				},
+				"BYMONTH" => {
+					rule.by_month = parse_list(value, |x| Month::new(parse_number(x)?).ok()).ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_MONTH))?
+				},
+				"BYWEEKNO" => rule.by_week_no = parse_list(value, |x| parse_offset(x, 53)).ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_WEEK_NO))?,
+				"BYYEARDAY" => rule.by_year_day = parse_list(value, |x| parse_offset(x, 366)).ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_YEAR_DAY))?,
+				"BYMONTHDAY" => rule.by_month_day = parse_list(value, |x| parse_offset(x, 31)).ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_MONTH_DAY))?,
+				"BYDAY" => rule.by_day = parse_list(value, parse_weekday_num).ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_DAY))?,
+				"BYSETPOS" => rule.by_set_pos = parse_list(value, |x| parse_offset(x, 366)).ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_SET_POS))?,
+				"WKST" => rule.week_start = parse_weekday(value).ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_WEEK_START))?,
+				_ => return Err(InvalidRecurrenceRule::new(EXPECTED_KNOWN_PART)),
+			}
+			seen.push(name);
+		}
+
+		if rule.count.is_some() && rule.until.is_some() {
+			return Err(InvalidRecurrenceRule::new(EXPECTED_COUNT_OR_UNTIL));
+		}
+		rule.frequency = frequency.ok_or_else(|| InvalidRecurrenceRule::new(EXPECTED_FREQ))?;
+		Ok(rule)
+	}
+}
+
+impl core::fmt::Display for RecurrenceRule {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "FREQ={}", self.frequency.name())?;
+		if let Some(until) = self.until {
+			write!(f, ";UNTIL={:04}{:02}{:02}", until.year().to_number(), until.month().to_number(), until.day())?;
+		}
+		if let Some(count) = self.count {
+			write!(f, ";COUNT={}", count)?;
+		}
+		if self.interval != 1 {
+			write!(f, ";INTERVAL={}", self.interval)?;
+		}
+		write_list(f, "BYMONTH", &self.by_month, |f, month| write!(f, "{}", month.to_number()))?;
+		write_list(f, "BYWEEKNO", &self.by_week_no, |f, week| write!(f, "{}", week))?;
+		write_list(f, "BYYEARDAY", &self.by_year_day, |f, day| write!(f, "{}", day))?;
+		write_list(f, "BYMONTHDAY", &self.by_month_day, |f, day| write!(f, "{}", day))?;
+		write_list(f, "BYDAY", &self.by_day, |f, day| {
+			if let Some(n) = day.n {
+				write!(f, "{}", n)?;
+			}
+			write!(f, "{}", weekday_code(day.weekday))
+		})?;
+		write_list(f, "BYSETPOS", &self.by_set_pos, |f, pos| write!(f, "{}", pos))?;
+		if self.week_start != Weekday::Monday {
+			write!(f, ";WKST={}", weekday_code(self.week_start))?;
+		}
+		Ok(())
+	}
+}
+
+/// Iterator over the dates produced by a [`RecurrenceRule`].
+///
+/// Created with [`RecurrenceRule::iter()`].
+#[derive(Clone, Debug)]
+pub struct RecurrenceIter<'a> {
+	rule: &'a RecurrenceRule,
+	start: Date,
+	filter: Filter<'a>,
+	period: Option<Date>,
+	candidates: Vec<Date>,
+	index: usize,
+	produced: u32,
+}
+
+impl<'a> RecurrenceIter<'a> {
+	fn new(rule: &'a RecurrenceRule, start: Date) -> Self {
+		let period = match rule.frequency {
+			Frequency::Daily => start,
+			Frequency::Weekly => start.weekday_on_or_before(rule.week_start),
+			Frequency::Monthly => start.year_month().first_day(),
+			Frequency::Yearly => start.year().first_day(),
+		};
+		Self {
+			rule,
+			start,
+			filter: Filter::new(rule, start),
+			period: Some(period),
+			candidates: Vec::new(),
+			index: 0,
+			produced: 0,
+		}
+	}
+
+	/// Advance a period start by the interval of the rule.
+	///
+	/// Returns `None` if the next period starts after [`LAST_DATE`].
+	fn next_period(&self, period: Date) -> Option<Date> {
+		let interval = i64::from(self.rule.interval);
+		let next = match self.rule.frequency {
+			Frequency::Daily | Frequency::Weekly => {
+				let days = if self.rule.frequency == Frequency::Daily { interval } else { interval * 7 };
+				let days = i64::from(period.days_since_year_zero()) + days;
+				if days > i64::from(LAST_DATE.days_since_year_zero()) {
+					return None;
+				}
+				Date::from_days_since_year_zero(days as i32)
+			},
+			Frequency::Monthly | Frequency::Yearly => {
+				let months = if self.rule.frequency == Frequency::Monthly { interval } else { interval * 12 };
+				let months = i64::from(period.year().to_number()) * 12 + i64::from(period.month().to_number() - 1) + months;
+				let year = months.div_euclid(12);
+				if year > i64::from(LAST_DATE.year().to_number()) {
+					return None;
+				}
+				Year::new(year as i16).first_month().add_months(months.rem_euclid(12) as i32).first_day()
+			},
+		};
+		Some(next)
+	}
+}
+
+impl Iterator for RecurrenceIter<'_> {
+	type Item = Date;
+
+	fn next(&mut self) -> Option<Date> {
+		loop {
+			if self.rule.count.is_some_and(|count| self.produced >= count) {
+				return None;
+			}
+
+			if let Some(&date) = self.candidates.get(self.index) {
+				self.index += 1;
+				if date < self.start {
+					continue;
+				}
+				if self.rule.until.is_some_and(|until| date > until) {
+					self.period = None;
+					self.candidates.clear();
+					return None;
+				}
+				self.produced += 1;
+				return Some(date);
+			}
+
+			let period = self.period?;
+			if self.rule.until.is_some_and(|until| period > until) {
+				self.period = None;
+				return None;
+			}
+			self.candidates = self.filter.candidates(period);
+			self.index = 0;
+			self.period = self.next_period(period);
+		}
+	}
+}
+
+impl core::iter::FusedIterator for RecurrenceIter<'_> {}
+
+/// The `BY*` parts of a rule, with the defaults derived from the start date filled in.
+#[derive(Clone, Debug)]
+struct Filter<'a> {
+	rule: &'a RecurrenceRule,
+	by_month: &'a [Month],
+	by_month_day: &'a [i8],
+	by_day: &'a [WeekdayNum],
+	default_month: Option<Month>,
+	default_month_day: Option<i8>,
+	default_weekday: Option<Weekday>,
+	week_rules: WeekRules,
+}
+
+impl<'a> Filter<'a> {
+	fn new(rule: &'a RecurrenceRule, start: Date) -> Self {
+		let mut filter = Self {
+			rule,
+			by_month: &rule.by_month,
+			by_month_day: &rule.by_month_day,
+			by_day: &rule.by_day,
+			default_month: None,
+			default_month_day: None,
+			default_weekday: None,
+			week_rules: WeekRules::new(rule.week_start, 4),
+		};
+
+		// Without any day selection, the rule repeats the day of the start date.
+		if rule.by_week_no.is_empty() && rule.by_year_day.is_empty() && rule.by_month_day.is_empty() && rule.by_day.is_empty() {
+			match rule.frequency {
+				Frequency::Daily => (),
+				Frequency::Weekly => filter.default_weekday = Some(start.weekday()),
+				Frequency::Monthly => filter.default_month_day = Some(start.day() as i8),
+				Frequency::Yearly => {
+					filter.default_month_day = Some(start.day() as i8);
+					if rule.by_month.is_empty() {
+						filter.default_month = Some(start.month());
+					}
+				},
+			}
+		}
+		filter
+	}
+
+	/// Get all matching dates in a period, after applying `BYSETPOS`.
+	fn candidates(&self, period: Date) -> Vec<Date> {
+		let days = match self.rule.frequency {
+			Frequency::Daily => 1,
+			Frequency::Weekly => 7,
+			Frequency::Monthly => u32::from(period.year_month().total_days()),
+			Frequency::Yearly => u32::from(period.year().total_days()),
+		};
+		let matching: Vec<_> = (0..days as i32).map(|i| period.add_days(i)).filter(|&date| self.matches(date)).collect();
+		if self.rule.by_set_pos.is_empty() {
+			return matching;
+		}
+
+		let mut selected: Vec<_> = self
+			.rule
+			.by_set_pos
+			.iter()
+			.filter_map(|&pos| {
+				let index = if pos > 0 { pos as usize - 1 } else { matching.len().checked_sub(pos.unsigned_abs() as usize)? };
+				matching.get(index).copied()
+			})
+			.collect();
+		selected.sort();
+		selected.dedup();
+		selected
+	}
+
+	fn matches(&self, date: Date) -> bool {
+		let month = date.year_month();
+		if !self.by_month.is_empty() && !self.by_month.contains(&date.month()) {
+			return false;
+		}
+		if self.default_month.is_some_and(|default| default != date.month()) {
+			return false;
+		}
+		if !self.rule.by_week_no.is_empty() {
+			let week = i16::from(self.week_rules.week_of_year(date));
+			let weeks = i16::from(self.week_rules.weeks_in_year(self.week_rules.week_year(date)));
+			if !self.rule.by_week_no.iter().any(|&n| i16::from(n) == week || i16::from(n) == week - weeks - 1) {
+				return false;
+			}
+		}
+		if !self.rule.by_year_day.is_empty() {
+			let day = date.day_of_year() as i16;
+			let days = date.year().total_days() as i16;
+			if !self.rule.by_year_day.iter().any(|&n| n == day || n == day - days - 1) {
+				return false;
+			}
+		}
+		let day = date.day() as i8;
+		let days = month.total_days() as i8;
+		if !self.by_month_day.is_empty() && !self.by_month_day.iter().any(|&n| n == day || n == day - days - 1) {
+			return false;
+		}
+		if self.default_month_day.is_some_and(|default| default != day) {
+			return false;
+		}
+		if !self.by_day.is_empty() && !self.by_day.iter().any(|&weekday| self.matches_weekday(date, weekday)) {
+			return false;
+		}
+		if self.default_weekday.is_some_and(|default| default != date.weekday()) {
+			return false;
+		}
+		true
+	}
+
+	fn matches_weekday(&self, date: Date, weekday: WeekdayNum) -> bool {
+		if date.weekday() != weekday.weekday {
+			return false;
+		}
+		let n = match weekday.n {
+			Some(n) => i16::from(n),
+			None => return true,
+		};
+
+		// The occurence counts within the month for monthly rules and yearly rules with BYMONTH, and within the year otherwise.
+		let (day, days) = match self.rule.frequency {
+			Frequency::Monthly => (date.day() as i16, date.year_month().total_days() as i16),
+			Frequency::Yearly if !self.rule.by_month.is_empty() => (date.day() as i16, date.year_month().total_days() as i16),
+			Frequency::Yearly => (date.day_of_year() as i16, date.year().total_days() as i16),
+			Frequency::Daily | Frequency::Weekly => return true,
+		};
+		n == (day - 1) / 7 + 1 || n == -((days - day) / 7 + 1)
+	}
+}
+
+/// A set of dates defined by a start date, recurrence rules, extra dates and excluded dates.
+///
+/// This corresponds to the `DTSTART`, `RRULE`, `RDATE` and `EXDATE` properties of RFC 5545.
+/// The start date is always part of the set, unless it is excluded.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RecurrenceSet {
+	/// The start date of the set (`DTSTART`).
+	pub start: Date,
+
+	/// The recurrence rules of the set (`RRULE`).
+	pub rules: Vec<RecurrenceRule>,
+
+	/// Extra dates in the set (`RDATE`).
+	pub dates: Vec<Date>,
+
+	/// Dates excluded from the set (`EXDATE`).
+	pub exclusions: Vec<Date>,
+}
+
+impl RecurrenceSet {
+	/// Create a new set that contains only the start date.
+	pub const fn new(start: Date) -> Self {
+		Self {
+			start,
+			rules: Vec::new(),
+			dates: Vec::new(),
+			exclusions: Vec::new(),
+		}
+	}
+
+	/// Add a recurrence rule, returning the modified set.
+	pub fn with_rule(mut self, rule: RecurrenceRule) -> Self {
+		self.rules.push(rule);
+		self
+	}
+
+	/// Add an extra date, returning the modified set.
+	pub fn with_date(mut self, date: Date) -> Self {
+		self.dates.push(date);
+		self
+	}
+
+	/// Exclude a date, returning the modified set.
+	pub fn with_exclusion(mut self, date: Date) -> Self {
+		self.exclusions.push(date);
+		self
+	}
+
+	/// Iterate over the dates in the set in ascending order, without duplicates.
+	pub fn iter(&self) -> RecurrenceSetIter<'_> {
+		let mut dates = self.dates.clone();
+		dates.push(self.start);
+		dates.sort();
+		dates.dedup();
+		let mut exclusions = self.exclusions.clone();
+		exclusions.sort();
+
+		RecurrenceSetIter {
+			rules: self.rules.iter().map(|rule| rule.iter(self.start).peekable()).collect(),
+			dates: dates.into_iter().peekable(),
+			exclusions,
+		}
+	}
+}
+
+/// Iterator over the dates in a [`RecurrenceSet`].
+///
+/// Created with [`RecurrenceSet::iter()`].
+#[derive(Clone, Debug)]
+pub struct RecurrenceSetIter<'a> {
+	rules: Vec<core::iter::Peekable<RecurrenceIter<'a>>>,
+	dates: core::iter::Peekable<std::vec::IntoIter<Date>>,
+	exclusions: Vec<Date>,
+}
+
+impl Iterator for RecurrenceSetIter<'_> {
+	type Item = Date;
+
+	fn next(&mut self) -> Option<Date> {
+		loop {
+			let next = self.rules.iter_mut().filter_map(|rule| rule.peek().copied()).chain(self.dates.peek().copied()).min()?;
+			for rule in &mut self.rules {
+				rule.next_if_eq(&next);
+			}
+			self.dates.next_if_eq(&next);
+			if self.exclusions.binary_search(&next).is_err() {
+				return Some(next);
+			}
+		}
+	}
+}
+
+impl core::iter::FusedIterator for RecurrenceSetIter<'_> {}
+
+/// Get the two letter code of a weekday, such as `"MO"`.
+pub(crate) const fn weekday_code(weekday: Weekday) -> &'static str {
+	match weekday {
+		Weekday::Monday => "MO",
+		Weekday::Tuesday => "TU",
+		Weekday::Wednesday => "WE",
+		Weekday::Thursday => "TH",
+		Weekday::Friday => "FR",
+		Weekday::Saturday => "SA",
+		Weekday::Sunday => "SU",
+	}
+}
+
+fn parse_weekday(data: &str) -> Option<Weekday> {
+	crate::WEEKDAYS.into_iter().find(|&weekday| data.eq_ignore_ascii_case(weekday_code(weekday)))
+}
+
+fn parse_weekday_num(data: &str) -> Option<WeekdayNum> {
+	let split = data.len().checked_sub(2)?;
+	let weekday = parse_weekday(data.get(split..)?)?;
+	let n = match &data[..split] {
+		"" => None,
+		n => Some(parse_offset(n, 53)?),
+	};
+	Some(WeekdayNum { n, weekday })
+}
+
+fn parse_frequency(data: &str) -> Option<Frequency> {
+	[Frequency::Daily, Frequency::Weekly, Frequency::Monthly, Frequency::Yearly]
+		.into_iter()
+		.find(|frequency| data.eq_ignore_ascii_case(frequency.name()))
+}
+
+/// Parse an iCalendar date or date-time value like `20241231` or `20241231T120000Z`, ignoring the time if present.
+pub(crate) fn parse_date_value(data: &str) -> Option<Date> {
+	let (date, time) = match data.split_once(['T', 't']) {
+		Some((date, time)) => (date, Some(time)),
+		None => (data, None),
+	};
+	if let Some(time) = time {
+		let time = time.strip_suffix(['Z', 'z']).unwrap_or(time);
+		if time.len() != 6 || !time.bytes().all(|c| c.is_ascii_digit()) {
+			return None;
+		}
+	}
+	if date.len() != 8 || !date.bytes().all(|c| c.is_ascii_digit()) {
+		return None;
+	}
+	Date::new(date[..4].parse::<i16>().ok()?, date[4..6].parse::<u8>().ok()?, date[6..].parse().ok()?).ok()
+}
+
+/// Parse an unsigned number without sign.
+fn parse_number<T: core::str::FromStr>(data: &str) -> Option<T> {
+	if data.is_empty() || !data.bytes().all(|c| c.is_ascii_digit()) {
+		return None;
+	}
+	data.parse().ok()
+}
+
+/// Parse a non-zero number in the range `-max..=max`.
+fn parse_offset<T: TryFrom<i16>>(data: &str, max: i16) -> Option<T> {
+	let (negative, digits) = match data.strip_prefix('-') {
+		Some(digits) => (true, digits),
+		None => (false, data.strip_prefix('+').unwrap_or(data)),
+	};
+	let value: i16 = parse_number(digits)?;
+	if value == 0 || value > max {
+		return None;
+	}
+	T::try_from(if negative { -value } else { value }).ok()
+}
+
+fn parse_list<T>(data: &str, parse: impl Fn(&str) -> Option<T>) -> Option<Vec<T>> {
+	data.split(',').map(parse).collect()
+}
+
+fn write_list<T>(
+	f: &mut core::fmt::Formatter,
+	name: &str,
+	values: &[T],
+	write: impl Fn(&mut core::fmt::Formatter, &T) -> core::fmt::Result,
+) -> core::fmt::Result {
+	for (i, value) in values.iter().enumerate() {
+		if i == 0 {
+			write!(f, ";{}=", name)?;
+		} else {
+			write!(f, ",")?;
+		}
+		write(f, value)?;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Month::*;
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	#[track_caller]
+	fn rule(data: &str) -> RecurrenceRule {
+		let_assert!(Ok(rule) = data.parse());
+		rule
+	}
+
+	/// Collect at most `limit` dates produced by a rule.
+	#[track_caller]
+	fn dates(data: &str, start: Date, limit: usize) -> Vec<Date> {
+		rule(data).iter(start).take(limit).collect()
+	}
+
+	#[test]
+	fn daily() {
+		let start = date(1997, September, 2);
+		assert!(dates("FREQ=DAILY;COUNT=10", start, 100) == (0..10).map(|i| start.add_days(i)).collect::<Vec<_>>());
+
+		// The RFC examples use a time of day and exclude the UNTIL date itself, but it is included for dates.
+		let until = dates("FREQ=DAILY;UNTIL=19971224T000000Z", start, 1000);
+		assert!(until.len() == 114);
+		assert!(until.last() == Some(&date(1997, December, 24)));
+
+		assert!(dates("FREQ=DAILY;INTERVAL=10;COUNT=5", start, 100) == [
+			date(1997, September, 2),
+			date(1997, September, 12),
+			date(1997, September, 22),
+			date(1997, October, 2),
+			date(1997, October, 12),
+		]);
+
+		let start = date(1998, January, 1);
+		let yearly = dates("FREQ=YEARLY;UNTIL=20000131;BYMONTH=1;BYDAY=SU,MO,TU,WE,TH,FR,SA", start, 1000);
+		let daily = dates("FREQ=DAILY;UNTIL=20000131;BYMONTH=1", start, 1000);
+		assert!(yearly.len() == 93);
+		assert!(yearly == daily);
+	}
+
+	#[test]
+	fn weekly() {
+		let start = date(1997, September, 2);
+		let weekly = dates("FREQ=WEEKLY;COUNT=10", start, 100);
+		assert!(weekly.len() == 10);
+		assert!(weekly.last() == Some(&date(1997, November, 4)));
+
+		assert!(dates("FREQ=WEEKLY;UNTIL=19971007;WKST=SU;BYDAY=TU,TH", start, 100) == [
+			date(1997, September, 2),
+			date(1997, September, 4),
+			date(1997, September, 9),
+			date(1997, September, 11),
+			date(1997, September, 16),
+			date(1997, September, 18),
+			date(1997, September, 23),
+			date(1997, September, 25),
+			date(1997, September, 30),
+			date(1997, October, 2),
+			date(1997, October, 7),
+		]);
+
+		let start = date(1997, September, 1);
+		let every_other = dates("FREQ=WEEKLY;INTERVAL=2;UNTIL=19971224;WKST=SU;BYDAY=MO,WE,FR", start, 100);
+		assert!(every_other.len() == 26);
+		assert!(every_other[..7] == [
+			date(1997, September, 1),
+			date(1997, September, 3),
+			date(1997, September, 5),
+			date(1997, September, 15),
+			date(1997, September, 17),
+			date(1997, September, 19),
+			date(1997, September, 29),
+		]);
+		assert!(every_other.last() == Some(&date(1997, December, 24)));
+	}
+
+	#[test]
+	fn week_start() {
+		let start = date(1997, August, 5);
+		assert!(dates("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO", start, 100) == [
+			date(1997, August, 5),
+			date(1997, August, 10),
+			date(1997, August, 19),
+			date(1997, August, 24),
+		]);
+		assert!(dates("FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU", start, 100) == [
+			date(1997, August, 5),
+			date(1997, August, 17),
+			date(1997, August, 19),
+			date(1997, August, 31),
+		]);
+	}
+
+	#[test]
+	fn monthly() {
+		assert!(dates("FREQ=MONTHLY;COUNT=10;BYDAY=1FR", date(1997, September, 5), 100) == [
+			date(1997, September, 5),
+			date(1997, October, 3),
+			date(1997, November, 7),
+			date(1997, December, 5),
+			date(1998, January, 2),
+			date(1998, February, 6),
+			date(1998, March, 6),
+			date(1998, April, 3),
+			date(1998, May, 1),
+			date(1998, June, 5),
+		]);
+
+		assert!(dates("FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU", date(1997, September, 7), 100) == [
+			date(1997, September, 7),
+			date(1997, September, 28),
+			date(1997, November, 2),
+			date(1997, November, 30),
+			date(1998, January, 4),
+			date(1998, January, 25),
+			date(1998, March, 1),
+			date(1998, March, 29),
+			date(1998, May, 3),
+			date(1998, May, 31),
+		]);
+
+		assert!(dates("FREQ=MONTHLY;COUNT=6;BYDAY=-2MO", date(1997, September, 22), 100) == [
+			date(1997, September, 22),
+			date(1997, October, 20),
+			date(1997, November, 17),
+			date(1997, December, 22),
+			date(1998, January, 19),
+			date(1998, February, 16),
+		]);
+
+		assert!(dates("FREQ=MONTHLY;BYMONTHDAY=-3", date(1997, September, 28), 6) == [
+			date(1997, September, 28),
+			date(1997, October, 29),
+			date(1997, November, 28),
+			date(1997, December, 29),
+			date(1998, January, 29),
+			date(1998, February, 26),
+		]);
+
+		assert!(dates("FREQ=MONTHLY;COUNT=10;BYMONTHDAY=2,15", date(1997, September, 2), 100) == [
+			date(1997, September, 2),
+			date(1997, September, 15),
+			date(1997, October, 2),
+			date(1997, October, 15),
+			date(1997, November, 2),
+			date(1997, November, 15),
+			date(1997, December, 2),
+			date(1997, December, 15),
+			date(1998, January, 2),
+			date(1998, January, 15),
+		]);
+
+		// Invalid dates are skipped and do not count towards COUNT.
+		assert!(dates("FREQ=MONTHLY;BYMONTHDAY=15,30;COUNT=5", date(2007, January, 15), 100) == [
+			date(2007, January, 15),
+			date(2007, January, 30),
+			date(2007, February, 15),
+			date(2007, March, 15),
+			date(2007, March, 30),
+		]);
+
+		// The first Saturday that follows the first Sunday of the month.
+		assert!(dates("FREQ=MONTHLY;BYDAY=SA;BYMONTHDAY=7,8,9,10,11,12,13", date(1997, September, 13), 4) == [
+			date(1997, September, 13),
+			date(1997, October, 11),
+			date(1997, November, 8),
+			date(1997, December, 13),
+		]);
+	}
+
+	#[test]
+	fn yearly() {
+		let june_july = dates("FREQ=YEARLY;COUNT=10;BYMONTH=6,7", date(1997, June, 10), 100);
+		assert!(june_july.len() == 10);
+		assert!(june_july[..3] == [date(1997, June, 10), date(1997, July, 10), date(1998, June, 10)]);
+
+		assert!(dates("FREQ=YEARLY;INTERVAL=3;COUNT=10;BYYEARDAY=1,100,200", date(1997, January, 1), 100) == [
+			date(1997, January, 1),
+			date(1997, April, 10),
+			date(1997, July, 19),
+			date(2000, January, 1),
+			date(2000, April, 9),
+			date(2000, July, 18),
+			date(2003, January, 1),
+			date(2003, April, 10),
+			date(2003, July, 19),
+			date(2006, January, 1),
+		]);
+
+		assert!(dates("FREQ=YEARLY;BYDAY=20MO", date(1997, May, 19), 3) == [
+			date(1997, May, 19),
+			date(1998, May, 18),
+			date(1999, May, 17),
+		]);
+
+		assert!(dates("FREQ=YEARLY;BYWEEKNO=20;BYDAY=MO", date(1997, May, 12), 3) == [
+			date(1997, May, 12),
+			date(1998, May, 11),
+			date(1999, May, 17),
+		]);
+
+		assert!(dates("FREQ=YEARLY;BYMONTH=3;BYDAY=TH", date(1997, March, 13), 7) == [
+			date(1997, March, 13),
+			date(1997, March, 20),
+			date(1997, March, 27),
+			date(1998, March, 5),
+			date(1998, March, 12),
+			date(1998, March, 19),
+			date(1998, March, 26),
+		]);
+
+		// US presidential election day.
+		assert!(dates("FREQ=YEARLY;INTERVAL=4;BYMONTH=11;BYDAY=TU;BYMONTHDAY=2,3,4,5,6,7,8", date(1996, November, 5), 3) == [
+			date(1996, November, 5),
+			date(2000, November, 7),
+			date(2004, November, 2),
+		]);
+
+		// Without any day selection, the day of the start date is repeated.
+		assert!(dates("FREQ=YEARLY", date(2024, February, 29), 2) == [date(2024, February, 29), date(2028, February, 29)]);
+	}
+
+	#[test]
+	fn set_pos() {
+		assert!(dates("FREQ=MONTHLY;COUNT=3;BYDAY=TU,WE,TH;BYSETPOS=3", date(1997, September, 4), 100) == [
+			date(1997, September, 4),
+			date(1997, October, 7),
+			date(1997, November, 6),
+		]);
+
+		assert!(dates("FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2", date(1997, September, 29), 7) == [
+			date(1997, September, 29),
+			date(1997, October, 30),
+			date(1997, November, 27),
+			date(1997, December, 30),
+			date(1998, January, 29),
+			date(1998, February, 26),
+			date(1998, March, 30),
+		]);
+	}
+
+	#[test]
+	fn never_matches() {
+		assert!(dates("FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30", date(2024, January, 1), 1) == []);
+	}
+
+	#[test]
+	fn set() {
+		// Every Friday the 13th, excluding the start date.
+		let start = date(1997, September, 2);
+		let set = RecurrenceSet::new(start).with_rule(rule("FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13")).with_exclusion(start);
+		assert!(set.iter().take(5).collect::<Vec<_>>() == [
+			date(1998, February, 13),
+			date(1998, March, 13),
+			date(1998, November, 13),
+			date(1999, August, 13),
+			date(2000, October, 13),
+		]);
+
+		let set = RecurrenceSet::new(date(2024, January, 3))
+			.with_rule(rule("FREQ=WEEKLY;COUNT=3;BYDAY=MO"))
+			.with_rule(rule("FREQ=MONTHLY;COUNT=2;BYMONTHDAY=15"))
+			.with_date(date(2024, January, 15))
+			.with_date(date(2024, January, 2))
+			.with_exclusion(date(2024, January, 22));
+		assert!(set.iter().collect::<Vec<_>>() == [
+			date(2024, January, 2),
+			date(2024, January, 3),
+			date(2024, January, 8),
+			date(2024, January, 15),
+			date(2024, February, 15),
+		]);
+	}
+
+	#[test]
+	fn parse() {
+		let_assert!(Ok(parsed) = "RRULE:freq=monthly;interval=2;count=5;bymonth=1,7;byday=MO,-1fr;bysetpos=1;wkst=SU".parse::<RecurrenceRule>());
+		assert!(parsed.frequency == Frequency::Monthly);
+		assert!(parsed.interval == 2);
+		assert!(parsed.count == Some(5));
+		assert!(parsed.by_month == [January, July]);
+		assert!(parsed.by_day == [WeekdayNum::every(Weekday::Monday), WeekdayNum::nth(-1, Weekday::Friday)]);
+		assert!(parsed.by_set_pos == [1]);
+		assert!(parsed.week_start == Weekday::Sunday);
+
+		assert!(rule("FREQ=DAILY;UNTIL=20241231").until == Some(date(2024, December, 31)));
+		assert!(rule("FREQ=YEARLY;BYWEEKNO=-1;BYYEARDAY=-366;BYMONTHDAY=+31").by_month_day == [31]);
+
+		let error = |data: &str| data.parse::<RecurrenceRule>().map_err(|e| e.expected);
+		assert!(error("") == Err(EXPECTED_PART));
+		assert!(error("COUNT=5") == Err(EXPECTED_FREQ));
+		assert!(error("FREQ=HOURLY") == Err(EXPECTED_FREQUENCY));
+		assert!(error("FREQ=DAILY;FREQ=DAILY") == Err(EXPECTED_UNIQUE_PART));
+		assert!(error("FREQ=DAILY;BYHOUR=1") == Err(EXPECTED_KNOWN_PART));
+		assert!(error("FREQ=DAILY;COUNT=-1") == Err(EXPECTED_COUNT));
+		assert!(error("FREQ=DAILY;COUNT=1;UNTIL=20240101") == Err(EXPECTED_COUNT_OR_UNTIL));
+		assert!(error("FREQ=DAILY;UNTIL=2024-01-01") == Err(EXPECTED_UNTIL));
+		assert!(error("FREQ=DAILY;INTERVAL=0") == Err(EXPECTED_INTERVAL));
+		assert!(error("FREQ=DAILY;BYMONTH=13") == Err(EXPECTED_MONTH));
+		assert!(error("FREQ=DAILY;BYMONTHDAY=0") == Err(EXPECTED_MONTH_DAY));
+		assert!(error("FREQ=DAILY;BYDAY=0MO") == Err(EXPECTED_DAY));
+		assert!(error("FREQ=DAILY;BYDAY=MON") == Err(EXPECTED_DAY));
+		assert!(error("FREQ=DAILY;BYWEEKNO=54") == Err(EXPECTED_WEEK_NO));
+		assert!(error("FREQ=DAILY;WKST=XX") == Err(EXPECTED_WEEK_START));
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn format() {
+		for data in [
+			"FREQ=DAILY",
+			"FREQ=WEEKLY;UNTIL=19971007;BYDAY=TU,TH;WKST=SU",
+			"FREQ=MONTHLY;COUNT=10;INTERVAL=2;BYDAY=1SU,-1SU",
+			"FREQ=YEARLY;BYMONTH=11;BYWEEKNO=20;BYYEARDAY=-1;BYMONTHDAY=2,3;BYDAY=TU;BYSETPOS=-2",
+		] {
+			assert!(rule(data).to_string() == data);
+		}
+		assert!(rule("RRULE:FREQ=DAILY;INTERVAL=1;WKST=MO").to_string() == "FREQ=DAILY");
+	}
+
+	#[test]
+	fn serde() {
+		#[derive(Debug, serde::Deserialize, serde::Serialize)]
+		struct Container {
+			rule: RecurrenceRule,
+		}
+
+		let_assert!(Ok(serialized) = serde_yaml::to_string(&Container { rule: rule("FREQ=MONTHLY;BYDAY=-1FR") }));
+		assert!(serialized == "rule: FREQ=MONTHLY;BYDAY=-1FR\n");
+		let_assert!(Ok(parsed) = serde_yaml::from_str::<Container>("rule: FREQ=WEEKLY;COUNT=2"));
+		assert!(parsed.rule.count == Some(2));
+	}
+}
@@ -0,0 +1,253 @@
+use crate::{Date, DateRange};
+
+/// A cyclic shift rotation, such as 4-on/4-off or the DuPont schedule.
+///
+/// The pattern is a repeating cycle of days, starting at an anchor date.
+/// Each day of the cycle is either `0` for a day off, or the number of the shift that is worked,
+/// such as `1` for a day shift and `2` for a night shift.
+///
+/// Multiple crews work the same cycle, each starting at a different offset in the cycle.
+/// On any date, crew `i` works the shift at position `(days since anchor + offset of crew i) % cycle length`.
+/// All lookups are computed directly from the number of days since the anchor date,
+/// so they take constant time regardless of how far the date is from the anchor.
+///
+/// ```
+/// # use gregorian::{Date, RotationPattern};
+/// let anchor = Date::new(2024, 1, 1).unwrap();
+/// let rotation = RotationPattern::four_on_four_off(anchor);
+/// assert!(rotation.crews_on(anchor).collect::<Vec<_>>() == [0]);
+/// assert!(rotation.crews_on(anchor.add_days(4)).collect::<Vec<_>>() == [1]);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RotationPattern {
+	anchor: Date,
+	cycle: Vec<u8>,
+	crew_offsets: Vec<u32>,
+}
+
+impl RotationPattern {
+	/// Create a rotation for a single crew from the shifts of each day in the cycle.
+	///
+	/// A value of `0` is a day off, any other value is the number of the shift that is worked.
+	///
+	/// # Panics
+	/// This function panics if the cycle is empty.
+	pub fn new(anchor: Date, cycle: impl IntoIterator<Item = u8>) -> Self {
+		let cycle: Vec<u8> = cycle.into_iter().collect();
+		assert!(!cycle.is_empty(), "the cycle of a rotation pattern can not be empty");
+		Self {
+			anchor,
+			cycle,
+			crew_offsets: vec![0],
+		}
+	}
+
+	/// Create a rotation for a single crew from alternating runs of working days and days off.
+	///
+	/// The first run is worked, the second is off, and so on.
+	/// For example, `[2, 2, 3, 2, 2, 3]` is the 2-2-3 rotation.
+	///
+	/// # Panics
+	/// This function panics if the total length of the runs is zero.
+	pub fn from_runs(anchor: Date, runs: &[u32]) -> Self {
+		let cycle = runs
+			.iter()
+			.enumerate()
+			.flat_map(|(i, &length)| core::iter::repeat_n(if i % 2 == 0 { 1 } else { 0 }, length as usize));
+		Self::new(anchor, cycle)
+	}
+
+	/// The 4-on/4-off rotation with two crews.
+	///
+	/// Each crew works four days and is off for four days, so every day is covered by exactly one crew.
+	pub fn four_on_four_off(anchor: Date) -> Self {
+		Self::from_runs(anchor, &[4, 4]).with_crews(2)
+	}
+
+	/// The Pitman or 2-2-3 rotation with two crews.
+	///
+	/// Each crew works two days, is off for two days, works three days, and so on, in a 14 day cycle.
+	/// Every day is covered by exactly one crew, and each crew has every other weekend off.
+	pub fn pitman(anchor: Date) -> Self {
+		Self::from_runs(anchor, &[2, 2, 3, 2, 2, 3]).with_crews(2)
+	}
+
+	/// The DuPont rotation with four crews working 12 hour day (`1`) and night (`2`) shifts.
+	///
+	/// Each crew works four nights, is off for three days, works three days, is off for one day,
+	/// works three nights, is off for three days, works four days and is then off for seven days, in a 28 day cycle.
+	/// Every day, one crew works the day shift and another crew works the night shift.
+	pub fn dupont(anchor: Date) -> Self {
+		let runs: [(u8, usize); 8] = [(2, 4), (0, 3), (1, 3), (0, 1), (2, 3), (0, 3), (1, 4), (0, 7)];
+		let cycle = runs.into_iter().flat_map(|(shift, length)| core::iter::repeat_n(shift, length));
+		Self::new(anchor, cycle).with_crews(4)
+	}
+
+	/// Set the number of crews, returning the modified rotation.
+	///
+	/// The crews are spread evenly over the cycle: crew `i` starts at offset `i * cycle length / count`.
+	///
+	/// # Panics
+	/// This function panics if `count` is zero.
+	pub fn with_crews(self, count: u32) -> Self {
+		assert!(count > 0, "a rotation pattern needs at least one crew");
+		let len = self.cycle_len();
+		self.with_crew_offsets((0..count).map(|i| (u64::from(i) * u64::from(len) / u64::from(count)) as u32))
+	}
+
+	/// Set the offset in the cycle of each crew, returning the modified rotation.
+	///
+	/// # Panics
+	/// This function panics if there are no offsets.
+	pub fn with_crew_offsets(mut self, offsets: impl IntoIterator<Item = u32>) -> Self {
+		self.crew_offsets = offsets.into_iter().collect();
+		assert!(!self.crew_offsets.is_empty(), "a rotation pattern needs at least one crew");
+		self
+	}
+
+	/// Get the anchor date: the date at which the first crew starts the cycle.
+	pub fn anchor(&self) -> Date {
+		self.anchor
+	}
+
+	/// Get the shifts of each day in the cycle, for the first crew.
+	pub fn cycle(&self) -> &[u8] {
+		&self.cycle
+	}
+
+	/// Get the length of the cycle in days.
+	pub fn cycle_len(&self) -> u32 {
+		self.cycle.len() as u32
+	}
+
+	/// Get the number of crews.
+	pub fn crews(&self) -> usize {
+		self.crew_offsets.len()
+	}
+
+	/// Get the shift worked by a crew on a date, or `None` if the crew is off.
+	///
+	/// # Panics
+	/// This function panics if `crew` is not a valid crew index.
+	pub fn shift(&self, crew: usize, date: Date) -> Option<u8> {
+		let len = i64::from(self.cycle_len());
+		let position = (i64::from(self.anchor.days_since(date)) + i64::from(self.crew_offsets[crew])).rem_euclid(len);
+		match self.cycle[position as usize] {
+			0 => None,
+			shift => Some(shift),
+		}
+	}
+
+	/// Check if a crew works on a date.
+	///
+	/// # Panics
+	/// This function panics if `crew` is not a valid crew index.
+	pub fn is_working(&self, crew: usize, date: Date) -> bool {
+		self.shift(crew, date).is_some()
+	}
+
+	/// Get the crews that work on a date, in order of their index.
+	pub fn crews_on(&self, date: Date) -> impl Iterator<Item = usize> + '_ {
+		(0..self.crews()).filter(move |&crew| self.is_working(crew, date))
+	}
+
+	/// Get the crew that works a specific shift on a date.
+	///
+	/// If multiple crews work the shift, the one with the lowest index is returned.
+	pub fn crew_on_shift(&self, date: Date, shift: u8) -> Option<usize> {
+		(0..self.crews()).find(|&crew| self.shift(crew, date) == Some(shift))
+	}
+
+	/// Iterate over the dates in a range on which a crew works.
+	///
+	/// # Panics
+	/// This function panics if `crew` is not a valid crew index.
+	pub fn working_dates(&self, crew: usize, range: impl Into<DateRange>) -> impl Iterator<Item = Date> + '_ {
+		assert!(crew < self.crews(), "invalid crew index: {}", crew);
+		range.into().into_iter().filter(move |&date| self.is_working(crew, date))
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, YearMonth};
+	use crate::util::date;
+	use assert2::assert;
+
+	#[test]
+	fn four_on_four_off() {
+		let anchor = date(2024, January, 1);
+		let rotation = RotationPattern::four_on_four_off(anchor);
+		assert!(rotation.cycle_len() == 8);
+		assert!(rotation.crews() == 2);
+		for i in -16..16 {
+			let day = anchor.add_days(i);
+			let expected = if i.rem_euclid(8) < 4 { 0 } else { 1 };
+			assert!(rotation.crews_on(day).collect::<Vec<_>>() == [expected]);
+		}
+
+		assert!(rotation.working_dates(1, YearMonth::new(2024, January)).take(5).collect::<Vec<_>>() == [
+			date(2024, January, 5),
+			date(2024, January, 6),
+			date(2024, January, 7),
+			date(2024, January, 8),
+			date(2024, January, 13),
+		]);
+	}
+
+	#[test]
+	fn pitman() {
+		let anchor = date(2024, January, 1);
+		let rotation = RotationPattern::pitman(anchor);
+		assert!(rotation.cycle() == [1, 1, 0, 0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0]);
+
+		// Every day is covered by exactly one crew, and each crew works half of the days.
+		for day in YearMonth::new(2024, January).days() {
+			assert!(rotation.crews_on(day).count() == 1);
+		}
+		assert!(rotation.working_dates(0, date(2024, January, 1)..date(2024, January, 29)).count() == 14);
+		assert!(rotation.working_dates(1, date(2024, January, 1)..date(2024, January, 29)).count() == 14);
+	}
+
+	#[test]
+	fn dupont() {
+		let anchor = date(2024, January, 1);
+		let rotation = RotationPattern::dupont(anchor);
+		assert!(rotation.cycle_len() == 28);
+		for i in 0..56 {
+			let day = anchor.add_days(i);
+			let day_crew = rotation.crew_on_shift(day, 1);
+			let night_crew = rotation.crew_on_shift(day, 2);
+			assert!(day_crew.is_some());
+			assert!(night_crew.is_some());
+			assert!(day_crew != night_crew);
+			assert!(rotation.crews_on(day).count() == 2);
+		}
+		assert!(rotation.shift(0, anchor) == Some(2));
+		assert!(rotation.shift(0, date(2024, January, 5)) == None);
+		assert!(rotation.shift(0, date(2024, January, 8)) == Some(1));
+	}
+
+	#[test]
+	fn far_away_dates() {
+		let anchor = date(2024, January, 1);
+		let rotation = RotationPattern::from_runs(anchor, &[3, 4]);
+		// The cycle is exactly one week, so the crew works on the same weekdays forever.
+		for year in [-2000, 1, 1900, 2024, 9999] {
+			let monday = date(year, January, 1).weekday_on_or_after(crate::Weekday::Monday);
+			assert!(rotation.is_working(0, monday));
+			assert!(rotation.is_working(0, monday.add_days(2)));
+			assert!(!rotation.is_working(0, monday.add_days(3)));
+		}
+	}
+
+	#[test]
+	fn crew_offsets() {
+		let anchor = date(2024, January, 1);
+		let rotation = RotationPattern::new(anchor, [1, 0, 0]).with_crew_offsets([0, 2]);
+		assert!(rotation.crews_on(anchor).collect::<Vec<_>>() == [0]);
+		assert!(rotation.crews_on(anchor.add_days(1)).collect::<Vec<_>>() == [1]);
+		assert!(rotation.crews_on(anchor.add_days(2)).count() == 0);
+	}
+}
@@ -0,0 +1,435 @@
+use crate::{BusinessCalendar, BusinessDayConvention, Date, DateResultExt, InvalidSchedule, Period, PeriodUnit};
+
+/// The rule for placing an irregular period (a stub) when the schedule does not divide evenly into regular periods.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StubRule {
+	/// Generate dates backward from the termination date, leaving a short stub at the front.
+	#[default]
+	ShortFront,
+
+	/// Generate dates backward from the termination date, merging the stub at the front with the first regular period.
+	LongFront,
+
+	/// Generate dates forward from the effective date, leaving a short stub at the back.
+	ShortBack,
+
+	/// Generate dates forward from the effective date, merging the stub at the back with the last regular period.
+	LongBack,
+}
+
+impl StubRule {
+	/// Check if the stub is placed at the front of the schedule.
+	pub const fn is_front(self) -> bool {
+		matches!(self, Self::ShortFront | Self::LongFront)
+	}
+
+	/// Check if the stub is merged with the adjacent regular period.
+	pub const fn is_long(self) -> bool {
+		matches!(self, Self::LongFront | Self::LongBack)
+	}
+}
+
+/// A schedule of dates, such as the coupon dates of a bond or the payment dates of a swap.
+///
+/// The schedule contains the unadjusted dates and the dates adjusted according to a business day convention.
+/// The first date is the effective date and the last date is the termination date.
+/// Each pair of consecutive dates forms a period of the schedule.
+///
+/// Use [`Schedule::builder()`] to generate a schedule.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Schedule {
+	unadjusted: Vec<Date>,
+	adjusted: Vec<Date>,
+}
+
+impl Schedule {
+	/// Create a builder for a schedule from `effective` to `termination` with a regular period of `frequency`.
+	pub fn builder<'a>(effective: Date, termination: Date, frequency: Period) -> ScheduleBuilder<'a> {
+		ScheduleBuilder {
+			effective,
+			termination,
+			frequency,
+			stub: StubRule::default(),
+			first_regular: None,
+			last_regular: None,
+			end_of_month: false,
+			roll_day: None,
+			adjustment: None,
+		}
+	}
+
+	/// Get the unadjusted dates of the schedule.
+	pub fn unadjusted(&self) -> &[Date] {
+		&self.unadjusted
+	}
+
+	/// Get the adjusted dates of the schedule.
+	///
+	/// If no business day adjustment was configured, these are the same as the unadjusted dates.
+	pub fn adjusted(&self) -> &[Date] {
+		&self.adjusted
+	}
+
+	/// Get the number of dates in the schedule.
+	pub fn len(&self) -> usize {
+		self.unadjusted.len()
+	}
+
+	/// Check if the schedule is empty.
+	///
+	/// A generated schedule always contains at least the effective and termination date.
+	pub fn is_empty(&self) -> bool {
+		self.unadjusted.is_empty()
+	}
+
+	/// Iterate over the adjusted periods of the schedule as pairs of start and end date.
+	pub fn periods(&self) -> impl Iterator<Item = (Date, Date)> + '_ {
+		self.adjusted.windows(2).map(|pair| (pair[0], pair[1]))
+	}
+}
+
+/// Builder for a [`Schedule`].
+///
+/// Created with [`Schedule::builder()`].
+#[derive(Clone, Debug)]
+pub struct ScheduleBuilder<'a> {
+	effective: Date,
+	termination: Date,
+	frequency: Period,
+	stub: StubRule,
+	first_regular: Option<Date>,
+	last_regular: Option<Date>,
+	end_of_month: bool,
+	roll_day: Option<u8>,
+	adjustment: Option<(BusinessDayConvention, &'a BusinessCalendar)>,
+}
+
+impl<'a> ScheduleBuilder<'a> {
+	/// Set the stub rule, returning the modified builder.
+	///
+	/// The default is [`StubRule::ShortFront`].
+	pub fn stub(mut self, stub: StubRule) -> Self {
+		self.stub = stub;
+		self
+	}
+
+	/// Set the first regular date, returning the modified builder.
+	///
+	/// The period from the effective date to the first regular date is an explicit front stub.
+	pub fn first_regular_date(mut self, date: Date) -> Self {
+		self.first_regular = Some(date);
+		self
+	}
+
+	/// Set the last regular date, returning the modified builder.
+	///
+	/// The period from the last regular date to the termination date is an explicit back stub.
+	pub fn last_regular_date(mut self, date: Date) -> Self {
+		self.last_regular = Some(date);
+		self
+	}
+
+	/// Enable or disable the end-of-month rule, returning the modified builder.
+	///
+	/// If enabled and the date that the regular periods are generated from is the last day of a month,
+	/// all regular dates are placed on the last day of their month.
+	/// This only applies to periods of months and years, and takes precedence over the roll day.
+	pub fn end_of_month(mut self, end_of_month: bool) -> Self {
+		self.end_of_month = end_of_month;
+		self
+	}
+
+	/// Set the roll day, returning the modified builder.
+	///
+	/// Regular dates are placed on this day of the month,
+	/// or on the last day of the month if the month is too short.
+	/// By default, the day of the date that the regular periods are generated from is used.
+	/// This only applies to periods of months and years.
+	pub fn roll_day(mut self, day: u8) -> Self {
+		self.roll_day = Some(day);
+		self
+	}
+
+	/// Adjust the dates with a business day convention and calendar, returning the modified builder.
+	pub fn adjust(mut self, convention: BusinessDayConvention, calendar: &'a BusinessCalendar) -> Self {
+		self.adjustment = Some((convention, calendar));
+		self
+	}
+
+	/// Generate the schedule.
+	pub fn build(&self) -> Result<Schedule, InvalidSchedule> {
+		if self.termination <= self.effective {
+			return Err(InvalidSchedule::TerminationNotAfterEffective);
+		}
+		if self.frequency.count <= 0 {
+			return Err(InvalidSchedule::NonPositiveFrequency);
+		}
+		if let Some(roll_day) = self.roll_day {
+			if !(1..=31).contains(&roll_day) {
+				return Err(InvalidSchedule::InvalidRollDay { roll_day });
+			}
+		}
+
+		let regular_start = self.first_regular.unwrap_or(self.effective);
+		let regular_end = self.last_regular.unwrap_or(self.termination);
+		if regular_start < self.effective || regular_end > self.termination || regular_end <= regular_start {
+			return Err(InvalidSchedule::RegularDatesOutOfRange);
+		}
+
+		// An explicit first or last regular date on the effective or termination date does not create a stub.
+		let mut unadjusted = Vec::new();
+		if regular_start != self.effective {
+			unadjusted.push(self.effective);
+		}
+		if self.stub.is_front() {
+			let mut dates = self.generate(regular_end, regular_start, -1);
+			dates.reverse();
+			unadjusted.extend(dates);
+		} else {
+			unadjusted.extend(self.generate(regular_start, regular_end, 1));
+		}
+		if regular_end != self.termination {
+			unadjusted.push(self.termination);
+		}
+
+		let adjusted = match self.adjustment {
+			Some((convention, calendar)) => unadjusted.iter().map(|&date| convention.adjust(date, calendar)).collect(),
+			None => unadjusted.clone(),
+		};
+
+		Ok(Schedule { unadjusted, adjusted })
+	}
+
+	/// Generate regular dates from `anchor` towards `limit` (both included) in the given direction.
+	///
+	/// If the dates do not reach `limit` exactly, a stub is created according to the stub rule.
+	fn generate(&self, anchor: Date, limit: Date, direction: i32) -> Vec<Date> {
+		let past_limit = |date: Date| if direction > 0 { date >= limit } else { date <= limit };
+
+		let mut dates = vec![anchor];
+		for i in 1.. {
+			// A regular date outside of the supported range of years is always past the limit.
+			match self.regular_date(anchor, i * direction) {
+				Some(date) if !past_limit(date) => dates.push(date),
+				date => {
+					// Merge a short stub into the previous period if requested, but keep at least one period.
+					if date != Some(limit) && self.stub.is_long() && dates.len() > 1 {
+						dates.pop();
+					}
+					dates.push(limit);
+					break;
+				},
+			}
+		}
+		dates
+	}
+
+	/// Get the regular date that is `count` periods away from `anchor`.
+	///
+	/// Returns `None` if the date is outside of the supported range of years.
+	fn regular_date(&self, anchor: Date, count: i32) -> Option<Date> {
+		let period = self.frequency.checked_times(count)?;
+		let months = match period.unit {
+			PeriodUnit::Days | PeriodUnit::Weeks => return anchor.checked_add_period(period).map(|date| date.or_prev_valid()),
+			PeriodUnit::Months | PeriodUnit::Years => period.to_months()?,
+		};
+
+		let month = anchor.year_month().checked_add_months(months)?;
+		if self.end_of_month && anchor == anchor.year_month().last_day() {
+			Some(month.last_day())
+		} else {
+			Some(month.with_day(self.roll_day.unwrap_or(anchor.day())).or_prev_valid())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Month::*;
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	fn dates(schedule: &ScheduleBuilder) -> Vec<Date> {
+		let_assert!(Ok(schedule) = schedule.build());
+		schedule.unadjusted().to_vec()
+	}
+
+	#[test]
+	fn regular() {
+		let builder = Schedule::builder(date(2024, January, 15), date(2025, January, 15), Period::months(3));
+		let expected = [
+			date(2024, January, 15),
+			date(2024, April, 15),
+			date(2024, July, 15),
+			date(2024, October, 15),
+			date(2025, January, 15),
+		];
+		assert!(dates(&builder) == expected);
+		assert!(dates(&builder.clone().stub(StubRule::ShortBack)) == expected);
+		assert!(dates(&builder.clone().stub(StubRule::LongFront)) == expected);
+	}
+
+	#[test]
+	fn stubs() {
+		let builder = Schedule::builder(date(2024, February, 1), date(2025, January, 15), Period::months(3));
+		assert!(dates(&builder.clone().stub(StubRule::ShortFront)) == [
+			date(2024, February, 1),
+			date(2024, April, 15),
+			date(2024, July, 15),
+			date(2024, October, 15),
+			date(2025, January, 15),
+		]);
+		assert!(dates(&builder.clone().stub(StubRule::LongFront)) == [
+			date(2024, February, 1),
+			date(2024, July, 15),
+			date(2024, October, 15),
+			date(2025, January, 15),
+		]);
+		assert!(dates(&builder.clone().stub(StubRule::ShortBack)) == [
+			date(2024, February, 1),
+			date(2024, May, 1),
+			date(2024, August, 1),
+			date(2024, November, 1),
+			date(2025, January, 15),
+		]);
+		assert!(dates(&builder.clone().stub(StubRule::LongBack)) == [
+			date(2024, February, 1),
+			date(2024, May, 1),
+			date(2024, August, 1),
+			date(2025, January, 15),
+		]);
+
+		// A long stub is not merged if there is no regular period to merge with.
+		let builder = Schedule::builder(date(2024, February, 1), date(2024, April, 15), Period::months(3));
+		assert!(dates(&builder.stub(StubRule::LongFront)) == [date(2024, February, 1), date(2024, April, 15)]);
+	}
+
+	#[test]
+	fn explicit_stubs() {
+		let builder = Schedule::builder(date(2024, January, 10), date(2025, February, 20), Period::months(6))
+			.first_regular_date(date(2024, February, 15))
+			.last_regular_date(date(2025, February, 15));
+		assert!(dates(&builder) == [
+			date(2024, January, 10),
+			date(2024, February, 15),
+			date(2024, August, 15),
+			date(2025, February, 15),
+			date(2025, February, 20),
+		]);
+
+		// Regular dates on the effective or termination date do not create a zero-length stub.
+		let builder = Schedule::builder(date(2024, January, 15), date(2024, July, 15), Period::months(3));
+		let expected = [date(2024, January, 15), date(2024, April, 15), date(2024, July, 15)];
+		assert!(dates(&builder.clone().first_regular_date(date(2024, January, 15))) == expected);
+		assert!(dates(&builder.clone().last_regular_date(date(2024, July, 15))) == expected);
+	}
+
+	#[test]
+	fn huge_frequency() {
+		let builder = Schedule::builder(date(2024, January, 1), date(2025, January, 1), Period::years(1_000_000_000));
+		assert!(dates(&builder) == [date(2024, January, 1), date(2025, January, 1)]);
+		let builder = Schedule::builder(date(2024, January, 1), date(2025, January, 1), Period::years(20_000));
+		assert!(dates(&builder.stub(StubRule::ShortFront)) == [date(2024, January, 1), date(2025, January, 1)]);
+		let builder = Schedule::builder(date(2024, January, 1), date(2025, January, 1), Period::weeks(i32::MAX));
+		assert!(dates(&builder) == [date(2024, January, 1), date(2025, January, 1)]);
+	}
+
+	#[test]
+	fn end_of_month() {
+		let builder = Schedule::builder(date(2023, November, 30), date(2024, May, 31), Period::months(2));
+		assert!(dates(&builder.clone()) == [
+			date(2023, November, 30),
+			date(2024, January, 31),
+			date(2024, March, 31),
+			date(2024, May, 31),
+		]);
+
+		let builder = Schedule::builder(date(2023, August, 31), date(2024, February, 29), Period::months(2)).stub(StubRule::ShortBack);
+		assert!(dates(&builder.clone()) == [
+			date(2023, August, 31),
+			date(2023, October, 31),
+			date(2023, December, 31),
+			date(2024, February, 29),
+		]);
+
+		let builder = Schedule::builder(date(2023, February, 28), date(2024, February, 29), Period::months(3)).stub(StubRule::ShortBack);
+		assert!(dates(&builder.clone()) == [
+			date(2023, February, 28),
+			date(2023, May, 28),
+			date(2023, August, 28),
+			date(2023, November, 28),
+			date(2024, February, 28),
+			date(2024, February, 29),
+		]);
+		assert!(dates(&builder.end_of_month(true)) == [
+			date(2023, February, 28),
+			date(2023, May, 31),
+			date(2023, August, 31),
+			date(2023, November, 30),
+			date(2024, February, 29),
+		]);
+	}
+
+	#[test]
+	fn roll_day() {
+		let builder = Schedule::builder(date(2024, January, 15), date(2024, July, 15), Period::months(1))
+			.stub(StubRule::ShortBack)
+			.roll_day(31);
+		assert!(dates(&builder) == [
+			date(2024, January, 15),
+			date(2024, February, 29),
+			date(2024, March, 31),
+			date(2024, April, 30),
+			date(2024, May, 31),
+			date(2024, June, 30),
+			date(2024, July, 15),
+		]);
+	}
+
+	#[test]
+	fn weekly() {
+		let builder = Schedule::builder(date(2024, January, 1), date(2024, January, 31), Period::weeks(2));
+		assert!(dates(&builder) == [
+			date(2024, January, 1),
+			date(2024, January, 3),
+			date(2024, January, 17),
+			date(2024, January, 31),
+		]);
+	}
+
+	#[test]
+	fn adjusted() {
+		let calendar = BusinessCalendar::weekdays().with_holidays([date(2024, December, 25)]);
+		let_assert!(Ok(schedule) = Schedule::builder(date(2024, March, 30), date(2025, March, 30), Period::months(3))
+			.adjust(BusinessDayConvention::ModifiedFollowing, &calendar)
+			.build());
+		assert!(schedule.unadjusted() == [
+			date(2024, March, 30),
+			date(2024, June, 30),
+			date(2024, September, 30),
+			date(2024, December, 30),
+			date(2025, March, 30),
+		]);
+		assert!(schedule.adjusted() == [
+			date(2024, March, 29),
+			date(2024, June, 28),
+			date(2024, September, 30),
+			date(2024, December, 30),
+			date(2025, March, 31),
+		]);
+		assert!(schedule.len() == 5);
+		assert!(schedule.periods().next() == Some((date(2024, March, 29), date(2024, June, 28))));
+	}
+
+	#[test]
+	fn errors() {
+		let start = date(2024, January, 1);
+		let end = date(2025, January, 1);
+		assert!(let Err(InvalidSchedule::TerminationNotAfterEffective) = Schedule::builder(end, start, Period::months(1)).build());
+		assert!(let Err(InvalidSchedule::NonPositiveFrequency) = Schedule::builder(start, end, Period::months(0)).build());
+		assert!(let Err(InvalidSchedule::InvalidRollDay { roll_day: 32 }) = Schedule::builder(start, end, Period::months(1)).roll_day(32).build());
+		assert!(let Err(InvalidSchedule::RegularDatesOutOfRange) = Schedule::builder(start, end, Period::months(1)).first_regular_date(date(2023, December, 1)).build());
+	}
+}
@@ -0,0 +1,298 @@
+use crate::{Date, MonthGrid, WeekRules, Year, YearMonth};
+
+/// Render months and years as plain text, like the `cal` command.
+///
+/// Each month is rendered as a block with a title, a header with the weekdays and one line per week.
+/// Multiple months can be rendered next to each other, and a full year is rendered as four rows of three months.
+/// Trailing whitespace is removed from every line.
+///
+/// Dates can be highlighted with a callback.
+/// By default, highlighted dates are shown in reverse video using ANSI escape codes, just like `cal` shows today.
+///
+/// ```
+/// # use gregorian::{Month::*, TextCalendar, YearMonth};
+/// let text = TextCalendar::new().render_month(YearMonth::new(2024, February));
+/// assert!(text == concat!(
+///     "   February 2024\n",
+///     "Mo Tu We Th Fr Sa Su\n",
+///     "          1  2  3  4\n",
+///     " 5  6  7  8  9 10 11\n",
+///     "12 13 14 15 16 17 18\n",
+///     "19 20 21 22 23 24 25\n",
+///     "26 27 28 29\n",
+/// ));
+/// ```
+pub struct TextCalendar {
+	week_rules: WeekRules,
+	week_numbers: bool,
+	outside_days: bool,
+	highlight: Option<Box<dyn Fn(Date) -> bool>>,
+	highlight_style: (String, String),
+}
+
+/// The space between months rendered next to each other.
+const GAP: &str = "  ";
+
+impl TextCalendar {
+	/// Create a new text renderer using ISO 8601 weeks.
+	pub fn new() -> Self {
+		Self {
+			week_rules: WeekRules::ISO,
+			week_numbers: false,
+			outside_days: false,
+			highlight: None,
+			highlight_style: ("\x1b[7m".into(), "\x1b[27m".into()),
+		}
+	}
+
+	/// Set the week rules used for the first weekday and the week numbers, returning the modified renderer.
+	///
+	/// Use [`WeekRules::US`] to get the same layout as `cal` in the US, with weeks starting on Sunday.
+	pub fn with_week_rules(mut self, week_rules: WeekRules) -> Self {
+		self.week_rules = week_rules;
+		self
+	}
+
+	/// Show the week number in front of every week, returning the modified renderer.
+	pub fn with_week_numbers(mut self, week_numbers: bool) -> Self {
+		self.week_numbers = week_numbers;
+		self
+	}
+
+	/// Show the leading and trailing days of adjacent months, returning the modified renderer.
+	pub fn with_outside_days(mut self, outside_days: bool) -> Self {
+		self.outside_days = outside_days;
+		self
+	}
+
+	/// Highlight the dates for which a callback returns `true`, returning the modified renderer.
+	pub fn with_highlight(mut self, highlight: impl Fn(Date) -> bool + 'static) -> Self {
+		self.highlight = Some(Box::new(highlight));
+		self
+	}
+
+	/// Set the text written before and after a highlighted date, returning the modified renderer.
+	///
+	/// The text should not take up any space on the terminal, or the columns will no longer line up.
+	pub fn with_highlight_style(mut self, before: impl Into<String>, after: impl Into<String>) -> Self {
+		self.highlight_style = (before.into(), after.into());
+		self
+	}
+
+	/// Render a single month.
+	pub fn render_month(&self, month: YearMonth) -> String {
+		self.render_months(&[month])
+	}
+
+	/// Render months next to each other, such as the previous, current and next month.
+	pub fn render_months(&self, months: &[YearMonth]) -> String {
+		let mut output = String::new();
+		self.write_row(&mut output, months, true);
+		output
+	}
+
+	/// Render all months of a year, in four rows of three months.
+	pub fn render_year(&self, year: Year) -> String {
+		let width = self.block_width() * 3 + GAP.len() * 2;
+		let mut output = String::new();
+		push_line(&mut output, &center(&year.to_number().to_string(), width));
+		for quarter in 0..4 {
+			output.push('\n');
+			let first = year.with_month(crate::Month::January).add_months(quarter * 3);
+			self.write_row(&mut output, &[first, first.next(), first.next().next()], false);
+		}
+		output
+	}
+
+	/// Get the width of a rendered month.
+	fn block_width(&self) -> usize {
+		if self.week_numbers {
+			23
+		} else {
+			20
+		}
+	}
+
+	/// Write months next to each other.
+	fn write_row(&self, output: &mut String, months: &[YearMonth], with_year: bool) {
+		let blocks: Vec<Vec<String>> = months.iter().map(|&month| self.block(month, with_year)).collect();
+		let lines = blocks.iter().map(Vec::len).max().unwrap_or(0);
+		let empty = " ".repeat(self.block_width());
+		for i in 0..lines {
+			let line: Vec<&str> = blocks.iter().map(|block| block.get(i).unwrap_or(&empty).as_str()).collect();
+			push_line(output, &line.join(GAP));
+		}
+	}
+
+	/// Render the lines of a single month, each padded to the width of the block.
+	fn block(&self, month: YearMonth, with_year: bool) -> Vec<String> {
+		let grid = MonthGrid::new(month).with_week_rules(self.week_rules);
+		let title = if with_year {
+			format!("{} {}", month.month().name(), month.year().to_number())
+		} else {
+			month.month().name().to_string()
+		};
+
+		let mut lines = vec![center(&title, self.block_width())];
+
+		let mut header = String::from(if self.week_numbers { "Wk " } else { "" });
+		let weekdays: Vec<&str> = grid.weekdays().iter().map(|weekday| &weekday.short_name()[..2]).collect();
+		header.push_str(&weekdays.join(" "));
+		lines.push(header);
+
+		for week in grid.rows() {
+			let mut line = String::new();
+			if self.week_numbers {
+				line.push_str(&format!("{:2} ", week.week_number));
+			}
+			for (i, day) in week.days.iter().enumerate() {
+				if i > 0 {
+					line.push(' ');
+				}
+				if !day.in_month && !self.outside_days {
+					line.push_str("  ");
+				} else if self.highlight.as_ref().is_some_and(|highlight| highlight(day.date)) {
+					let (before, after) = &self.highlight_style;
+					line.push_str(&format!("{}{:2}{}", before, day.date.day(), after));
+				} else {
+					line.push_str(&format!("{:2}", day.date.day()));
+				}
+			}
+			lines.push(line);
+		}
+		lines
+	}
+}
+
+impl Default for TextCalendar {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Center text in a field of the given width, padding with spaces on both sides.
+fn center(text: &str, width: usize) -> String {
+	let left = width.saturating_sub(text.len()) / 2;
+	let right = width.saturating_sub(text.len() + left);
+	format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
+
+/// Add a line to the output without trailing whitespace.
+fn push_line(output: &mut String, line: &str) {
+	output.push_str(line.trim_end());
+	output.push('\n');
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Month::*;
+	use assert2::assert;
+
+	#[test]
+	fn month_like_cal() {
+		let text = TextCalendar::new().with_week_rules(WeekRules::US).render_month(YearMonth::new(2024, September));
+		assert!(text == concat!(
+			"   September 2024\n",
+			"Su Mo Tu We Th Fr Sa\n",
+			" 1  2  3  4  5  6  7\n",
+			" 8  9 10 11 12 13 14\n",
+			"15 16 17 18 19 20 21\n",
+			"22 23 24 25 26 27 28\n",
+			"29 30\n",
+		));
+	}
+
+	#[test]
+	fn week_numbers_and_outside_days() {
+		let text = TextCalendar::new()
+			.with_week_numbers(true)
+			.with_outside_days(true)
+			.render_month(YearMonth::new(2024, September));
+		assert!(text == concat!(
+			"    September 2024\n",
+			"Wk Mo Tu We Th Fr Sa Su\n",
+			"35 26 27 28 29 30 31  1\n",
+			"36  2  3  4  5  6  7  8\n",
+			"37  9 10 11 12 13 14 15\n",
+			"38 16 17 18 19 20 21 22\n",
+			"39 23 24 25 26 27 28 29\n",
+			"40 30  1  2  3  4  5  6\n",
+		));
+	}
+
+	#[test]
+	fn highlight() {
+		let today = Date::new(2021, February, 14).unwrap();
+		let text = TextCalendar::new()
+			.with_highlight(move |date| date == today || date.day() == 1)
+			.with_highlight_style("\x1b[1m", "\x1b[0m")
+			.render_month(YearMonth::new(2021, February));
+		assert!(text == concat!(
+			"   February 2021\n",
+			"Mo Tu We Th Fr Sa Su\n",
+			"\x1b[1m 1\x1b[0m  2  3  4  5  6  7\n",
+			" 8  9 10 11 12 13 \x1b[1m14\x1b[0m\n",
+			"15 16 17 18 19 20 21\n",
+			"22 23 24 25 26 27 28\n",
+		));
+	}
+
+	#[test]
+	fn three_months() {
+		let months = [YearMonth::new(2024, January), YearMonth::new(2024, February), YearMonth::new(2024, March)];
+		let text = TextCalendar::new().render_months(&months);
+		assert!(text == concat!(
+			"    January 2024         February 2024           March 2024\n",
+			"Mo Tu We Th Fr Sa Su  Mo Tu We Th Fr Sa Su  Mo Tu We Th Fr Sa Su\n",
+			" 1  2  3  4  5  6  7            1  2  3  4               1  2  3\n",
+			" 8  9 10 11 12 13 14   5  6  7  8  9 10 11   4  5  6  7  8  9 10\n",
+			"15 16 17 18 19 20 21  12 13 14 15 16 17 18  11 12 13 14 15 16 17\n",
+			"22 23 24 25 26 27 28  19 20 21 22 23 24 25  18 19 20 21 22 23 24\n",
+			"29 30 31              26 27 28 29           25 26 27 28 29 30 31\n",
+		));
+	}
+
+	#[test]
+	fn year() {
+		let text = TextCalendar::new().with_week_rules(WeekRules::US).render_year(Year::new(2023));
+		assert!(text == concat!(
+			"                              2023\n",
+			"\n",
+			"      January               February               March\n",
+			"Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa\n",
+			" 1  2  3  4  5  6  7            1  2  3  4            1  2  3  4\n",
+			" 8  9 10 11 12 13 14   5  6  7  8  9 10 11   5  6  7  8  9 10 11\n",
+			"15 16 17 18 19 20 21  12 13 14 15 16 17 18  12 13 14 15 16 17 18\n",
+			"22 23 24 25 26 27 28  19 20 21 22 23 24 25  19 20 21 22 23 24 25\n",
+			"29 30 31              26 27 28              26 27 28 29 30 31\n",
+			"\n",
+			"       April                  May                   June\n",
+			"Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa\n",
+			"                   1      1  2  3  4  5  6               1  2  3\n",
+			" 2  3  4  5  6  7  8   7  8  9 10 11 12 13   4  5  6  7  8  9 10\n",
+			" 9 10 11 12 13 14 15  14 15 16 17 18 19 20  11 12 13 14 15 16 17\n",
+			"16 17 18 19 20 21 22  21 22 23 24 25 26 27  18 19 20 21 22 23 24\n",
+			"23 24 25 26 27 28 29  28 29 30 31           25 26 27 28 29 30\n",
+			"30\n",
+			"\n",
+			"        July                 August              September\n",
+			"Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa\n",
+			"                   1         1  2  3  4  5                  1  2\n",
+			" 2  3  4  5  6  7  8   6  7  8  9 10 11 12   3  4  5  6  7  8  9\n",
+			" 9 10 11 12 13 14 15  13 14 15 16 17 18 19  10 11 12 13 14 15 16\n",
+			"16 17 18 19 20 21 22  20 21 22 23 24 25 26  17 18 19 20 21 22 23\n",
+			"23 24 25 26 27 28 29  27 28 29 30 31        24 25 26 27 28 29 30\n",
+			"30 31\n",
+			"\n",
+			"      October               November              December\n",
+			"Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa  Su Mo Tu We Th Fr Sa\n",
+			" 1  2  3  4  5  6  7            1  2  3  4                  1  2\n",
+			" 8  9 10 11 12 13 14   5  6  7  8  9 10 11   3  4  5  6  7  8  9\n",
+			"15 16 17 18 19 20 21  12 13 14 15 16 17 18  10 11 12 13 14 15 16\n",
+			"22 23 24 25 26 27 28  19 20 21 22 23 24 25  17 18 19 20 21 22 23\n",
+			"29 30 31              26 27 28 29 30        24 25 26 27 28 29 30\n",
+			"                                            31\n",
+		));
+	}
+}
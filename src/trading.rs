@@ -0,0 +1,228 @@
+use crate::{BusinessCalendar, Date, DateRange, HolidaySet, WeekdaySet};
+
+/// A time of day in hours and minutes, such as the closing time of an exchange.
+///
+/// The time has no time zone: it is interpreted in the local time of whatever it describes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeOfDay {
+	/// The hour in the range 0-23.
+	pub hour: u8,
+
+	/// The minute in the range 0-59.
+	pub minute: u8,
+}
+
+impl TimeOfDay {
+	/// Create a new time of day from an hour and a minute.
+	pub const fn new(hour: u8, minute: u8) -> Self {
+		Self { hour, minute }
+	}
+}
+
+impl core::fmt::Display for TimeOfDay {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "{:02}:{:02}", self.hour, self.minute)
+	}
+}
+
+/// A calendar of trading days, such as the calendar of a stock exchange.
+///
+/// A trading day is a day that is not on a weekend day and not a closure.
+/// Closures are described by a [`HolidaySet`], so that both regular holidays and one-off closures can be included.
+/// Both the actual and the observed date of a closure are treated as non-trading days.
+///
+/// On some trading days, the market closes early.
+/// These days are described by a separate [`HolidaySet`], using only the actual date of each rule.
+///
+/// For many calculations over long spans of time,
+/// it is more efficient to create a [`BusinessCalendar`] with [`Self::business_calendar()`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TradingCalendar {
+	weekend: WeekdaySet,
+	closures: HolidaySet,
+	early_closes: HolidaySet,
+	open: TimeOfDay,
+	close: TimeOfDay,
+	early_close: TimeOfDay,
+}
+
+impl TradingCalendar {
+	/// Create a new trading calendar with regular trading hours and a set of closures.
+	///
+	/// The calendar has Saturday and Sunday as weekend and no early closes.
+	pub fn new(closures: HolidaySet, open: TimeOfDay, close: TimeOfDay) -> Self {
+		Self {
+			weekend: WeekdaySet::WEEKEND,
+			closures,
+			early_closes: HolidaySet::new(),
+			open,
+			close,
+			early_close: close,
+		}
+	}
+
+	/// Set the weekend days, returning the modified calendar.
+	///
+	/// # Panics
+	/// This function panics if all weekdays are weekend days,
+	/// since there could never be any trading days.
+	pub fn with_weekend(mut self, weekend: WeekdaySet) -> Self {
+		assert!(weekend != WeekdaySet::ALL, "a trading calendar needs at least one trading weekday");
+		self.weekend = weekend;
+		self
+	}
+
+	/// Set the days on which the market closes early and the early closing time, returning the modified calendar.
+	pub fn with_early_closes(mut self, early_closes: HolidaySet, early_close: TimeOfDay) -> Self {
+		self.early_closes = early_closes;
+		self.early_close = early_close;
+		self
+	}
+
+	/// Get the weekend days.
+	pub fn weekend(&self) -> WeekdaySet {
+		self.weekend
+	}
+
+	/// Get the closures of the calendar.
+	pub fn closures(&self) -> &HolidaySet {
+		&self.closures
+	}
+
+	/// Get the days on which the market closes early.
+	pub fn early_closes(&self) -> &HolidaySet {
+		&self.early_closes
+	}
+
+	/// Get the regular opening and closing time.
+	pub fn regular_hours(&self) -> (TimeOfDay, TimeOfDay) {
+		(self.open, self.close)
+	}
+
+	/// Check if a date is a trading day.
+	pub fn is_trading_day(&self, date: Date) -> bool {
+		!self.weekend.contains_date(date) && !self.closures.is_holiday(date)
+	}
+
+	/// Get the name of the closure on a date, if any.
+	///
+	/// Weekend days are not closures and return `None`.
+	pub fn closure_name(&self, date: Date) -> Option<&str> {
+		self.closures.holiday_name(date)
+	}
+
+	/// Check if a date is a trading day on which the market closes early.
+	pub fn is_early_close(&self, date: Date) -> bool {
+		self.is_trading_day(date) && self.early_closes.holidays_on(date).iter().any(|holiday| holiday.date == date)
+	}
+
+	/// Get the opening and closing time on a date.
+	///
+	/// Returns `None` if the date is not a trading day.
+	pub fn trading_hours(&self, date: Date) -> Option<(TimeOfDay, TimeOfDay)> {
+		if !self.is_trading_day(date) {
+			None
+		} else if self.is_early_close(date) {
+			Some((self.open, self.early_close))
+		} else {
+			Some((self.open, self.close))
+		}
+	}
+
+	/// Get the first trading day after a date.
+	pub fn next_trading_day(&self, date: Date) -> Date {
+		self.trading_day_on_or_after(date.next())
+	}
+
+	/// Get the last trading day before a date.
+	pub fn prev_trading_day(&self, date: Date) -> Date {
+		self.trading_day_on_or_before(date.prev())
+	}
+
+	/// Get the date itself if it is a trading day, or the first trading day after it.
+	pub fn trading_day_on_or_after(&self, mut date: Date) -> Date {
+		while !self.is_trading_day(date) {
+			date = date.next();
+		}
+		date
+	}
+
+	/// Get the date itself if it is a trading day, or the last trading day before it.
+	pub fn trading_day_on_or_before(&self, mut date: Date) -> Date {
+		while !self.is_trading_day(date) {
+			date = date.prev();
+		}
+		date
+	}
+
+	/// Count the number of trading days from `start` (inclusive) until `end` (exclusive).
+	///
+	/// If `end` is before `start`, the result is negative.
+	pub fn trading_days_between(&self, start: Date, end: Date) -> i32 {
+		let range = DateRange::new(start.min(end), start.max(end));
+		self.business_calendar(range).business_days_between(start, end)
+	}
+
+	/// Create a [`BusinessCalendar`] with the weekend and all closures in a date range.
+	pub fn business_calendar(&self, range: impl Into<DateRange>) -> BusinessCalendar {
+		self.closures.business_calendar(self.weekend, range)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{HolidayRule, Month::*};
+	use crate::util::date;
+	use assert2::assert;
+
+	fn calendar() -> TradingCalendar {
+		let closures = HolidaySet::new()
+			.with(HolidayRule::fixed("Christmas Day", December, 25))
+			.with(HolidayRule::fixed("Storm", October, 30).since(2024).until(2024));
+		let early_closes = HolidaySet::new().with(HolidayRule::fixed("Christmas Eve", December, 24));
+		TradingCalendar::new(closures, TimeOfDay::new(9, 30), TimeOfDay::new(16, 0)).with_early_closes(early_closes, TimeOfDay::new(13, 0))
+	}
+
+	#[test]
+	fn is_trading_day() {
+		let calendar = calendar();
+		assert!(calendar.is_trading_day(date(2024, December, 24)));
+		assert!(!calendar.is_trading_day(date(2024, December, 25)));
+		assert!(!calendar.is_trading_day(date(2024, December, 28)));
+		assert!(!calendar.is_trading_day(date(2024, October, 30)));
+		assert!(calendar.is_trading_day(date(2025, October, 30)));
+		assert!(calendar.closure_name(date(2024, October, 30)) == Some("Storm"));
+		assert!(calendar.closure_name(date(2024, December, 28)) == None);
+	}
+
+	#[test]
+	fn trading_hours() {
+		let calendar = calendar();
+		assert!(calendar.trading_hours(date(2024, December, 23)) == Some((TimeOfDay::new(9, 30), TimeOfDay::new(16, 0))));
+		assert!(calendar.trading_hours(date(2024, December, 24)) == Some((TimeOfDay::new(9, 30), TimeOfDay::new(13, 0))));
+		assert!(calendar.trading_hours(date(2024, December, 25)) == None);
+		assert!(!calendar.is_early_close(date(2022, December, 24)));
+		assert!(TimeOfDay::new(9, 5).to_string() == "09:05");
+	}
+
+	#[test]
+	fn next_prev_trading_day() {
+		let calendar = calendar();
+		assert!(calendar.next_trading_day(date(2024, December, 24)) == date(2024, December, 26));
+		assert!(calendar.next_trading_day(date(2024, December, 27)) == date(2024, December, 30));
+		assert!(calendar.prev_trading_day(date(2024, December, 26)) == date(2024, December, 24));
+		assert!(calendar.trading_day_on_or_after(date(2024, October, 30)) == date(2024, October, 31));
+		assert!(calendar.trading_day_on_or_before(date(2024, October, 30)) == date(2024, October, 29));
+	}
+
+	#[test]
+	fn trading_days_between() {
+		let calendar = calendar();
+		assert!(calendar.trading_days_between(date(2024, December, 23), date(2024, December, 30)) == 4);
+		assert!(calendar.trading_days_between(date(2024, December, 30), date(2024, December, 23)) == -4);
+		assert!(calendar.trading_days_between(date(2024, October, 28), date(2024, November, 4)) == 4);
+		assert!(calendar.trading_days_between(date(2024, January, 1), date(2025, January, 1)) == 262 - 2);
+	}
+}
@@ -6,6 +6,16 @@ pub const fn modulo_i32(a: i32, b: i32) -> i32 {
 	((a % b) + b) % b
 }
 
+/// Create a date in tests, panicking if it is not valid.
+#[cfg(test)]
+pub(crate) fn date<M>(year: i16, month: M, day: u8) -> crate::Date
+where
+	M: TryInto<crate::Month>,
+	crate::InvalidDate: From<M::Error>,
+{
+	crate::Date::new(year, month, day).unwrap()
+}
+
 #[cfg(test)]
 mod test {
 	use assert2::assert;
@@ -0,0 +1,340 @@
+use crate::{Date, Weekday, Year, YearMonth};
+
+/// Rules for numbering the weeks of a year or month.
+///
+/// Different week-numbering systems disagree on the first day of the week,
+/// and on how many days of a week must fall in a year for it to count as week 1.
+/// For example, ISO 8601 weeks start on Monday,
+/// and week 1 is the first week with at least four days in the new year (the week containing the first Thursday).
+///
+/// With these rules, every date belongs to exactly one week of a week-year.
+/// The week-year can differ from the calendar year for dates near the start or end of the year.
+/// The same rules can be applied to months, which gives the week-month of a date.
+///
+/// The `min_days_in_first_week` field must be in the range 1-7 (inclusive).
+/// Values outside of that range are clamped to the nearest valid value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeekRules {
+	/// The first day of the week.
+	pub first_weekday: Weekday,
+
+	/// The minimum number of days of the first week that must fall in the year.
+	pub min_days_in_first_week: u8,
+}
+
+impl WeekRules {
+	/// ISO 8601 weeks: weeks start on Monday, and week 1 contains the first Thursday of the year.
+	pub const ISO: Self = Self::new(Weekday::Monday, 4);
+
+	/// US weeks: weeks start on Sunday, and week 1 contains 1 January.
+	///
+	/// Use [`Self::week_of_calendar_year()`] to get week numbers that do not roll over to the next year,
+	/// as many spreadsheet applications do.
+	pub const US: Self = Self::new(Weekday::Sunday, 1);
+
+	/// Middle-Eastern weeks: weeks start on Saturday, and week 1 contains 1 January.
+	pub const MIDDLE_EASTERN: Self = Self::new(Weekday::Saturday, 1);
+
+	/// The broadcast calendar: weeks start on Monday, and week 1 contains 1 January.
+	///
+	/// A broadcast month starts on the Monday of the week containing the first day of the calendar month.
+	/// Use [`Self::week_month()`] and [`Self::first_day_of_week_month()`] to work with broadcast months.
+	pub const BROADCAST: Self = Self::new(Weekday::Monday, 1);
+
+	/// Weeks starting on Sunday, where week 1 starts at the first Sunday of the year.
+	///
+	/// Together with [`Self::week_of_calendar_year()`], this gives the `%U` week number of `strftime`.
+	pub const SUNDAY_FULL_WEEK: Self = Self::new(Weekday::Sunday, 7);
+
+	/// Weeks starting on Monday, where week 1 starts at the first Monday of the year.
+	///
+	/// Together with [`Self::week_of_calendar_year()`], this gives the `%W` week number of `strftime`.
+	pub const MONDAY_FULL_WEEK: Self = Self::new(Weekday::Monday, 7);
+
+	/// Create new week rules.
+	///
+	/// The `min_days_in_first_week` parameter is clamped to the range 1-7 (inclusive).
+	pub const fn new(first_weekday: Weekday, min_days_in_first_week: u8) -> Self {
+		let min_days_in_first_week = if min_days_in_first_week < 1 {
+			1
+		} else if min_days_in_first_week > 7 {
+			7
+		} else {
+			min_days_in_first_week
+		};
+		Self {
+			first_weekday,
+			min_days_in_first_week,
+		}
+	}
+
+	/// Get the first day of the week containing a date.
+	pub const fn week_start(self, date: Date) -> Date {
+		date.sub_days(self.first_weekday.days_until(date.weekday()) as i32)
+	}
+
+	/// Get the last day of the week containing a date.
+	pub const fn week_end(self, date: Date) -> Date {
+		self.week_start(date).add_days(6)
+	}
+
+	/// Get the first day of week 1 of a week-year.
+	///
+	/// This date may fall in the previous calendar year.
+	pub const fn first_day_of_week_year(self, year: Year) -> Date {
+		self.first_week_start(year.first_day())
+	}
+
+	/// Get the total number of weeks in a week-year.
+	pub const fn weeks_in_year(self, year: Year) -> u8 {
+		let start = self.first_day_of_week_year(year);
+		let end = self.first_day_of_week_year(year.next());
+		(start.days_since(end) / 7) as u8
+	}
+
+	/// Get the week-year of a date.
+	///
+	/// For dates near the start or end of a calendar year,
+	/// the week-year can be the previous or next calendar year.
+	pub const fn week_year(self, date: Date) -> Year {
+		let year = date.year();
+		if date.days_since_year_zero() < self.first_day_of_week_year(year).days_since_year_zero() {
+			year.prev()
+		} else if date.days_since_year_zero() >= self.first_day_of_week_year(year.next()).days_since_year_zero() {
+			year.next()
+		} else {
+			year
+		}
+	}
+
+	/// Get the week number of a date within its week-year.
+	///
+	/// Week numbers start at 1.
+	/// The week-year itself can be retrieved with [`Self::week_year()`].
+	pub const fn week_of_year(self, date: Date) -> u8 {
+		let start = self.first_day_of_week_year(self.week_year(date));
+		(start.days_since(date) / 7 + 1) as u8
+	}
+
+	/// Get the week number of a date, counted within the calendar year of the date.
+	///
+	/// Unlike [`Self::week_of_year()`], this never assigns a date to a week of the previous or next year.
+	/// Days before week 1 are in week 0, and days after the last week of the week-year continue counting up.
+	///
+	/// With [`Self::SUNDAY_FULL_WEEK`] and [`Self::MONDAY_FULL_WEEK`] this gives the `%U` and `%W` week numbers of `strftime`.
+	/// With [`Self::US`] this gives the week numbers used by many spreadsheet applications.
+	pub const fn week_of_calendar_year(self, date: Date) -> u8 {
+		let start = self.first_day_of_week_year(date.year());
+		let days = start.days_since(date);
+		if days < 0 {
+			0
+		} else {
+			(days / 7 + 1) as u8
+		}
+	}
+
+	/// Get the first day of week 1 of a week-month.
+	///
+	/// This date may fall in the previous calendar month.
+	///
+	/// For [`Self::BROADCAST`], this is the first day of the broadcast month.
+	pub const fn first_day_of_week_month(self, month: YearMonth) -> Date {
+		self.first_week_start(month.first_day())
+	}
+
+	/// Get the week-month of a date.
+	///
+	/// For dates near the start or end of a calendar month,
+	/// the week-month can be the previous or next calendar month.
+	///
+	/// For [`Self::BROADCAST`], this is the broadcast month of the date.
+	pub const fn week_month(self, date: Date) -> YearMonth {
+		let month = date.year_month();
+		if date.days_since_year_zero() < self.first_day_of_week_month(month).days_since_year_zero() {
+			month.prev()
+		} else if date.days_since_year_zero() >= self.first_day_of_week_month(month.next()).days_since_year_zero() {
+			month.next()
+		} else {
+			month
+		}
+	}
+
+	/// Get the week number of a date within its week-month.
+	///
+	/// Week numbers start at 1.
+	/// The week-month itself can be retrieved with [`Self::week_month()`].
+	pub const fn week_of_month(self, date: Date) -> u8 {
+		let start = self.first_day_of_week_month(self.week_month(date));
+		(start.days_since(date) / 7 + 1) as u8
+	}
+
+	/// Get the start of the first week of a period starting on `first_day`.
+	const fn first_week_start(self, first_day: Date) -> Date {
+		let week_start = self.week_start(first_day);
+		let days_in_period = 7 - week_start.days_since(first_day);
+		// A full week always counts, even if `min_days_in_first_week` was set to a value above 7.
+		if days_in_period >= self.min_days_in_first_week as i32 || days_in_period == 7 {
+			week_start
+		} else {
+			week_start.add_days(7)
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::{Month::*, Weekday::*};
+	use crate::util::date;
+	use assert2::{assert, let_assert};
+
+	#[test]
+	fn new_clamps() {
+		assert!(WeekRules::new(Monday, 0).min_days_in_first_week == 1);
+		assert!(WeekRules::new(Monday, 8).min_days_in_first_week == 7);
+		assert!(WeekRules::new(Monday, 4) == WeekRules::ISO);
+	}
+
+	#[test]
+	fn week_start() {
+		assert!(WeekRules::ISO.week_start(date(2024, February, 29)) == date(2024, February, 26));
+		assert!(WeekRules::ISO.week_end(date(2024, February, 29)) == date(2024, March, 3));
+		assert!(WeekRules::US.week_start(date(2024, February, 29)) == date(2024, February, 25));
+		assert!(WeekRules::MIDDLE_EASTERN.week_start(date(2024, February, 29)) == date(2024, February, 24));
+		assert!(WeekRules::US.week_start(date(2024, February, 25)) == date(2024, February, 25));
+	}
+
+	#[test]
+	fn iso() {
+		let rules = WeekRules::ISO;
+		assert!(rules.week_year(date(2020, December, 31)) == 2020);
+		assert!(rules.week_of_year(date(2020, December, 31)) == 53);
+		assert!(rules.week_year(date(2021, January, 3)) == 2020);
+		assert!(rules.week_of_year(date(2021, January, 3)) == 53);
+		assert!(rules.week_year(date(2021, January, 4)) == 2021);
+		assert!(rules.week_of_year(date(2021, January, 4)) == 1);
+
+		assert!(rules.week_year(date(2019, December, 30)) == 2020);
+		assert!(rules.week_of_year(date(2019, December, 30)) == 1);
+		assert!(rules.week_year(date(2008, December, 29)) == 2009);
+		assert!(rules.week_of_year(date(2008, December, 29)) == 1);
+		assert!(rules.week_year(date(2010, January, 3)) == 2009);
+		assert!(rules.week_of_year(date(2010, January, 3)) == 53);
+
+		assert!(rules.first_day_of_week_year(Year::new(2021)) == date(2021, January, 4));
+		assert!(rules.first_day_of_week_year(Year::new(2020)) == date(2019, December, 30));
+		assert!(rules.weeks_in_year(Year::new(2020)) == 53);
+		assert!(rules.weeks_in_year(Year::new(2021)) == 52);
+		assert!(rules.weeks_in_year(Year::new(2026)) == 53);
+	}
+
+	#[test]
+	fn iso_weeks_are_consecutive() {
+		let rules = WeekRules::ISO;
+		let mut day = date(1999, January, 4);
+		let mut week_year = Year::new(1999);
+		let mut week = 1;
+		let mut weekday = Monday;
+		for _ in 0..10_000 {
+			assert!(rules.week_year(day) == week_year);
+			assert!(rules.week_of_year(day) == week);
+			day = day.next();
+			weekday = weekday.wrapping_next();
+			if weekday == Monday {
+				if week == rules.weeks_in_year(week_year) {
+					week_year = week_year.next();
+					week = 1;
+				} else {
+					week += 1;
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn us() {
+		let rules = WeekRules::US;
+		// 1 January 2022 is a Saturday.
+		assert!(rules.week_of_year(date(2022, January, 1)) == 1);
+		assert!(rules.week_of_year(date(2022, January, 2)) == 2);
+		assert!(rules.week_start(date(2022, January, 1)) == date(2021, December, 26));
+		assert!(rules.week_year(date(2021, December, 26)) == 2022);
+		assert!(rules.week_of_year(date(2021, December, 26)) == 1);
+
+		// Spreadsheet style week numbers do not roll over.
+		assert!(rules.week_of_calendar_year(date(2021, December, 26)) == 53);
+		assert!(rules.week_of_calendar_year(date(2021, December, 31)) == 53);
+		assert!(rules.week_of_calendar_year(date(2022, January, 1)) == 1);
+
+		// 2000 is a leap year that starts on a Saturday, so it touches 54 weeks.
+		assert!(rules.week_of_calendar_year(date(2000, December, 31)) == 54);
+	}
+
+	#[test]
+	fn strftime() {
+		// Reference values computed with `date +%U` and `date +%W`.
+		let sunday = WeekRules::SUNDAY_FULL_WEEK;
+		let monday = WeekRules::MONDAY_FULL_WEEK;
+		assert!(sunday.week_of_calendar_year(date(2022, January, 1)) == 0);
+		assert!(sunday.week_of_calendar_year(date(2022, January, 2)) == 1);
+		assert!(monday.week_of_calendar_year(date(2022, January, 2)) == 0);
+		assert!(monday.week_of_calendar_year(date(2022, January, 3)) == 1);
+		assert!(sunday.week_of_calendar_year(date(2023, January, 1)) == 1);
+		assert!(monday.week_of_calendar_year(date(2023, January, 1)) == 0);
+		assert!(sunday.week_of_calendar_year(date(2024, December, 31)) == 52);
+		assert!(monday.week_of_calendar_year(date(2024, December, 31)) == 53);
+		assert!(sunday.week_of_calendar_year(date(2020, June, 20)) == 24);
+		assert!(monday.week_of_calendar_year(date(2020, June, 20)) == 24);
+	}
+
+	#[test]
+	fn middle_eastern() {
+		let rules = WeekRules::MIDDLE_EASTERN;
+		// 1 January 2021 is a Friday, so week 1 started on Saturday 26 December 2020.
+		assert!(rules.first_day_of_week_year(Year::new(2021)) == date(2020, December, 26));
+		assert!(rules.week_of_year(date(2021, January, 1)) == 1);
+		assert!(rules.week_of_year(date(2021, January, 2)) == 2);
+		assert!(rules.week_year(date(2020, December, 26)) == 2021);
+	}
+
+	#[test]
+	fn broadcast() {
+		let rules = WeekRules::BROADCAST;
+		// The broadcast year 2023 started on Monday 26 December 2022.
+		assert!(rules.first_day_of_week_year(Year::new(2023)) == date(2022, December, 26));
+		assert!(rules.week_year(date(2022, December, 26)) == 2023);
+		assert!(rules.week_of_year(date(2023, January, 1)) == 1);
+		assert!(rules.week_of_year(date(2023, January, 2)) == 2);
+		assert!(rules.first_day_of_week_year(Year::new(2024)) == date(2024, January, 1));
+
+		// Broadcast March 2024 runs from 26 February up to and including 31 March.
+		let march = YearMonth::new(2024, March);
+		assert!(rules.first_day_of_week_month(march) == date(2024, February, 26));
+		assert!(rules.week_month(date(2024, February, 26)) == march);
+		assert!(rules.week_month(date(2024, February, 25)) == YearMonth::new(2024, February));
+		assert!(rules.week_month(date(2024, March, 31)) == march);
+		assert!(rules.week_month(date(2024, April, 1)) == YearMonth::new(2024, April));
+		assert!(rules.week_of_month(date(2024, February, 29)) == 1);
+		assert!(rules.week_of_month(date(2024, March, 4)) == 2);
+		assert!(rules.week_of_month(date(2024, March, 31)) == 5);
+	}
+
+	#[test]
+	fn week_of_month_iso() {
+		let rules = WeekRules::ISO;
+		// 1 March 2024 is a Friday, so the first week of March starts on 4 March.
+		assert!(rules.week_month(date(2024, March, 1)) == YearMonth::new(2024, February));
+		assert!(rules.week_of_month(date(2024, March, 1)) == 5);
+		assert!(rules.week_month(date(2024, March, 4)) == YearMonth::new(2024, March));
+		assert!(rules.week_of_month(date(2024, March, 4)) == 1);
+	}
+
+	#[test]
+	fn serde() {
+		let_assert!(Ok(serialized) = serde_yaml::to_string(&WeekRules::ISO));
+		assert!(serialized == "first_weekday: 1\nmin_days_in_first_week: 4\n");
+		let_assert!(Ok(parsed) = serde_yaml::from_str::<WeekRules>("first_weekday: 7\nmin_days_in_first_week: 1\n"));
+		assert!(parsed == WeekRules::US);
+	}
+}
@@ -0,0 +1,258 @@
+use crate::{InvalidWeekdayName, InvalidWeekdayNumber};
+
+/// All weekdays in order as array, starting with Monday.
+pub const WEEKDAYS: [Weekday; 7] = [Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday];
+
+/// A day of the week.
+///
+/// The weekdays are numbered according to ISO 8601,
+/// starting with 1 for Monday up to 7 for Sunday.
+#[repr(u8)]
+#[cfg_attr(
+	feature = "serde",
+	derive(serde::Serialize, serde::Deserialize),
+	serde(try_from = "u8", into = "u8")
+)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Weekday {
+	Monday = 1,
+	Tuesday = 2,
+	Wednesday = 3,
+	Thursday = 4,
+	Friday = 5,
+	Saturday = 6,
+	Sunday = 7,
+}
+
+pub use Weekday::*;
+
+impl Weekday {
+	/// Create a new weekday from an ISO 8601 weekday number.
+	///
+	/// The number must be in the range 1-7 (inclusive), where 1 is Monday and 7 is Sunday.
+	pub const fn new(weekday: u8) -> Result<Self, InvalidWeekdayNumber> {
+		match weekday {
+			1 => Ok(Self::Monday),
+			2 => Ok(Self::Tuesday),
+			3 => Ok(Self::Wednesday),
+			4 => Ok(Self::Thursday),
+			5 => Ok(Self::Friday),
+			6 => Ok(Self::Saturday),
+			7 => Ok(Self::Sunday),
+			number => Err(InvalidWeekdayNumber { number }),
+		}
+	}
+
+	/// Get the ISO 8601 weekday number in the range 1-7.
+	///
+	/// Monday is 1 and Sunday is 7.
+	pub const fn to_number(self) -> u8 {
+		self as u8
+	}
+
+	pub(crate) const fn from_number(number: u8) -> Self {
+		match number {
+			1 => Self::Monday,
+			2 => Self::Tuesday,
+			3 => Self::Wednesday,
+			4 => Self::Thursday,
+			5 => Self::Friday,
+			6 => Self::Saturday,
+			7 => Self::Sunday,
+			//TODO: Replace this with unreachable!() when const_panic is stabilized.
+			_ => Self::Monday,
+		}
+	}
+
+	/// Get the full English name of the weekday, such as `"Monday"`.
+	pub const fn name(self) -> &'static str {
+		match self {
+			Self::Monday => "Monday",
+			Self::Tuesday => "Tuesday",
+			Self::Wednesday => "Wednesday",
+			Self::Thursday => "Thursday",
+			Self::Friday => "Friday",
+			Self::Saturday => "Saturday",
+			Self::Sunday => "Sunday",
+		}
+	}
+
+	/// Get the abbreviated English name of the weekday, such as `"Mon"`.
+	pub const fn short_name(self) -> &'static str {
+		match self {
+			Self::Monday => "Mon",
+			Self::Tuesday => "Tue",
+			Self::Wednesday => "Wed",
+			Self::Thursday => "Thu",
+			Self::Friday => "Fri",
+			Self::Saturday => "Sat",
+			Self::Sunday => "Sun",
+		}
+	}
+
+	/// Add a number of days, wrapping back to Monday after Sunday.
+	pub const fn wrapping_add(self, count: i8) -> Self {
+		let count = if count < 0 {
+			(count % 7) + 7
+		} else {
+			count % 7
+		};
+		let index = (self.to_number() as i8 - 1 + count) % 7;
+		Self::from_number(index as u8 + 1)
+	}
+
+	/// Subtract a number of days, wrapping back to Sunday after Monday.
+	pub const fn wrapping_sub(self, count: i8) -> Self {
+		// Take remainder after dividing by 7 before negating,
+		// to prevent negating i8::MIN.
+		self.wrapping_add(-(count % 7))
+	}
+
+	/// Get the next weekday, wrapping back to Monday after Sunday.
+	pub const fn wrapping_next(self) -> Self {
+		self.wrapping_add(1)
+	}
+
+	/// Get the previous weekday, wrapping back to Sunday after Monday.
+	pub const fn wrapping_prev(self) -> Self {
+		self.wrapping_add(-1)
+	}
+
+	/// Get the number of days from `self` until the next occurence of `other`.
+	///
+	/// The returned value is in the range 0-6 (inclusive).
+	/// If `self` and `other` are the same weekday, this function returns 0.
+	pub const fn days_until(self, other: Weekday) -> u8 {
+		(other.to_number() + 7 - self.to_number()) % 7
+	}
+}
+
+impl core::convert::TryFrom<u8> for Weekday {
+	type Error = InvalidWeekdayNumber;
+
+	fn try_from(other: u8) -> Result<Self, Self::Error> {
+		Self::new(other)
+	}
+}
+
+impl From<Weekday> for u8 {
+	fn from(other: Weekday) -> Self {
+		other.to_number()
+	}
+}
+
+impl core::str::FromStr for Weekday {
+	type Err = InvalidWeekdayName;
+
+	/// Parse a weekday from the full or abbreviated English name.
+	///
+	/// The comparison is case insensitive.
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		WEEKDAYS
+			.iter()
+			.copied()
+			.find(|weekday| data.eq_ignore_ascii_case(weekday.name()) || data.eq_ignore_ascii_case(weekday.short_name()))
+			.ok_or_else(InvalidWeekdayName::new)
+	}
+}
+
+impl core::fmt::Display for Weekday {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		// Delegate to Debug.
+		write!(f, "{:?}", self)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Date;
+	use assert2::{assert, let_assert};
+
+	#[test]
+	fn to_number() {
+		assert!(let Err(InvalidWeekdayNumber { number: 0 }) = Weekday::new(0));
+		assert!(let Err(InvalidWeekdayNumber { number: 8 }) = Weekday::new(8));
+		for (i, weekday) in WEEKDAYS.iter().enumerate() {
+			assert!(Weekday::new(i as u8 + 1) == Ok(*weekday));
+			assert!(weekday.to_number() == i as u8 + 1);
+		}
+	}
+
+	#[test]
+	fn wrapping_add() {
+		assert!(Monday.wrapping_add(0) == Monday);
+		assert!(Monday.wrapping_add(1) == Tuesday);
+		assert!(Monday.wrapping_add(6) == Sunday);
+		assert!(Monday.wrapping_add(7) == Monday);
+		assert!(Sunday.wrapping_add(1) == Monday);
+		assert!(Monday.wrapping_add(-1) == Sunday);
+		assert!(Monday.wrapping_add(-8) == Sunday);
+		assert!(Monday.wrapping_sub(1) == Sunday);
+		assert!(Monday.wrapping_sub(i8::MIN) == Monday.wrapping_add(2));
+		assert!(Sunday.wrapping_next() == Monday);
+		assert!(Monday.wrapping_prev() == Sunday);
+	}
+
+	#[test]
+	fn days_until() {
+		assert!(Monday.days_until(Monday) == 0);
+		assert!(Monday.days_until(Tuesday) == 1);
+		assert!(Monday.days_until(Sunday) == 6);
+		assert!(Sunday.days_until(Monday) == 1);
+		assert!(Saturday.days_until(Friday) == 6);
+	}
+
+	#[test]
+	fn date_weekday() {
+		assert!(Date::new(0, 1, 1).unwrap().weekday() == Saturday);
+		assert!(Date::new(1970, 1, 1).unwrap().weekday() == Thursday);
+		assert!(Date::new(2000, 1, 1).unwrap().weekday() == Saturday);
+		assert!(Date::new(2020, 6, 20).unwrap().weekday() == Saturday);
+		assert!(Date::new(2024, 2, 29).unwrap().weekday() == Thursday);
+		assert!(Date::new(-1, 12, 31).unwrap().weekday() == Friday);
+
+		let mut date = Date::new(1999, 1, 1).unwrap();
+		let mut weekday = Friday;
+		for _ in 0..1000 {
+			assert!(date.weekday() == weekday);
+			date = date.next();
+			weekday = weekday.wrapping_next();
+		}
+	}
+
+	#[test]
+	fn parse() {
+		for weekday in WEEKDAYS {
+			assert!(weekday.name().parse() == Ok(weekday));
+			assert!(weekday.short_name().parse() == Ok(weekday));
+		}
+		assert!("monday".parse() == Ok(Monday));
+		assert!("SUN".parse() == Ok(Sunday));
+		assert!(let Err(_) = "Mo".parse::<Weekday>());
+		assert!(let Err(_) = "".parse::<Weekday>());
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn format() {
+		assert!(format!("{}", Monday) == "Monday");
+		assert!(format!("{:?}", Sunday) == "Sunday");
+	}
+
+	#[test]
+	fn serde() {
+		#[derive(Debug, serde::Deserialize, serde::Serialize)]
+		struct Container {
+			weekday: Weekday,
+		}
+
+		let_assert!(Ok(serialized) = serde_yaml::to_string(&Container { weekday: Wednesday }));
+		assert!(serialized == "weekday: 3\n");
+		let_assert!(Ok(parsed) = serde_yaml::from_str::<Container>("weekday: 7"));
+		assert!(parsed.weekday == Sunday);
+
+		let_assert!(Err(e) = serde_yaml::from_str::<Container>("weekday: 0"));
+		assert!(e.to_string() == "invalid weekday number: expected 1-7, got 0");
+	}
+}
@@ -0,0 +1,564 @@
+use crate::{Date, InvalidWeekdaySetSyntax, Weekday, WEEKDAYS};
+
+/// A set of weekdays, stored as a bitset.
+///
+/// This can be used to describe work weeks, weekends or recurring days of the week.
+///
+/// The set can be parsed from and formatted as a comma separated list of weekdays and weekday ranges,
+/// such as `"Mon,Wed-Fri"`.
+/// Ranges may wrap around the end of the week, so `"Fri-Mon"` is the same as `"Mon,Fri,Sat,Sun"`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct WeekdaySet {
+	bits: u8,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for WeekdaySet {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(self)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Deserialize<'a> for WeekdaySet {
+	fn deserialize<D: serde::Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+		let raw = std::borrow::Cow::<'_, str>::deserialize(deserializer)?;
+		raw.parse().map_err(serde::de::Error::custom)
+	}
+}
+
+impl WeekdaySet {
+	/// The empty set.
+	pub const EMPTY: Self = Self { bits: 0 };
+
+	/// The set containing all weekdays.
+	pub const ALL: Self = Self { bits: 0b111_1111 };
+
+	/// The set of weekdays from Monday to Friday.
+	pub const WORKDAYS: Self = Self::range(Weekday::Monday, Weekday::Friday);
+
+	/// The set containing Saturday and Sunday.
+	pub const WEEKEND: Self = Self::range(Weekday::Saturday, Weekday::Sunday);
+
+	/// Create a set from a bitmask.
+	///
+	/// Bit 0 represents Monday and bit 6 represents Sunday.
+	/// The most significant bit is ignored.
+	pub const fn from_bits(bits: u8) -> Self {
+		Self { bits: bits & Self::ALL.bits }
+	}
+
+	/// Get the bitmask of the set.
+	///
+	/// Bit 0 represents Monday and bit 6 represents Sunday.
+	pub const fn to_bits(self) -> u8 {
+		self.bits
+	}
+
+	/// Create a set containing a single weekday.
+	pub const fn single(weekday: Weekday) -> Self {
+		Self { bits: Self::bit(weekday) }
+	}
+
+	/// Create a set from an array of weekdays.
+	pub const fn from_array<const N: usize>(weekdays: [Weekday; N]) -> Self {
+		let mut bits = 0;
+		let mut i = 0;
+		while i < N {
+			bits |= Self::bit(weekdays[i]);
+			i += 1;
+		}
+		Self { bits }
+	}
+
+	/// Create a set containing all weekdays from `first` up to and including `last`.
+	///
+	/// The range wraps around the end of the week if `last` comes before `first`.
+	pub const fn range(first: Weekday, last: Weekday) -> Self {
+		let len = first.days_until(last) + 1;
+		Self::from_bits((1 << len) - 1).rotate_to(first)
+	}
+
+	/// Get the number of weekdays in the set.
+	pub const fn len(self) -> u8 {
+		self.bits.count_ones() as u8
+	}
+
+	/// Check if the set is empty.
+	pub const fn is_empty(self) -> bool {
+		self.bits == 0
+	}
+
+	/// Check if the set contains a weekday.
+	pub const fn contains(self, weekday: Weekday) -> bool {
+		self.bits & Self::bit(weekday) != 0
+	}
+
+	/// Check if the set contains the weekday of a date.
+	pub const fn contains_date(self, date: Date) -> bool {
+		self.contains(date.weekday())
+	}
+
+	/// Get a copy of the set with a weekday added.
+	pub const fn with(self, weekday: Weekday) -> Self {
+		Self { bits: self.bits | Self::bit(weekday) }
+	}
+
+	/// Get a copy of the set with a weekday removed.
+	pub const fn without(self, weekday: Weekday) -> Self {
+		Self { bits: self.bits & !Self::bit(weekday) }
+	}
+
+	/// Add a weekday to the set.
+	///
+	/// Returns `true` if the weekday was not yet in the set.
+	pub fn insert(&mut self, weekday: Weekday) -> bool {
+		let added = !self.contains(weekday);
+		*self = self.with(weekday);
+		added
+	}
+
+	/// Remove a weekday from the set.
+	///
+	/// Returns `true` if the weekday was in the set.
+	pub fn remove(&mut self, weekday: Weekday) -> bool {
+		let removed = self.contains(weekday);
+		*self = self.without(weekday);
+		removed
+	}
+
+	/// Get the union of two sets.
+	pub const fn union(self, other: Self) -> Self {
+		Self { bits: self.bits | other.bits }
+	}
+
+	/// Get the intersection of two sets.
+	pub const fn intersection(self, other: Self) -> Self {
+		Self { bits: self.bits & other.bits }
+	}
+
+	/// Get the weekdays that are in `self` but not in `other`.
+	pub const fn difference(self, other: Self) -> Self {
+		Self { bits: self.bits & !other.bits }
+	}
+
+	/// Get the weekdays that are in exactly one of the two sets.
+	pub const fn symmetric_difference(self, other: Self) -> Self {
+		Self { bits: self.bits ^ other.bits }
+	}
+
+	/// Get the set of all weekdays that are not in `self`.
+	pub const fn complement(self) -> Self {
+		Self { bits: !self.bits & Self::ALL.bits }
+	}
+
+	/// Check if all weekdays of `self` are also in `other`.
+	pub const fn is_subset(self, other: Self) -> bool {
+		self.bits & !other.bits == 0
+	}
+
+	/// Check if all weekdays of `other` are also in `self`.
+	pub const fn is_superset(self, other: Self) -> bool {
+		other.is_subset(self)
+	}
+
+	/// Count the weekdays in the set for a number of consecutive days, starting at `start`.
+	///
+	/// This takes constant time, regardless of the number of days.
+	pub const fn count_in_days(self, start: Weekday, days: u32) -> u32 {
+		let full_weeks = days / 7;
+		let remaining = days % 7;
+		let partial = self.rotate_from(start).bits & ((1 << remaining) - 1);
+		full_weeks * self.len() as u32 + partial.count_ones()
+	}
+
+	/// Get the number of days from `start` until the first weekday in the set.
+	///
+	/// If `start` itself is in the set, this returns 0.
+	/// If the set is empty, this returns `None`.
+	pub const fn days_until_next(self, start: Weekday) -> Option<u8> {
+		if self.is_empty() {
+			None
+		} else {
+			Some(self.rotate_from(start).bits.trailing_zeros() as u8)
+		}
+	}
+
+	/// Get the number of days from `start` back to the last weekday in the set.
+	///
+	/// If `start` itself is in the set, this returns 0.
+	/// If the set is empty, this returns `None`.
+	pub const fn days_since_prev(self, start: Weekday) -> Option<u8> {
+		if self.is_empty() {
+			None
+		} else {
+			// Rotate so that the day after `start` is bit 0, which puts `start` at bit 6.
+			let bits = self.rotate_from(start.wrapping_next()).bits;
+			Some((bits << 1).leading_zeros() as u8)
+		}
+	}
+
+	/// Iterate over the weekdays in the set, starting at Monday.
+	pub const fn iter(self) -> WeekdaySetIter {
+		WeekdaySetIter { bits: self.bits }
+	}
+
+	/// Get the bit for a weekday.
+	const fn bit(weekday: Weekday) -> u8 {
+		1 << (weekday.to_number() - 1)
+	}
+
+	/// Rotate the bits so that `start` ends up at bit 0.
+	const fn rotate_from(self, start: Weekday) -> Self {
+		let shift = start.to_number() - 1;
+		Self::from_bits((self.bits >> shift) | (self.bits << (7 - shift)))
+	}
+
+	/// Rotate the bits so that bit 0 ends up at `start`.
+	const fn rotate_to(self, start: Weekday) -> Self {
+		let shift = start.to_number() - 1;
+		Self::from_bits((self.bits << shift) | (self.bits >> (7 - shift)))
+	}
+}
+
+impl From<Weekday> for WeekdaySet {
+	fn from(other: Weekday) -> Self {
+		Self::single(other)
+	}
+}
+
+impl<const N: usize> From<[Weekday; N]> for WeekdaySet {
+	fn from(other: [Weekday; N]) -> Self {
+		Self::from_array(other)
+	}
+}
+
+impl core::iter::FromIterator<Weekday> for WeekdaySet {
+	fn from_iter<I: IntoIterator<Item = Weekday>>(iter: I) -> Self {
+		let mut set = Self::EMPTY;
+		set.extend(iter);
+		set
+	}
+}
+
+impl Extend<Weekday> for WeekdaySet {
+	fn extend<I: IntoIterator<Item = Weekday>>(&mut self, iter: I) {
+		for weekday in iter {
+			self.insert(weekday);
+		}
+	}
+}
+
+impl IntoIterator for WeekdaySet {
+	type Item = Weekday;
+	type IntoIter = WeekdaySetIter;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl core::ops::BitOr for WeekdaySet {
+	type Output = Self;
+
+	fn bitor(self, other: Self) -> Self {
+		self.union(other)
+	}
+}
+
+impl core::ops::BitAnd for WeekdaySet {
+	type Output = Self;
+
+	fn bitand(self, other: Self) -> Self {
+		self.intersection(other)
+	}
+}
+
+impl core::ops::BitXor for WeekdaySet {
+	type Output = Self;
+
+	fn bitxor(self, other: Self) -> Self {
+		self.symmetric_difference(other)
+	}
+}
+
+impl core::ops::Sub for WeekdaySet {
+	type Output = Self;
+
+	fn sub(self, other: Self) -> Self {
+		self.difference(other)
+	}
+}
+
+impl core::ops::Not for WeekdaySet {
+	type Output = Self;
+
+	fn not(self) -> Self {
+		self.complement()
+	}
+}
+
+impl core::ops::BitOrAssign for WeekdaySet {
+	fn bitor_assign(&mut self, other: Self) {
+		*self = self.union(other)
+	}
+}
+
+impl core::ops::BitAndAssign for WeekdaySet {
+	fn bitand_assign(&mut self, other: Self) {
+		*self = self.intersection(other)
+	}
+}
+
+impl core::ops::BitXorAssign for WeekdaySet {
+	fn bitxor_assign(&mut self, other: Self) {
+		*self = self.symmetric_difference(other)
+	}
+}
+
+impl core::ops::SubAssign for WeekdaySet {
+	fn sub_assign(&mut self, other: Self) {
+		*self = self.difference(other)
+	}
+}
+
+impl core::str::FromStr for WeekdaySet {
+	type Err = InvalidWeekdaySetSyntax;
+
+	fn from_str(data: &str) -> Result<Self, Self::Err> {
+		let data = data.trim();
+		if data.is_empty() {
+			return Ok(Self::EMPTY);
+		}
+
+		let mut set = Self::EMPTY;
+		for item in data.split(',') {
+			let item = item.trim();
+			let range = match item.split_once('-') {
+				Some((first, last)) => {
+					let first = first.trim().parse().map_err(|_| InvalidWeekdaySetSyntax::new())?;
+					let last = last.trim().parse().map_err(|_| InvalidWeekdaySetSyntax::new())?;
+					Self::range(first, last)
+				},
+				None => Self::single(item.parse().map_err(|_| InvalidWeekdaySetSyntax::new())?),
+			};
+			set |= range;
+		}
+		Ok(set)
+	}
+}
+
+impl core::fmt::Display for WeekdaySet {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		let mut first = true;
+		let mut i = 0;
+		while i < WEEKDAYS.len() {
+			if !self.contains(WEEKDAYS[i]) {
+				i += 1;
+				continue;
+			}
+
+			// Find the end of the run of consecutive weekdays.
+			let start = i;
+			while i + 1 < WEEKDAYS.len() && self.contains(WEEKDAYS[i + 1]) {
+				i += 1;
+			}
+
+			if !first {
+				f.write_str(",")?;
+			}
+			first = false;
+
+			// Only collapse runs of three or more weekdays into a range.
+			match i - start {
+				0 => f.write_str(WEEKDAYS[start].short_name())?,
+				1 => write!(f, "{},{}", WEEKDAYS[start].short_name(), WEEKDAYS[i].short_name())?,
+				_ => write!(f, "{}-{}", WEEKDAYS[start].short_name(), WEEKDAYS[i].short_name())?,
+			}
+			i += 1;
+		}
+		Ok(())
+	}
+}
+
+impl core::fmt::Debug for WeekdaySet {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "WeekdaySet({})", self)
+	}
+}
+
+/// Iterator over the weekdays in a [`WeekdaySet`].
+#[derive(Clone, Debug)]
+pub struct WeekdaySetIter {
+	bits: u8,
+}
+
+impl Iterator for WeekdaySetIter {
+	type Item = Weekday;
+
+	fn next(&mut self) -> Option<Weekday> {
+		if self.bits == 0 {
+			None
+		} else {
+			let index = self.bits.trailing_zeros() as u8;
+			self.bits &= self.bits - 1;
+			Some(Weekday::from_number(index + 1))
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.bits.count_ones() as usize;
+		(len, Some(len))
+	}
+}
+
+impl DoubleEndedIterator for WeekdaySetIter {
+	fn next_back(&mut self) -> Option<Weekday> {
+		if self.bits == 0 {
+			None
+		} else {
+			let index = 7 - self.bits.leading_zeros() as u8;
+			self.bits &= !(1 << index);
+			Some(Weekday::from_number(index + 1))
+		}
+	}
+}
+
+impl ExactSizeIterator for WeekdaySetIter {}
+
+impl core::iter::FusedIterator for WeekdaySetIter {}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Weekday::*;
+	use assert2::{assert, let_assert};
+
+	#[test]
+	fn constructors() {
+		assert!(WeekdaySet::EMPTY.len() == 0);
+		assert!(WeekdaySet::ALL.len() == 7);
+		assert!(WeekdaySet::WORKDAYS == WeekdaySet::from_array([Monday, Tuesday, Wednesday, Thursday, Friday]));
+		assert!(WeekdaySet::WEEKEND == WeekdaySet::from_array([Saturday, Sunday]));
+		assert!(WeekdaySet::range(Friday, Monday) == WeekdaySet::from_array([Friday, Saturday, Sunday, Monday]));
+		assert!(WeekdaySet::range(Wednesday, Wednesday) == WeekdaySet::single(Wednesday));
+		assert!(WeekdaySet::range(Tuesday, Monday) == WeekdaySet::ALL);
+		assert!(WeekdaySet::from_bits(0xFF) == WeekdaySet::ALL);
+		assert!(WeekdaySet::single(Monday).to_bits() == 1);
+		assert!(WeekdaySet::single(Sunday).to_bits() == 0b100_0000);
+	}
+
+	#[test]
+	fn set_operations() {
+		let a = WeekdaySet::from_array([Monday, Tuesday, Wednesday]);
+		let b = WeekdaySet::from_array([Wednesday, Thursday]);
+		assert!(a | b == WeekdaySet::range(Monday, Thursday));
+		assert!(a & b == WeekdaySet::single(Wednesday));
+		assert!(a - b == WeekdaySet::from_array([Monday, Tuesday]));
+		assert!(a ^ b == WeekdaySet::from_array([Monday, Tuesday, Thursday]));
+		assert!(!WeekdaySet::WORKDAYS == WeekdaySet::WEEKEND);
+		assert!(WeekdaySet::single(Monday).is_subset(a));
+		assert!(!b.is_subset(a));
+		assert!(a.is_superset(WeekdaySet::EMPTY));
+
+		let mut set = WeekdaySet::EMPTY;
+		assert!(set.insert(Friday));
+		assert!(!set.insert(Friday));
+		assert!(set.contains(Friday));
+		assert!(set.remove(Friday));
+		assert!(!set.remove(Friday));
+		assert!(set.is_empty());
+	}
+
+	#[test]
+	fn contains_date() {
+		assert!(WeekdaySet::WEEKEND.contains_date(Date::new(2020, 6, 20).unwrap()));
+		assert!(!WeekdaySet::WEEKEND.contains_date(Date::new(2020, 6, 22).unwrap()));
+	}
+
+	#[test]
+	fn iter() {
+		let set = WeekdaySet::from_array([Sunday, Monday, Thursday]);
+		let mut iter = set.iter();
+		assert!(iter.len() == 3);
+		assert!(iter.next() == Some(Monday));
+		assert!(iter.next_back() == Some(Sunday));
+		assert!(iter.next() == Some(Thursday));
+		assert!(iter.next() == None);
+		assert!(iter.next_back() == None);
+
+		assert!(WeekdaySet::ALL.iter().eq(WEEKDAYS.iter().copied()));
+		assert!(WeekdaySet::ALL.iter().rev().eq(WEEKDAYS.iter().rev().copied()));
+		assert!(set.iter().collect::<WeekdaySet>() == set);
+	}
+
+	#[test]
+	fn count_in_days() {
+		let set = WeekdaySet::from_array([Monday, Friday]);
+		assert!(set.count_in_days(Monday, 0) == 0);
+		assert!(set.count_in_days(Monday, 1) == 1);
+		assert!(set.count_in_days(Tuesday, 3) == 0);
+		assert!(set.count_in_days(Tuesday, 4) == 1);
+		assert!(set.count_in_days(Saturday, 3) == 1);
+		assert!(set.count_in_days(Saturday, 7) == 2);
+		assert!(set.count_in_days(Saturday, 7 * 52 + 3) == 105);
+	}
+
+	#[test]
+	fn days_until_next_and_since_prev() {
+		let set = WeekdaySet::from_array([Monday, Friday]);
+		assert!(set.days_until_next(Monday) == Some(0));
+		assert!(set.days_until_next(Tuesday) == Some(3));
+		assert!(set.days_until_next(Saturday) == Some(2));
+		assert!(set.days_since_prev(Friday) == Some(0));
+		assert!(set.days_since_prev(Thursday) == Some(3));
+		assert!(set.days_since_prev(Sunday) == Some(2));
+		assert!(set.days_since_prev(Tuesday) == Some(1));
+		assert!(WeekdaySet::EMPTY.days_until_next(Monday) == None);
+		assert!(WeekdaySet::EMPTY.days_since_prev(Monday) == None);
+		assert!(WeekdaySet::single(Sunday).days_since_prev(Saturday) == Some(6));
+	}
+
+	#[test]
+	fn parse() {
+		assert!("Mon,Wed-Fri".parse::<WeekdaySet>() == Ok(WeekdaySet::from_array([Monday, Wednesday, Thursday, Friday])));
+		assert!("mon, wednesday - fri".parse::<WeekdaySet>() == Ok(WeekdaySet::from_array([Monday, Wednesday, Thursday, Friday])));
+		assert!("Fri-Mon".parse::<WeekdaySet>() == Ok(WeekdaySet::range(Friday, Monday)));
+		assert!("".parse::<WeekdaySet>() == Ok(WeekdaySet::EMPTY));
+		assert!(let Err(_) = "Mon,,Tue".parse::<WeekdaySet>());
+		assert!(let Err(_) = "Mon-".parse::<WeekdaySet>());
+		assert!(let Err(_) = "Funday".parse::<WeekdaySet>());
+	}
+
+	#[test]
+	#[cfg(feature = "std")]
+	fn format() {
+		assert!(WeekdaySet::from_array([Monday, Wednesday, Thursday, Friday]).to_string() == "Mon,Wed-Fri");
+		assert!(WeekdaySet::WEEKEND.to_string() == "Sat,Sun");
+		assert!(WeekdaySet::ALL.to_string() == "Mon-Sun");
+		assert!(WeekdaySet::EMPTY.to_string() == "");
+		assert!(WeekdaySet::from_array([Monday, Sunday]).to_string() == "Mon,Sun");
+		assert!(format!("{:?}", WeekdaySet::WORKDAYS) == "WeekdaySet(Mon-Fri)");
+
+		for bits in 0..128 {
+			let set = WeekdaySet::from_bits(bits);
+			assert!(set.to_string().parse::<WeekdaySet>() == Ok(set));
+		}
+	}
+
+	#[test]
+	fn serde() {
+		#[derive(Debug, serde::Deserialize, serde::Serialize)]
+		struct Container {
+			days: WeekdaySet,
+		}
+
+		let_assert!(Ok(serialized) = serde_yaml::to_string(&Container { days: WeekdaySet::WORKDAYS }));
+		assert!(serialized == "days: Mon-Fri\n");
+		let_assert!(Ok(parsed) = serde_yaml::from_str::<Container>("days: Tue,Fri"));
+		assert!(parsed.days == WeekdaySet::from_array([Tuesday, Friday]));
+
+		let_assert!(Err(e) = serde_yaml::from_str::<Container>("days: Mon-Funday"));
+		assert!(e.to_string().starts_with("invalid weekday set syntax"));
+	}
+}
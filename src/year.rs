@@ -1,4 +1,4 @@
-use crate::{Date, InvalidDayOfYear, Month, YearMonth};
+use crate::{Date, DateRange, InvalidDayOfYear, Month, YearMonth};
 
 /// A calendar year.
 ///
@@ -112,6 +112,11 @@ impl Year {
 			day: 31,
 		}
 	}
+
+	/// Get all days of the year as [`DateRange`].
+	pub const fn days(self) -> DateRange {
+		DateRange::inclusive(self.first_day(), self.last_day())
+	}
 }
 
 impl From<i16> for Year {
@@ -229,6 +234,14 @@ mod test {
 		assert!(months[11] == year.with_month(Month::December));
 	}
 
+	#[test]
+	fn days() {
+		assert!(Year::new(2020).days().len() == 366);
+		assert!(Year::new(2021).days().len() == 365);
+		assert!(Year::new(2021).days().start() == Date::new(2021, 1, 1).unwrap());
+		assert!(Year::new(2021).days().end() == Date::new(2022, 1, 1).unwrap());
+	}
+
 	#[test]
 	#[cfg(feature = "std")]
 	fn format_year() {
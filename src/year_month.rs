@@ -1,4 +1,5 @@
-use crate::{Date, InvalidDayOfMonth, Month, Year};
+use crate::date::MIN_YEAR;
+use crate::{Date, DateRange, InvalidDayOfMonth, Month, Weekday, Year};
 
 /// A month of a specific year.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -98,6 +99,17 @@ impl YearMonth {
 		Year::new(year).with_month(month)
 	}
 
+	/// Get a new [`YearMonth`] by adding a number of months, or `None` if the year is before `MIN_YEAR` or after [`i16::MAX`].
+	pub(crate) const fn checked_add_months(self, months: i32) -> Option<Self> {
+		let index = self.year.to_number() as i64 * 12 + (self.month.to_number() - 1) as i64 + months as i64;
+		let year = index.div_euclid(12);
+		if year < MIN_YEAR as i64 || year > i16::MAX as i64 {
+			return None;
+		}
+		let month = Month::January.wrapping_add(index.rem_euclid(12) as i8);
+		Some(Year::new(year as i16).with_month(month))
+	}
+
 	/// Get a new [`YearMonth`] by subtracting a number of months.
 	pub const fn sub_months(self, months: i32) -> Self {
 		// This breaks for i32::MIN, but that would overflow the year counter anyway.
@@ -137,6 +149,33 @@ impl YearMonth {
 			day: self.total_days(),
 		}
 	}
+
+	/// Get all days of the month as [`DateRange`].
+	pub const fn days(self) -> DateRange {
+		DateRange::inclusive(self.first_day(), self.last_day())
+	}
+
+	/// Get the n-th occurence of a weekday in the month.
+	///
+	/// For positive `n`, this counts from the start of the month, so `n = 1` gives the first occurence.
+	/// For negative `n`, this counts from the end of the month, so `n = -1` gives the last occurence.
+	///
+	/// Returns `None` if `n` is zero or if the month does not have that many occurences of the weekday.
+	pub const fn nth_weekday(self, n: i8, weekday: Weekday) -> Option<Date> {
+		let date = if n > 0 {
+			self.first_day().weekday_on_or_after(weekday).add_days((n as i32 - 1) * 7)
+		} else if n < 0 {
+			self.last_day().weekday_on_or_before(weekday).sub_days((-(n as i32) - 1) * 7)
+		} else {
+			return None;
+		};
+
+		if date.year().to_number() == self.year.to_number() && date.month() as u8 == self.month as u8 {
+			Some(date)
+		} else {
+			None
+		}
+	}
 }
 
 impl core::fmt::Display for YearMonth {
@@ -229,6 +268,30 @@ mod test {
 		}
 	}
 
+	#[test]
+	fn days() {
+		let month = YearMonth::new(2020, February);
+		assert!(month.days().len() == 29);
+		assert!(month.days().iter().next() == Some(month.first_day()));
+		assert!(month.days().iter().next_back() == Some(month.last_day()));
+	}
+
+	#[test]
+	fn nth_weekday() {
+		let month = YearMonth::new(2024, February);
+		assert!(month.nth_weekday(1, Thursday) == Some(Date::new(2024, February, 1).unwrap()));
+		assert!(month.nth_weekday(3, Wednesday) == Some(Date::new(2024, February, 21).unwrap()));
+		assert!(month.nth_weekday(5, Thursday) == Some(Date::new(2024, February, 29).unwrap()));
+		assert!(month.nth_weekday(5, Friday) == None);
+		assert!(month.nth_weekday(-1, Thursday) == Some(Date::new(2024, February, 29).unwrap()));
+		assert!(month.nth_weekday(-1, Friday) == Some(Date::new(2024, February, 23).unwrap()));
+		assert!(month.nth_weekday(-4, Friday) == Some(Date::new(2024, February, 2).unwrap()));
+		assert!(month.nth_weekday(-5, Friday) == None);
+		assert!(month.nth_weekday(0, Friday) == None);
+		assert!(month.nth_weekday(i8::MIN, Friday) == None);
+		assert!(month.nth_weekday(i8::MAX, Friday) == None);
+	}
+
 	#[test]
 	#[cfg(feature = "std")]
 	fn format() {